@@ -0,0 +1,77 @@
+use crate::cli::CliError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use triale::point::PointCreateError;
+use triale::solve::SolveError;
+use triale::triangle::{DegenerateTriangleError, TriangleCreateError};
+
+/// Top-level application error that unifies all possible failure modes.
+#[derive(Debug)]
+pub enum AppError {
+    Io(io::Error),
+    Cli(CliError),
+    Geometry(triale::Error),
+}
+
+impl Display for AppError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Cli(err) => write!(f, "Argument error: {err}"),
+            Self::Geometry(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Cli(err) => Some(err),
+            Self::Geometry(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<CliError> for AppError {
+    fn from(err: CliError) -> Self {
+        Self::Cli(err)
+    }
+}
+
+impl From<triale::Error> for AppError {
+    fn from(err: triale::Error) -> Self {
+        Self::Geometry(err)
+    }
+}
+
+impl From<PointCreateError> for AppError {
+    fn from(err: PointCreateError) -> Self {
+        Self::Geometry(err.into())
+    }
+}
+
+impl From<TriangleCreateError> for AppError {
+    fn from(err: TriangleCreateError) -> Self {
+        Self::Geometry(err.into())
+    }
+}
+
+impl From<DegenerateTriangleError> for AppError {
+    fn from(err: DegenerateTriangleError) -> Self {
+        Self::Geometry(err.into())
+    }
+}
+
+impl From<SolveError> for AppError {
+    fn from(err: SolveError) -> Self {
+        Self::Geometry(err.into())
+    }
+}
@@ -0,0 +1,313 @@
+//! Coordinates of a point relative to a triangle.
+//!
+//! Barycentric coordinates `u : v : w` weight the vertices A, B and C;
+//! normalized they sum to one and are the signed area ratios of the
+//! sub-triangles PBC, PCA and PAB to ABC. Trilinear coordinates `x : y : z`
+//! are proportional to the signed distances from the point to the side lines
+//! BC, CA and AB; normalized ("exact") they are those distances. Both are
+//! homogeneous: any non-zero multiple describes the same point. Points inside
+//! the triangle have all coordinates positive.
+
+use crate::point::{Point, PointCreateError};
+use crate::tolerance::Tolerance;
+use crate::triangle::Triangle;
+use std::fmt::{Display, Formatter};
+
+/// Homogeneous barycentric coordinates `u : v : w`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Barycentric {
+    pub u: f64,
+    pub v: f64,
+    pub w: f64,
+}
+
+impl Barycentric {
+    #[must_use]
+    pub const fn new(u: f64, v: f64, w: f64) -> Self {
+        Self { u, v, w }
+    }
+
+    /// Normalized coordinates of `point`. Returns `None` for a degenerate
+    /// triangle.
+    #[must_use]
+    pub fn from_cartesian(point: Point, tri: &Triangle) -> Option<Self> {
+        let double_area = (tri.b - tri.a).cross(tri.c - tri.a);
+        if double_area == 0.0 || !double_area.is_finite() {
+            return None;
+        }
+        Some(Self::new(
+            (tri.b - point).cross(tri.c - point) / double_area,
+            (tri.c - point).cross(tri.a - point) / double_area,
+            (tri.a - point).cross(tri.b - point) / double_area,
+        ))
+    }
+
+    /// Scales the coordinates to sum to one. Returns `None` for points at
+    /// infinity, whose coordinates sum to zero.
+    #[must_use]
+    pub fn normalized(self) -> Option<Self> {
+        self.normalized_with(Tolerance::default())
+    }
+
+    /// [`Self::normalized`], treating a sum within `tolerance` of zero,
+    /// relative to the coordinates' magnitude, as a point at infinity.
+    #[must_use]
+    pub fn normalized_with(self, tolerance: Tolerance) -> Option<Self> {
+        let sum = self.u + self.v + self.w;
+        let magnitude = self.u.abs() + self.v.abs() + self.w.abs();
+        if !sum.is_finite() || magnitude == 0.0 || tolerance.is_negligible(sum, magnitude) {
+            return None;
+        }
+        Some(Self::new(self.u / sum, self.v / sum, self.w / sum))
+    }
+
+    /// The Cartesian point these coordinates describe relative to `tri`.
+    #[must_use]
+    pub fn to_cartesian(self, tri: &Triangle) -> Option<Point> {
+        self.to_cartesian_with(tri, Tolerance::default())
+    }
+
+    /// [`Self::to_cartesian`], with points at infinity recognised within
+    /// `tolerance`, see [`Self::normalized_with`].
+    #[must_use]
+    pub fn to_cartesian_with(self, tri: &Triangle, tolerance: Tolerance) -> Option<Point> {
+        let Self { u, v, w } = self.normalized_with(tolerance)?;
+        Some(tri.a * u + tri.b * v + tri.c * w)
+    }
+
+    /// Homogeneous trilinears, `u / a : v / b : w / c`.
+    #[must_use]
+    pub fn to_trilinear(self, tri: &Triangle) -> Trilinear {
+        let [a, b, c] = tri.side_lengths();
+        Trilinear::new(self.u / a, self.v / b, self.w / c)
+    }
+}
+
+impl Display for Barycentric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} : {} : {})", self.u, self.v, self.w)
+    }
+}
+
+/// Homogeneous trilinear coordinates `x : y : z`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Trilinear {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Trilinear {
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Exact trilinears of `point`: its signed distances to BC, CA and AB,
+    /// positive on the side of the opposite vertex.
+    #[must_use]
+    pub fn from_cartesian(point: Point, tri: &Triangle) -> Option<Self> {
+        Barycentric::from_cartesian(point, tri)?
+            .to_trilinear(tri)
+            .exact(tri)
+    }
+
+    /// Scales the coordinates to the actual signed distances, so that
+    /// `a x + b y + c z` equals twice the area.
+    #[must_use]
+    pub fn exact(self, tri: &Triangle) -> Option<Self> {
+        self.exact_with(tri, Tolerance::default())
+    }
+
+    /// [`Self::exact`], with points at infinity recognised within
+    /// `tolerance`, see [`Barycentric::normalized_with`].
+    #[must_use]
+    pub fn exact_with(self, tri: &Triangle, tolerance: Tolerance) -> Option<Self> {
+        let [a, b, c] = tri.side_lengths();
+        let double_area = (tri.b - tri.a).cross(tri.c - tri.a).abs();
+        let Barycentric { u, v, w } = self.to_barycentric(tri).normalized_with(tolerance)?;
+        if double_area == 0.0 {
+            return None;
+        }
+        Some(Self::new(
+            double_area * u / a,
+            double_area * v / b,
+            double_area * w / c,
+        ))
+    }
+
+    /// Homogeneous barycentrics, `a x : b y : c z`.
+    #[must_use]
+    pub fn to_barycentric(self, tri: &Triangle) -> Barycentric {
+        let [a, b, c] = tri.side_lengths();
+        Barycentric::new(a * self.x, b * self.y, c * self.z)
+    }
+
+    /// The Cartesian point these coordinates describe relative to `tri`.
+    #[must_use]
+    pub fn to_cartesian(self, tri: &Triangle) -> Option<Point> {
+        self.to_cartesian_with(tri, Tolerance::default())
+    }
+
+    /// [`Self::to_cartesian`], with points at infinity recognised within
+    /// `tolerance`, see [`Barycentric::normalized_with`].
+    #[must_use]
+    pub fn to_cartesian_with(self, tri: &Triangle, tolerance: Tolerance) -> Option<Point> {
+        self.to_barycentric(tri).to_cartesian_with(tri, tolerance)
+    }
+}
+
+impl Display for Trilinear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} : {} : {})", self.x, self.y, self.z)
+    }
+}
+
+/// Parses barycentric coordinates written as `u : v : w`.
+///
+/// # Errors
+/// Returns a [`PointCreateError`] when there are not exactly three finite
+/// numbers.
+pub fn to_barycentric(input: &str) -> Result<Barycentric, PointCreateError> {
+    let parts: Vec<&str> = input.trim().split(':').map(str::trim).collect();
+    match parts.len() {
+        0..=2 => {
+            return Err(PointCreateError::TooFewPoints {
+                got: parts.len(),
+                expected: 3,
+            });
+        }
+        3 => {}
+        got => return Err(PointCreateError::TooManyPoints { got, expected: 3 }),
+    }
+
+    let mut values = [0.0; 3];
+    for (slot, raw) in values.iter_mut().zip(&parts) {
+        *slot = raw
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or_else(|| PointCreateError::InvalidFormat {
+                got: (*raw).to_string(),
+                example: "u : v : w, e.g. 1 : 1 : 1".to_string(),
+            })?;
+    }
+    let [u, v, w] = values;
+    Ok(Barycentric::new(u, v, w))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::compute_summary;
+    use crate::test_support::{approx_eq, approx_eq_pt, scalene};
+
+    #[test]
+    fn test_barycentric_round_trip() {
+        let tri = scalene();
+        for point in [
+            Point::new(4.0, 2.0),
+            Point::new(-3.0, 12.5),
+            Point::new(1e3, -7.0),
+            tri.c,
+        ] {
+            let bary = Barycentric::from_cartesian(point, &tri).unwrap();
+            assert!(approx_eq(bary.u + bary.v + bary.w, 1.0));
+            assert!(approx_eq_pt(bary.to_cartesian(&tri).unwrap(), point));
+
+            // Homogeneous: scaling changes nothing.
+            let scaled = Barycentric::new(bary.u * -4.0, bary.v * -4.0, bary.w * -4.0);
+            assert!(approx_eq_pt(scaled.to_cartesian(&tri).unwrap(), point));
+        }
+
+        let vertex = Barycentric::from_cartesian(tri.b, &tri).unwrap();
+        assert!(approx_eq(vertex.v, 1.0) && approx_eq(vertex.u, 0.0));
+    }
+
+    #[test]
+    fn test_trilinears_are_side_distances() {
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        );
+        let s = compute_summary(&tri);
+
+        // The incenter is 1 : 1 : 1, at distance r from every side.
+        let incenter = Trilinear::from_cartesian(s.incenter, &tri).unwrap();
+        for distance in [incenter.x, incenter.y, incenter.z] {
+            assert!(approx_eq(distance, s.inradius));
+        }
+        assert!(approx_eq_pt(
+            Trilinear::new(1.0, 1.0, 1.0).to_cartesian(&tri).unwrap(),
+            s.incenter
+        ));
+
+        // Outside beyond AB the distance to AB turns negative.
+        let below = Trilinear::from_cartesian(Point::new(1.0, -2.0), &tri).unwrap();
+        assert!(approx_eq(below.z, -2.0));
+        assert!(approx_eq(below.y, 1.0));
+
+        let exact = Trilinear::new(2.0, 2.0, 2.0).exact(&tri).unwrap();
+        assert!(approx_eq(exact.x, 1.0));
+    }
+
+    #[test]
+    fn test_conversions_agree() {
+        let tri = scalene();
+        let point = Point::new(2.5, 1.0);
+        let bary = Barycentric::from_cartesian(point, &tri).unwrap();
+        let tri_coords = bary.to_trilinear(&tri);
+        assert!(approx_eq_pt(tri_coords.to_cartesian(&tri).unwrap(), point));
+        let back = tri_coords.to_barycentric(&tri).normalized().unwrap();
+        assert!(approx_eq(back.u, bary.u) && approx_eq(back.w, bary.w));
+    }
+
+    #[test]
+    fn test_points_at_infinity_and_degenerate_triangles() {
+        let tri = scalene();
+        assert_eq!(Barycentric::new(1.0, -1.0, 0.0).to_cartesian(&tri), None);
+        assert_eq!(Barycentric::new(0.0, 0.0, 0.0).normalized(), None);
+        // Whether a sum counts as zero is relative to the coordinates.
+        for scale in [1e-9, 1.0, 1e9] {
+            let nearly = Barycentric::new(scale, -scale * (1.0 - 1e-12), 0.0);
+            assert_eq!(nearly.normalized(), None, "{scale}");
+            assert!(nearly.normalized_with(Tolerance::relative(0.0)).is_some());
+        }
+
+        let flat = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+        );
+        assert_eq!(Barycentric::from_cartesian(Point::zero(), &flat), None);
+        assert_eq!(Trilinear::from_cartesian(Point::zero(), &flat), None);
+    }
+
+    #[test]
+    fn test_parse_barycentric() {
+        assert_eq!(
+            to_barycentric(" 1 : 2:3.5 "),
+            Ok(Barycentric::new(1.0, 2.0, 3.5))
+        );
+        assert!(matches!(
+            to_barycentric("1 : 2"),
+            Err(PointCreateError::TooFewPoints {
+                got: 2,
+                expected: 3
+            })
+        ));
+        assert!(matches!(
+            to_barycentric("1:2:3:4"),
+            Err(PointCreateError::TooManyPoints {
+                got: 4,
+                expected: 3
+            })
+        ));
+        assert!(matches!(
+            to_barycentric("1:x:3"),
+            Err(PointCreateError::InvalidFormat { .. })
+        ));
+    }
+}
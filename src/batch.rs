@@ -0,0 +1,164 @@
+use crate::app_error::AppError;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use triale::geometry::{TriangleSummary, compute_summary_with};
+use triale::tolerance::Tolerance;
+use triale::triangle::{build_triangle_with, to_triangle};
+
+/// Where batch input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl BatchSource {
+    /// `-` is the conventional name for standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+}
+
+/// Result of processing one input line. `line` is 1-based.
+#[derive(Debug)]
+pub struct BatchItem {
+    pub line: usize,
+    pub outcome: Result<TriangleSummary, AppError>,
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct BatchReport {
+    pub total: usize,
+    pub failed: usize,
+}
+
+/// Blank lines and lines starting with `#` carry no triangle.
+fn is_skippable(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+/// Runs one input line through the same pipeline as a single triangle.
+///
+/// # Errors
+/// Returns the parse, duplicate-point or degeneracy error for the line.
+pub fn summarize_line(line: &str, tolerance: Tolerance) -> Result<TriangleSummary, AppError> {
+    let points = to_triangle(line)?;
+    let triangle = build_triangle_with(points, tolerance)?;
+    Ok(compute_summary_with(&triangle, tolerance))
+}
+
+/// Reads triangles line by line and hands every processed line to `emit`.
+/// A bad line is reported through its [`BatchItem`] and never stops the run.
+///
+/// # Errors
+/// Returns an error only when reading the input or running `emit` fails.
+pub fn process_lines<R, F>(
+    reader: R,
+    tolerance: Tolerance,
+    mut emit: F,
+) -> Result<BatchReport, io::Error>
+where
+    R: BufRead,
+    F: FnMut(&BatchItem) -> Result<(), io::Error>,
+{
+    let mut report = BatchReport::default();
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        if is_skippable(&line) {
+            continue;
+        }
+
+        let item = BatchItem {
+            line: idx + 1,
+            outcome: summarize_line(&line, tolerance),
+        };
+        report.total += 1;
+        if item.outcome.is_err() {
+            report.failed += 1;
+        }
+        emit(&item)?;
+    }
+    Ok(report)
+}
+
+/// Writes one human-readable row per item.
+///
+/// # Errors
+/// Returns any error raised by `writer`.
+pub fn write_text_row<W: Write>(writer: &mut W, item: &BatchItem) -> Result<(), io::Error> {
+    match &item.outcome {
+        Ok(summary) => writeln!(
+            writer,
+            "{}: [{}, {}, {}]  area={}  perimeter={}  {} {}",
+            item.line,
+            summary.vertex_a,
+            summary.vertex_b,
+            summary.vertex_c,
+            summary.area,
+            summary.perimeter,
+            summary.side_class,
+            summary.angle_class
+        ),
+        Err(err) => writeln!(writer, "{}: error: {err}", item.line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use triale::Error;
+
+    fn collect(input: &str) -> (Vec<(usize, bool)>, Option<BatchReport>) {
+        let mut rows = Vec::new();
+        let report = process_lines(Cursor::new(input), Tolerance::default(), |item| {
+            rows.push((item.line, item.outcome.is_ok()));
+            Ok(())
+        });
+        (rows, report.ok())
+    }
+
+    #[test]
+    fn test_failures_are_reported_per_line() {
+        let input = "0,0 4,0 0,3\n\
+                     # comment\n\
+                     \n\
+                     0 0 1 0 2 0\n\
+                     1,1 1,1 2,3\n\
+                     0,0 4,0\n\
+                     0 0 1 0 0 1\n";
+        let (rows, report) = collect(input);
+        assert_eq!(
+            rows,
+            vec![(1, true), (4, false), (5, false), (6, false), (7, true)]
+        );
+        assert_eq!(
+            report,
+            Some(BatchReport {
+                total: 5,
+                failed: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_kinds_are_preserved() {
+        assert!(matches!(
+            summarize_line("0 0 1 0 2 0", Tolerance::default()),
+            Err(AppError::Geometry(Error::DegenerateTriangle(_)))
+        ));
+        assert!(matches!(
+            summarize_line("1,1 1,1 2,3", Tolerance::default()),
+            Err(AppError::Geometry(Error::TriangleCreate(_)))
+        ));
+        assert!(
+            summarize_line("0 0 4 0 0 3", Tolerance::default())
+                .is_ok_and(|summary| Tolerance::default().approx_eq(summary.area, 6.0))
+        );
+    }
+}
@@ -0,0 +1,638 @@
+//! Arbitrary-precision signed integers.
+//!
+//! Only what exact rational geometry needs is provided: addition,
+//! subtraction, multiplication, division with remainder, greatest common
+//! divisors and conversion from and to decimal text.
+//!
+//! The magnitude is stored as little-endian base `2^32` digits. Operations use
+//! schoolbook algorithms a limb at a time, quadratic in the number of limbs;
+//! greatest common divisors use the binary algorithm. Decimal exponents are
+//! capped by `rational`, which keeps operands to some tens of thousands of
+//! bits even for coordinates like `1e-1000`.
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+const LIMB_BITS: u32 = 32;
+/// Largest power of ten that fits a limb, used for decimal conversion.
+const DECIMAL_LIMB: u32 = 1_000_000_000;
+const DECIMAL_LIMB_DIGITS: u32 = 9;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    /// Base `2^32` digits, least significant first, without trailing zeros.
+    /// Zero has no digits and is never negative.
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    #[must_use]
+    pub const fn zero() -> Self {
+        Self {
+            negative: false,
+            limbs: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn one() -> Self {
+        Self::from(1u32)
+    }
+
+    fn from_parts(negative: bool, mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self {
+            negative: negative && !limbs.is_empty(),
+            limbs,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// `Less`, `Equal` or `Greater` as the value is negative, zero or positive.
+    #[must_use]
+    pub const fn signum(&self) -> Ordering {
+        if self.negative {
+            Ordering::Less
+        } else if self.limbs.is_empty() {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.limbs.clone())
+    }
+
+    /// Number of bits of the magnitude, zero for zero.
+    #[must_use]
+    pub fn bit_length(&self) -> usize {
+        self.limbs.last().map_or(0, |top| {
+            (self.limbs.len() - 1) * LIMB_BITS as usize + (LIMB_BITS - top.leading_zeros()) as usize
+        })
+    }
+
+    /// The value multiplied by `2^bits`.
+    #[must_use]
+    pub fn shl(&self, bits: usize) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let (whole, part) = (bits / LIMB_BITS as usize, bits % LIMB_BITS as usize);
+        let mut limbs = vec![0; whole];
+        let mut carry = 0u32;
+        for &limb in &self.limbs {
+            let wide = (u64::from(limb) << part) | u64::from(carry);
+            limbs.push(low(wide));
+            carry = high(wide);
+        }
+        limbs.push(carry);
+        Self::from_parts(self.negative, limbs)
+    }
+
+    /// `10^exponent`.
+    #[must_use]
+    pub fn pow10(exponent: u32) -> Self {
+        let mut result = Self::one();
+        let mut remaining = exponent;
+        while remaining > 0 {
+            let step = remaining.min(DECIMAL_LIMB_DIGITS);
+            result = &result * &Self::from(10u32.pow(step));
+            remaining -= step;
+        }
+        result
+    }
+
+    /// Truncating division: the quotient rounds toward zero and the remainder
+    /// has the sign of `self`. `None` when `divisor` is zero.
+    #[must_use]
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = div_rem_magnitude(&self.limbs, &divisor.limbs);
+        Some((
+            Self::from_parts(self.negative != divisor.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        ))
+    }
+
+    /// Greatest common divisor, never negative. `gcd(0, 0)` is zero.
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> Self {
+        Self::from_parts(false, gcd_magnitude(&self.limbs, &other.limbs))
+    }
+
+    /// Parses an optionally signed run of decimal digits.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let (negative, digits) = match text.as_bytes().first()? {
+            b'-' => (true, &text[1..]),
+            b'+' => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let mut limbs = Vec::new();
+        let chunk_len = DECIMAL_LIMB_DIGITS as usize;
+        let head = digits.len() % chunk_len;
+        let chunks = std::iter::once(&digits[..head]).chain(
+            digits.as_bytes()[head..]
+                .chunks(chunk_len)
+                .filter_map(|chunk| std::str::from_utf8(chunk).ok()),
+        );
+        for chunk in chunks.filter(|chunk| !chunk.is_empty()) {
+            let value = chunk.parse::<u32>().ok()?;
+            let scale = 10u32.pow(u32::try_from(chunk.len()).ok()?);
+            limbs = mul_add_small(&limbs, scale, value);
+        }
+        Some(Self::from_parts(negative, limbs))
+    }
+
+    /// The nearest `f64` up to a few units in the last place; infinite when
+    /// the value is beyond the `f64` range.
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.limbs.iter().rev().fold(0.0, |total: f64, &limb| {
+            total.mul_add(4_294_967_296.0, f64::from(limb))
+        });
+        if self.negative { -magnitude } else { magnitude }
+    }
+}
+
+const fn low(wide: u64) -> u32 {
+    (wide & 0xffff_ffff) as u32
+}
+
+const fn high(wide: u64) -> u32 {
+    (wide >> LIMB_BITS) as u32
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (idx, &limb) in long.iter().enumerate() {
+        let wide = u64::from(limb) + u64::from(short.get(idx).copied().unwrap_or(0)) + carry;
+        sum.push(low(wide));
+        carry = u64::from(high(wide));
+    }
+    sum.push(low(carry));
+    sum
+}
+
+/// `a - b` for `a >= b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (idx, &limb) in a.iter().enumerate() {
+        let mut wide = i64::from(limb) - i64::from(b.get(idx).copied().unwrap_or(0)) - borrow;
+        borrow = i64::from(wide < 0);
+        if wide < 0 {
+            wide += 1 << LIMB_BITS;
+        }
+        difference.push(low(wide.unsigned_abs()));
+    }
+    difference
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let wide = u64::from(x) * u64::from(y) + u64::from(product[i + j]) + carry;
+            product[i + j] = low(wide);
+            carry = u64::from(high(wide));
+        }
+        product[i + b.len()] = low(carry);
+    }
+    product
+}
+
+/// `a * factor + addend` for a single-limb factor and addend.
+fn mul_add_small(a: &[u32], factor: u32, addend: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = u64::from(addend);
+    for &limb in a {
+        let wide = u64::from(limb) * u64::from(factor) + carry;
+        result.push(low(wide));
+        carry = u64::from(high(wide));
+    }
+    result.push(low(carry));
+    result
+}
+
+/// Quotient and remainder of a division by a single nonzero limb.
+fn div_rem_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for (idx, &limb) in a.iter().enumerate().rev() {
+        let wide = (remainder << LIMB_BITS) | u64::from(limb);
+        quotient[idx] = low(wide / u64::from(divisor));
+        remainder = wide % u64::from(divisor);
+    }
+    (quotient, low(remainder))
+}
+
+/// `a` shifted left by `shift < 32` bits, with one more limb for the carry.
+fn shl_small(a: &[u32], shift: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u32;
+    for &limb in a {
+        let wide = (u64::from(limb) << shift) | u64::from(carry);
+        result.push(low(wide));
+        carry = high(wide);
+    }
+    result.push(carry);
+    result
+}
+
+/// Shifts `a` right by `bits` in place, dropping the bits shifted out.
+fn shr_in_place(a: &mut Vec<u32>, bits: usize) {
+    let (whole, part) = (bits / LIMB_BITS as usize, bits % LIMB_BITS as usize);
+    a.drain(..whole.min(a.len()));
+    if part > 0 {
+        let mut carry = 0u32;
+        for limb in a.iter_mut().rev() {
+            let wide = (u64::from(*limb) << LIMB_BITS) >> part;
+            *limb = high(wide) | carry;
+            carry = low(wide);
+        }
+    }
+    trim(a);
+}
+
+fn trim(a: &mut Vec<u32>) {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+/// Number of zero bits below the lowest set bit of a nonzero magnitude.
+fn trailing_zeros(a: &[u32]) -> usize {
+    a.iter().position(|&limb| limb != 0).map_or(0, |idx| {
+        idx * LIMB_BITS as usize + a[idx].trailing_zeros() as usize
+    })
+}
+
+/// Divides a nonzero magnitude by its largest power-of-two factor.
+fn make_odd(a: &mut Vec<u32>) {
+    let zeros = trailing_zeros(a);
+    shr_in_place(a, zeros);
+}
+
+/// `a -= b` in place for `a >= b`.
+fn sub_in_place(a: &mut Vec<u32>, b: &[u32]) {
+    let mut borrow = false;
+    for (idx, limb) in a.iter_mut().enumerate() {
+        let (difference, under) = limb.overflowing_sub(b.get(idx).copied().unwrap_or(0));
+        let (difference, under_borrow) = difference.overflowing_sub(u32::from(borrow));
+        *limb = difference;
+        borrow = under || under_borrow;
+        if !borrow && idx >= b.len() {
+            break;
+        }
+    }
+    trim(a);
+}
+
+/// Long division of magnitudes, a limb at a time (Knuth, TAOCP vol. 2,
+/// algorithm 4.3.1 D). `b` must be nonzero.
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if let [divisor] = b {
+        let (quotient, remainder) = div_rem_small(a, *divisor);
+        return (quotient, vec![remainder]);
+    }
+    if cmp_magnitude(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    // Normalize so the top limb of the divisor has its high bit set, which
+    // keeps each estimated quotient limb at most two too large.
+    let shift = b.last().map_or(0, |top| top.leading_zeros());
+    let mut divisor = shl_small(b, shift);
+    divisor.pop();
+    let mut remainder = shl_small(a, shift);
+    let n = divisor.len();
+    let (top, next) = (u64::from(divisor[n - 1]), u64::from(divisor[n - 2]));
+    let base = 1u64 << LIMB_BITS;
+    let mut quotient = vec![0u32; a.len() - n + 1];
+    for j in (0..quotient.len()).rev() {
+        let numerator =
+            (u64::from(remainder[j + n]) << LIMB_BITS) | u64::from(remainder[j + n - 1]);
+        let (mut estimate, mut rest) = (numerator / top, numerator % top);
+        while estimate >= base
+            || estimate * next > ((rest << LIMB_BITS) | u64::from(remainder[j + n - 2]))
+        {
+            estimate -= 1;
+            rest += top;
+            if rest >= base {
+                break;
+            }
+        }
+
+        // remainder[j..=j + n] -= estimate * divisor
+        let (mut carry, mut borrow) = (0u64, false);
+        for (idx, &limb) in divisor.iter().enumerate() {
+            let product = estimate * u64::from(limb) + carry;
+            carry = u64::from(high(product));
+            let (difference, under) = remainder[j + idx].overflowing_sub(low(product));
+            let (difference, under_borrow) = difference.overflowing_sub(u32::from(borrow));
+            remainder[j + idx] = difference;
+            borrow = under || under_borrow;
+        }
+        let (difference, under) = remainder[j + n].overflowing_sub(low(carry));
+        let (difference, under_borrow) = difference.overflowing_sub(u32::from(borrow));
+        remainder[j + n] = difference;
+
+        // The estimate was one too large: add the divisor back.
+        if under || under_borrow {
+            estimate -= 1;
+            let mut carry = 0u64;
+            for (idx, &limb) in divisor.iter().enumerate() {
+                let sum = u64::from(remainder[j + idx]) + u64::from(limb) + carry;
+                remainder[j + idx] = low(sum);
+                carry = u64::from(high(sum));
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(low(carry));
+        }
+        quotient[j] = low(estimate);
+    }
+    remainder.truncate(n);
+    shr_in_place(&mut remainder, shift as usize);
+    (quotient, remainder)
+}
+
+/// Greatest common divisor of magnitudes: one division to bring the operands
+/// to a similar size, then the binary algorithm, which needs only shifts and
+/// subtractions.
+fn gcd_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (mut a, mut b) = match cmp_magnitude(a, b) {
+        Ordering::Less => (b.to_vec(), a.to_vec()),
+        _ => (a.to_vec(), b.to_vec()),
+    };
+    if b.is_empty() {
+        return a;
+    }
+    (_, a) = div_rem_magnitude(&a, &b);
+    trim(&mut a);
+    if a.is_empty() {
+        return b;
+    }
+    let twos = trailing_zeros(&a).min(trailing_zeros(&b));
+    make_odd(&mut a);
+    make_odd(&mut b);
+    // Both odd: their difference is even, and halving it keeps the gcd.
+    loop {
+        match cmp_magnitude(&a, &b) {
+            Ordering::Equal => break,
+            Ordering::Less => std::mem::swap(&mut a, &mut b),
+            Ordering::Greater => {}
+        }
+        sub_in_place(&mut a, &b);
+        make_odd(&mut a);
+    }
+    BigInt::from_parts(false, a).shl(twos).limbs
+}
+
+impl From<u32> for BigInt {
+    fn from(value: u32) -> Self {
+        Self::from_parts(false, vec![value])
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        Self::from_parts(false, vec![low(value), high(value)])
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let magnitude = Self::from(value.unsigned_abs());
+        Self::from_parts(value < 0, magnitude.limbs)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.limbs, &other.limbs),
+            (true, true) => cmp_magnitude(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.limbs.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_parts(!self.negative, self.limbs)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: Self) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(self.negative, add_magnitude(&self.limbs, &other.limbs));
+        }
+        match cmp_magnitude(&self.limbs, &other.limbs) {
+            Ordering::Less => {
+                BigInt::from_parts(other.negative, sub_magnitude(&other.limbs, &self.limbs))
+            }
+            _ => BigInt::from_parts(self.negative, sub_magnitude(&self.limbs, &other.limbs)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: Self) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: Self) -> BigInt {
+        BigInt::from_parts(
+            self.negative != other.negative,
+            mul_magnitude(&self.limbs, &other.limbs),
+        )
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut chunks = Vec::new();
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let (quotient, chunk) = div_rem_small(&rest, DECIMAL_LIMB);
+            chunks.push(chunk);
+            rest = quotient;
+            while rest.last() == Some(&0) {
+                rest.pop();
+            }
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        if let Some(top) = chunks.next() {
+            write!(f, "{top}")?;
+        }
+        let width = DECIMAL_LIMB_DIGITS as usize;
+        for chunk in chunks {
+            write!(f, "{chunk:0width$}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        BigInt::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        for text in [
+            "0",
+            "7",
+            "-42",
+            "4294967296",
+            "-18446744073709551617",
+            "123456789012345678901234567890123456789",
+        ] {
+            assert_eq!(big(text).to_string(), text);
+        }
+        assert_eq!(big("+0012").to_string(), "12");
+        assert_eq!(big("-0"), BigInt::zero());
+        for bad in ["", "-", "1.5", "12a", " 1"] {
+            assert_eq!(BigInt::parse(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = big("123456789012345678901234567890");
+        let b = big("-987654321098765432109876543210");
+        assert_eq!((&a + &b).to_string(), "-864197532086419753208641975320");
+        assert_eq!((&a - &b).to_string(), "1111111110111111111011111111100");
+        assert_eq!(
+            (&a * &b).to_string(),
+            "-121932631137021795226185032733622923332237463801111263526900"
+        );
+        assert_eq!(&a - &a, BigInt::zero());
+        assert!(b < a && -&b > a);
+        assert_eq!(BigInt::pow10(20).to_string(), "100000000000000000000");
+        assert_eq!(BigInt::from(5u32).shl(40).to_string(), "5497558138880");
+    }
+
+    #[test]
+    fn test_division_and_gcd() {
+        let a = big("121932631137021795226185032733622923332237463801111263526900");
+        let b = big("123456789012345678901234567890");
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+        assert_eq!(quotient.to_string(), "987654321098765432109876543210");
+        assert!(remainder.is_zero());
+
+        let (quotient, remainder) = big("-7").div_rem(&big("2")).unwrap();
+        assert_eq!((quotient, remainder), (big("-3"), big("-1")));
+        assert_eq!(big("1").div_rem(&BigInt::zero()), None);
+
+        let shared = big("18446744073709551629");
+        let gcd = (&shared * &big("6")).gcd(&(&shared * &big("-15")));
+        assert_eq!(gcd, &shared * &big("3"));
+    }
+
+    #[test]
+    fn test_limb_division_matches_multiplication() {
+        // Each estimated quotient limb is checked against the identity
+        // `a = q b + r`, `0 <= r < b`, over operands of many sizes.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            BigInt::from(state)
+        };
+        for len in 1..12 {
+            let mut a = next();
+            let mut b = next();
+            for step in 0..len {
+                a = &a.shl(64) + &next();
+                if step % 2 == 0 {
+                    b = &b.shl(37) + &next();
+                }
+            }
+            let (quotient, remainder) = a.div_rem(&b).unwrap();
+            assert_eq!(&(&quotient * &b) + &remainder, a);
+            assert!(!remainder.is_negative() && remainder < b);
+            assert_eq!(a.gcd(&b), b.gcd(&remainder));
+        }
+
+        // Estimates one too large, corrected by adding the divisor back.
+        let a = BigInt::from_parts(false, vec![0, 0, 0x8000_0000, 0x7fff_ffff]);
+        let b = BigInt::from_parts(false, vec![1, 0, 0x8000_0000]);
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+        assert_eq!(quotient, BigInt::from(0xffff_fffeu32));
+        assert_eq!(&(&quotient * &b) + &remainder, a);
+        assert!(remainder < b);
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert!((big("-4294967297").to_f64() + 4_294_967_297.0).abs() < 0.5);
+        let huge = BigInt::pow10(30);
+        assert!((huge.to_f64() / 1e30 - 1.0).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,419 @@
+//! A catalogue of triangle centres, addressable by their number in Clark
+//! Kimberling's Encyclopedia of Triangle Centers (ETC).
+//!
+//! Every entry is defined once by a *centre function* `f(a, b, c)` in the ETC
+//! convention: the barycentric coordinates of the centre are
+//! `f(a, b, c) : f(b, c, a) : f(c, a, b)`, where the second and third values
+//! come from cyclically relabelling the triangle. The function sees the side
+//! lengths and angles through [`Measures`].
+
+use crate::barycentric::Barycentric;
+use crate::geometry::angle_from_sides;
+use crate::point::Point;
+use crate::triangle::Triangle;
+use std::f64::consts::PI;
+
+/// Side lengths and angles of a triangle, labelled from one vertex.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Measures {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub angle_a: f64,
+    pub angle_b: f64,
+    pub angle_c: f64,
+}
+
+impl Measures {
+    #[must_use]
+    pub fn of(tri: &Triangle) -> Self {
+        let [a, b, c] = tri.side_lengths();
+        Self {
+            a,
+            b,
+            c,
+            angle_a: angle_from_sides(a, b, c),
+            angle_b: angle_from_sides(b, a, c),
+            angle_c: angle_from_sides(c, a, b),
+        }
+    }
+
+    /// The same triangle labelled from the next vertex: `a -> b -> c -> a`.
+    #[must_use]
+    pub const fn rotated(self) -> Self {
+        Self {
+            a: self.b,
+            b: self.c,
+            c: self.a,
+            angle_a: self.angle_b,
+            angle_b: self.angle_c,
+            angle_c: self.angle_a,
+        }
+    }
+
+    #[must_use]
+    pub fn semi_perimeter(&self) -> f64 {
+        (self.a + self.b + self.c) / 2.0
+    }
+
+    /// Conway's `S_A = (b² + c² - a²) / 2`.
+    fn conway_a(&self) -> f64 {
+        self.a
+            .mul_add(-self.a, self.b.mul_add(self.b, self.c * self.c))
+            / 2.0
+    }
+
+    /// Twice the area, from Heron's formula.
+    fn double_area(&self) -> f64 {
+        let s = self.semi_perimeter();
+        2.0 * (s * (s - self.a) * (s - self.b) * (s - self.c))
+            .max(0.0)
+            .sqrt()
+    }
+}
+
+/// One catalogue entry.
+#[derive(Debug, Copy, Clone)]
+pub struct CenterDef {
+    /// The ETC number, `None` for points that are not triangle centres in the
+    /// ETC sense, such as the Brocard points.
+    pub number: Option<u32>,
+    pub name: &'static str,
+    function: fn(&Measures) -> f64,
+}
+
+impl CenterDef {
+    /// Barycentric coordinates of this centre, not normalized.
+    #[must_use]
+    pub fn barycentric(&self, measures: &Measures) -> Barycentric {
+        let next = measures.rotated();
+        Barycentric::new(
+            (self.function)(measures),
+            (self.function)(&next),
+            (self.function)(&next.rotated()),
+        )
+    }
+
+    /// Locates this centre for `tri`. Returns `None` when the centre is
+    /// undefined for this shape, such as the Feuerbach point of an
+    /// equilateral triangle, or lies at infinity.
+    #[must_use]
+    pub fn evaluate(&self, tri: &Triangle) -> Option<Point> {
+        self.barycentric(&Measures::of(tri)).to_cartesian(tri)
+    }
+
+    /// `X(6)` style label, or the name for entries without a number.
+    #[must_use]
+    pub fn label(&self) -> String {
+        self.number
+            .map_or_else(|| self.name.to_string(), |number| format!("X({number})"))
+    }
+}
+
+/// `1 / sin(x)`, the ETC centre functions are usually written with it.
+fn csc(x: f64) -> f64 {
+    1.0 / x.sin()
+}
+
+/// Every catalogued centre, in ETC order.
+pub const CATALOGUE: [CenterDef; 18] = [
+    CenterDef {
+        number: Some(1),
+        name: "incenter",
+        function: |m| m.a,
+    },
+    CenterDef {
+        number: Some(2),
+        name: "centroid",
+        function: |_| 1.0,
+    },
+    CenterDef {
+        number: Some(3),
+        name: "circumcenter",
+        function: |m| m.a * m.a * m.conway_a(),
+    },
+    CenterDef {
+        number: Some(4),
+        name: "orthocenter",
+        // S_B S_C stays finite for right triangles, unlike tan A.
+        function: |m| {
+            let next = m.rotated();
+            next.conway_a() * next.rotated().conway_a()
+        },
+    },
+    CenterDef {
+        number: Some(5),
+        name: "nine-point center",
+        function: |m| {
+            let next = m.rotated();
+            let area2 = m.double_area();
+            area2.mul_add(area2, next.conway_a() * next.rotated().conway_a())
+        },
+    },
+    CenterDef {
+        number: Some(6),
+        name: "symmedian point",
+        function: |m| m.a * m.a,
+    },
+    CenterDef {
+        number: Some(7),
+        name: "Gergonne point",
+        function: |m| 1.0 / (m.semi_perimeter() - m.a),
+    },
+    CenterDef {
+        number: Some(8),
+        name: "Nagel point",
+        function: |m| m.semi_perimeter() - m.a,
+    },
+    CenterDef {
+        number: Some(9),
+        name: "Mittenpunkt",
+        function: |m| m.a * (m.semi_perimeter() - m.a),
+    },
+    CenterDef {
+        number: Some(10),
+        name: "Spieker center",
+        function: |m| m.b + m.c,
+    },
+    CenterDef {
+        number: Some(11),
+        name: "Feuerbach point",
+        function: |m| (m.b - m.c).powi(2) * (m.semi_perimeter() - m.a),
+    },
+    CenterDef {
+        number: Some(13),
+        name: "first Fermat point",
+        function: |m| m.a * csc(m.angle_a + PI / 3.0),
+    },
+    CenterDef {
+        number: Some(14),
+        name: "second Fermat point",
+        function: |m| m.a * csc(m.angle_a - PI / 3.0),
+    },
+    CenterDef {
+        number: Some(17),
+        name: "first Napoleon point",
+        function: |m| m.a * csc(m.angle_a + PI / 6.0),
+    },
+    CenterDef {
+        number: Some(18),
+        name: "second Napoleon point",
+        function: |m| m.a * csc(m.angle_a - PI / 6.0),
+    },
+    CenterDef {
+        number: Some(20),
+        name: "de Longchamps point",
+        function: |m| m.a * m.angle_b.cos().mul_add(-m.angle_c.cos(), m.angle_a.cos()),
+    },
+    CenterDef {
+        number: None,
+        name: "first Brocard point",
+        function: |m| 1.0 / (m.b * m.b),
+    },
+    CenterDef {
+        number: None,
+        name: "second Brocard point",
+        function: |m| 1.0 / (m.c * m.c),
+    },
+];
+
+/// Looks up a centre by its ETC number.
+#[must_use]
+pub fn by_number(number: u32) -> Option<&'static CenterDef> {
+    CATALOGUE.iter().find(|def| def.number == Some(number))
+}
+
+/// Looks up a centre by name, ignoring case.
+#[must_use]
+pub fn by_name(name: &str) -> Option<&'static CenterDef> {
+    CATALOGUE
+        .iter()
+        .find(|def| def.name.eq_ignore_ascii_case(name))
+}
+
+/// Evaluates the whole catalogue for `tri`.
+#[must_use]
+pub fn evaluate_all(tri: &Triangle) -> Vec<(&'static CenterDef, Option<Point>)> {
+    CATALOGUE
+        .iter()
+        .map(|def| (def, def.evaluate(tri)))
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::{compute_summary, foot_on_line, incenter};
+    use crate::test_support::{approx_eq, approx_eq_pt, scalene};
+
+    fn collinear(p: Point, q: Point, r: Point) -> bool {
+        (q - p).cross(r - p).abs() < 1e-9 * (q - p).length_sq().max(1.0)
+    }
+
+    fn at(number: u32, tri: &Triangle) -> Point {
+        by_number(number).unwrap().evaluate(tri).unwrap()
+    }
+
+    #[test]
+    fn test_classic_centres_match_summary() {
+        let triangles = [
+            scalene(),
+            // obtuse
+            Triangle::new(
+                Point::new(0.0, 0.0),
+                Point::new(10.0, 0.0),
+                Point::new(2.0, 1.5),
+            ),
+            // right angle at A
+            Triangle::new(
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(0.0, 3.0),
+            ),
+        ];
+        for tri in triangles {
+            let s = compute_summary(&tri);
+            assert!(approx_eq_pt(at(1, &tri), s.incenter));
+            assert!(approx_eq_pt(at(2, &tri), s.centroid));
+            assert!(approx_eq_pt(at(3, &tri), s.circumcenter));
+            assert!(approx_eq_pt(at(4, &tri), s.orthocenter));
+            assert!(approx_eq_pt(at(5, &tri), s.nine_point_center));
+        }
+    }
+
+    #[test]
+    fn test_incircle_related_centres() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+
+        // The Gergonne point lies on the cevian to the incircle touch point.
+        let touch_bc = foot_on_line(s.incenter, tri.b, tri.c).unwrap();
+        assert!(collinear(tri.a, touch_bc, at(7, &tri)));
+        // The Nagel point on the cevian to the A-excircle touch point.
+        assert!(collinear(tri.a, s.excircle_touch_a[0], at(8, &tri)));
+        // I, G and Na are collinear with GNa = 2 IG.
+        assert!(approx_eq_pt(
+            at(8, &tri),
+            s.centroid * 3.0 - s.incenter * 2.0
+        ));
+        // The Mittenpunkt lies on the line from an excenter to the midpoint of its side.
+        assert!(collinear(s.excenter_a, (tri.b + tri.c) / 2.0, at(9, &tri)));
+        assert!(collinear(s.excenter_b, (tri.a + tri.c) / 2.0, at(9, &tri)));
+        // The Spieker center is the incenter of the medial triangle.
+        let (ma, mb, mc) = (
+            (tri.b + tri.c) / 2.0,
+            (tri.a + tri.c) / 2.0,
+            (tri.a + tri.b) / 2.0,
+        );
+        let spieker = incenter(ma, mb, mc, s.side_a / 2.0, s.side_b / 2.0, s.side_c / 2.0).unwrap();
+        assert!(approx_eq_pt(at(10, &tri), spieker));
+        // The Feuerbach point is where the incircle touches the nine-point circle.
+        let feuerbach = at(11, &tri);
+        assert!(approx_eq(feuerbach.distance_to(s.incenter), s.inradius));
+        assert!(approx_eq(
+            feuerbach.distance_to(s.nine_point_center),
+            s.nine_point_radius
+        ));
+    }
+
+    #[test]
+    fn test_symmedian_distances_are_proportional_to_sides() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+        let k = at(6, &tri);
+        let to_a = k.distance_to(foot_on_line(k, tri.b, tri.c).unwrap());
+        let to_b = k.distance_to(foot_on_line(k, tri.c, tri.a).unwrap());
+        let to_c = k.distance_to(foot_on_line(k, tri.a, tri.b).unwrap());
+        assert!(approx_eq(to_a / s.side_a, to_b / s.side_b));
+        assert!(approx_eq(to_b / s.side_b, to_c / s.side_c));
+    }
+
+    #[test]
+    fn test_fermat_and_napoleon_points() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+
+        // Every side subtends 120 degrees at the first Fermat point.
+        let fermat = at(13, &tri);
+        let angle = |p: Point, q: Point| {
+            let (u, v) = (p - fermat, q - fermat);
+            (u.dot(v) / (u.length_sq() * v.length_sq()).sqrt()).acos()
+        };
+        for (p, q) in [(tri.a, tri.b), (tri.b, tri.c), (tri.c, tri.a)] {
+            assert!(approx_eq(angle(p, q), 2.0 * PI / 3.0));
+        }
+        assert!(by_number(14).unwrap().evaluate(&tri).is_some());
+
+        // The first Napoleon point joins each vertex to the centre of the
+        // equilateral triangle erected outwards on the opposite side.
+        let napoleon = at(17, &tri);
+        let outward_center = |p: Point, q: Point, away: Point, side: f64| {
+            let outward = foot_on_line(away, p, q).unwrap() - away;
+            let unit = outward / outward.length_sq().sqrt();
+            (p + q) / 2.0 + unit * (side / (2.0 * 3.0f64.sqrt()))
+        };
+        let center_a = outward_center(tri.b, tri.c, tri.a, s.side_a);
+        let center_b = outward_center(tri.c, tri.a, tri.b, s.side_b);
+        assert!(collinear(tri.a, center_a, napoleon));
+        assert!(collinear(tri.b, center_b, napoleon));
+        assert!(by_number(18).unwrap().evaluate(&tri).is_some());
+    }
+
+    #[test]
+    fn test_de_longchamps_and_brocard_points() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+        // L is the reflection of H in O.
+        assert!(approx_eq_pt(
+            at(20, &tri),
+            s.circumcenter * 2.0 - s.orthocenter
+        ));
+
+        // Cot of the Brocard angle is cot A + cot B + cot C.
+        let cot_sum =
+            1.0 / s.angle_a_rad.tan() + 1.0 / s.angle_b_rad.tan() + 1.0 / s.angle_c_rad.tan();
+        let brocard = (1.0 / cot_sum).atan();
+        let angle_at = |vertex: Point, towards: Point, point: Point| {
+            let (u, v) = (towards - vertex, point - vertex);
+            u.cross(v).abs().atan2(u.dot(v))
+        };
+
+        let first = by_name("first brocard point")
+            .unwrap()
+            .evaluate(&tri)
+            .unwrap();
+        assert!(approx_eq(angle_at(tri.a, tri.b, first), brocard));
+        assert!(approx_eq(angle_at(tri.b, tri.c, first), brocard));
+        assert!(approx_eq(angle_at(tri.c, tri.a, first), brocard));
+
+        let second = by_name("Second Brocard Point")
+            .unwrap()
+            .evaluate(&tri)
+            .unwrap();
+        assert!(approx_eq(angle_at(tri.a, tri.c, second), brocard));
+        assert!(approx_eq(angle_at(tri.b, tri.a, second), brocard));
+        assert!(approx_eq(angle_at(tri.c, tri.b, second), brocard));
+    }
+
+    #[test]
+    fn test_undefined_centres() {
+        let h = 3.0f64.sqrt() / 2.0;
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.5, h),
+        );
+        // Incircle and nine-point circle coincide, so there is no tangency point.
+        assert_eq!(by_number(11).unwrap().evaluate(&tri), None);
+        let centroid = Point::new(0.5, h / 3.0);
+        // Every other centre falls onto the common centre.
+        for (def, point) in evaluate_all(&tri) {
+            if let Some(point) = point {
+                assert!(approx_eq_pt(point, centroid), "{}", def.label());
+            }
+        }
+        assert!(by_number(12).is_none());
+    }
+}
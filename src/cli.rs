@@ -0,0 +1,1307 @@
+use crate::batch::BatchSource;
+use crate::output::OutputFormat;
+use crate::svg::SvgLayers;
+use crate::table::{Field, parse_fields};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use triale::derived::DerivedKind;
+use triale::exact::{ExactPoint, to_exact_point};
+use triale::point::{Point, PointCreateError, to_point};
+use triale::solve::{SolveInput, SsaInput};
+use triale::tolerance::Tolerance;
+use triale::transform::Transform;
+
+pub const USAGE: &str = "\
+Usage: triale [OPTIONS] [A B C]
+       triale [OPTIONS] --batch <FILE|->
+       triale [OPTIONS] --sss <a,b,c> | --sas <b,A,c> | --asa <A,c,B> | --aas <A,B,a>
+       triale [OPTIONS] --ssa <A,a,b>
+       triale locate [OPTIONS] <A B C> <POINT>...
+       triale compare [OPTIONS] <A B C> <D E F>
+
+Computes a full summary of the triangle with vertices A, B and C.
+Each vertex is written as 'x,y' (e.g. 0,0 4,0 0,3).
+When no vertices are given, triale prompts for them interactively.
+In batch mode every line of FILE (or stdin for '-') holds one triangle,
+as six numbers or three 'x,y' groups, and yields one result row.
+The solver options build the triangle from side lengths and angles instead,
+with A at the origin, B on the positive x-axis and C above it. Sides are
+named after the opposite vertex (a = BC, b = AC, c = AB) and angles are in
+degrees. --ssa reports whether zero, one or two triangles match and
+summarizes each of them.
+'locate' reports for every POINT whether it lies inside the triangle, on
+an edge or vertex, or outside, with the nearest point of the outline and
+the signed distance to it (negative inside).
+'compare' reports whether triangle ABC is congruent or similar to DEF,
+which vertices correspond and the scale, rotation and translation that
+carry ABC onto DEF.
+
+Options:
+      --a <x,y>    Vertex A
+      --b <x,y>    Vertex B
+      --c <x,y>    Vertex C
+      --batch <FILE|->
+                   Read one triangle per line from FILE or stdin
+      --sss <a,b,c>    Solve from three side lengths
+      --sas <b,A,c>    Solve from two sides and the angle between them
+      --asa <A,c,B>    Solve from two angles and the side between them
+      --aas <A,B,a>    Solve from two angles and the side opposite A
+      --ssa <A,a,b>    Solve from angle A, its opposite side and one other side
+      --transform <STEP>
+                   Transform the input before summarizing, one of rotate:DEG,
+                   scale:S, scale:SX,SY, translate:DX,DY, shear:KX,KY,
+                   reflect:x, reflect:y or matrix:A,B,C,D,TX,TY; repeat to
+                   apply several steps in order
+      --derived <KIND>
+                   Summarize a derived triangle instead: medial, orthic,
+                   intouch, excentral, tangential or anticomplementary
+      --pedal <x,y>    Summarize the pedal triangle of the point instead
+      --cevian <x,y>   Summarize the cevian triangle of the point instead
+      --antipedal <x,y>
+                   Summarize the antipedal triangle of the point instead
+      --tolerance <REL[,ABS]>
+                   Relative tolerance for every approximate comparison:
+                   collinearity, the side and angle classes, the solvers,
+                   derived triangles, point location, comparison and the
+                   verification (default 1e-10), optionally with an
+                   absolute floor
+      --format <FORMAT>
+                   Output format: text (default), json, csv or tsv
+      --fields <LIST>
+                   Comma separated csv/tsv columns, e.g. area,perimeter
+                   (line, side_a, angle_a_deg, centroid_x, circumradius, ...);
+                   batch tables always keep line and error
+      --svg <FILE>     Also draw the triangle, its centres and circles to FILE
+      --svg-show <LIST>
+                   Extra SVG layers: medians, altitudes, bisectors
+      --plot           Plot the triangle in the terminal before the summary
+      --plot-width <COLUMNS>
+                   Plot width, defaults to $COLUMNS or 80
+      --exact          Compute coordinates, squared sides, area, centres and
+                   classes with exact fractions; vertices may be written
+                   as fractions, e.g. 1/3,0.1
+  -h, --help       Print this help and exit
+  -V, --version    Print version information and exit
+";
+
+const VERTEX_LABELS: [&str; 3] = ["A", "B", "C"];
+/// Labels of the second triangle of `triale compare`.
+const OTHER_VERTEX_LABELS: [&str; 3] = ["D", "E", "F"];
+const SUBCOMMANDS: [&str; 2] = ["locate", "compare"];
+
+/// What the binary has been asked to do.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Interactive,
+    Summarize([Point; 3]),
+    /// Summarize with rational arithmetic, see `--exact`.
+    SummarizeExact(Box<[ExactPoint; 3]>),
+    Batch(BatchSource),
+    Solve(SolveInput),
+    /// The ambiguous case, which can yield several triangles.
+    SolveSsa(SsaInput),
+    /// Where each query point lies relative to the triangle.
+    Locate {
+        vertices: [Point; 3],
+        points: Vec<Point>,
+    },
+    /// How the first triangle relates to the second.
+    Compare {
+        first: [Point; 3],
+        second: [Point; 3],
+    },
+    Help,
+    Version,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    UnknownFlag {
+        flag: String,
+    },
+    MissingValue {
+        flag: String,
+    },
+    UnexpectedArgument {
+        got: String,
+    },
+    RepeatedVertex {
+        label: &'static str,
+    },
+    MissingVertex {
+        label: &'static str,
+    },
+    InvalidVertex {
+        label: &'static str,
+        source: PointCreateError,
+    },
+    ConflictingOptions {
+        first: &'static str,
+        second: &'static str,
+    },
+    InvalidValue {
+        flag: &'static str,
+        got: String,
+        expected: &'static str,
+    },
+    UnknownField {
+        name: String,
+    },
+    InvalidQueryPoint {
+        index: usize,
+        source: PointCreateError,
+    },
+    MissingQueryPoint,
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownFlag { flag } => write!(f, "Unknown option '{flag}'"),
+            Self::MissingValue { flag } => write!(f, "Option '{flag}' requires a value"),
+            Self::UnexpectedArgument { got } => write!(f, "Unexpected argument '{got}'"),
+            Self::RepeatedVertex { label } => {
+                write!(f, "Vertex {label} was given more than once")
+            }
+            Self::MissingVertex { label } => write!(f, "Vertex {label} is missing"),
+            Self::InvalidVertex { label, source } => write!(f, "Vertex {label}: {source}"),
+            Self::ConflictingOptions { first, second } => {
+                write!(f, "'{first}' cannot be combined with {second}")
+            }
+            Self::InvalidValue {
+                flag,
+                got,
+                expected,
+            } => write!(
+                f,
+                "Invalid value '{got}' for '{flag}', expected one of: {expected}"
+            ),
+            Self::UnknownField { name } => write!(f, "Unknown field '{name}' in '--fields'"),
+            Self::InvalidQueryPoint { index, source } => {
+                write!(f, "Query point {index}: {source}")
+            }
+            Self::MissingQueryPoint => write!(f, "'locate' needs at least one query point"),
+        }
+    }
+}
+
+impl Error for CliError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidVertex { source, .. } | Self::InvalidQueryPoint { source, .. } => {
+                Some(source)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Negative numbers such as `-1,2` are values, not flags.
+fn is_flag(arg: &str) -> bool {
+    let mut chars = arg.chars();
+    chars.next() == Some('-')
+        && chars
+            .next()
+            .is_some_and(|second| !(second.is_ascii_digit() || second == '.'))
+}
+
+/// Splits `--flag=value` into its two halves.
+fn split_inline_value(arg: &str) -> (&str, Option<&str>) {
+    match arg.split_once('=') {
+        Some((flag, value)) if arg.starts_with("--") => (flag, Some(value)),
+        _ => (arg, None),
+    }
+}
+
+/// Takes the value of `flag` either from `--flag=value` or from the next argument.
+fn take_value<I>(flag: &str, inline: Option<&str>, args: &mut I) -> Result<String, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    inline.map_or_else(
+        || {
+            args.next().ok_or_else(|| CliError::MissingValue {
+                flag: flag.to_string(),
+            })
+        },
+        |value| Ok(value.to_string()),
+    )
+}
+
+/// Parses exactly `N` comma separated finite numbers.
+fn parse_numbers<const N: usize>(raw: &str) -> Option<[f64; N]> {
+    let values: Vec<f64> = raw
+        .split(',')
+        .map(|part| part.trim().parse::<f64>().ok().filter(|v| v.is_finite()))
+        .collect::<Option<_>>()?;
+    values.try_into().ok()
+}
+
+/// Builds the solver command for one of the `--sss`, `--sas`, `--asa`,
+/// `--aas` and `--ssa` flags. Angles on the command line are given in degrees.
+fn parse_solve_input(flag: &str, raw: String) -> Result<(&'static str, Command), CliError> {
+    let (flag, expected) = match flag {
+        "--sss" => ("--sss", "three side lengths 'a,b,c'"),
+        "--sas" => ("--sas", "'b,A,c' with the angle in degrees"),
+        "--asa" => ("--asa", "'A,c,B' with angles in degrees"),
+        "--aas" => ("--aas", "'A,B,a' with angles in degrees"),
+        _ => ("--ssa", "'A,a,b' with the angle in degrees"),
+    };
+    let Some([first, second, third]) = parse_numbers(&raw) else {
+        return Err(CliError::InvalidValue {
+            flag,
+            got: raw,
+            expected,
+        });
+    };
+
+    let input = match flag {
+        "--sss" => SolveInput::Sss {
+            a: first,
+            b: second,
+            c: third,
+        },
+        "--sas" => SolveInput::Sas {
+            b: first,
+            angle_a: second.to_radians(),
+            c: third,
+        },
+        "--asa" => SolveInput::Asa {
+            angle_a: first.to_radians(),
+            c: second,
+            angle_b: third.to_radians(),
+        },
+        "--aas" => SolveInput::Aas {
+            angle_a: first.to_radians(),
+            angle_b: second.to_radians(),
+            a: third,
+        },
+        _ => {
+            let input = SsaInput {
+                angle_a: first.to_radians(),
+                a: second,
+                b: third,
+            };
+            return Ok((flag, Command::SolveSsa(input)));
+        }
+    };
+    Ok((flag, Command::Solve(input)))
+}
+
+/// Everything that changes how results are produced, independent of the command.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Options {
+    pub format: OutputFormat,
+    /// Columns for csv/tsv output; `None` selects every column.
+    pub fields: Option<Vec<Field>>,
+    /// Where to write the SVG drawing of a single triangle.
+    pub svg: Option<PathBuf>,
+    pub svg_layers: SvgLayers,
+    /// Draw a text-mode plot before the text summary.
+    pub plot: bool,
+    pub plot_columns: Option<usize>,
+    /// Applied to the input triangle before anything else.
+    pub transform: Option<Transform>,
+    /// Summarize this triangle derived from the input instead of the input.
+    pub derived: Option<DerivedKind>,
+    /// Decides collinearity, the classifications and the verification.
+    pub tolerance: Tolerance,
+}
+
+/// A fully parsed invocation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cli {
+    pub command: Command,
+    pub options: Options,
+}
+
+#[derive(Debug, Default)]
+struct Parser {
+    /// Vertices as written; they are parsed once `--exact` is known.
+    slots: [Option<String>; 3],
+    positional: Vec<String>,
+    batch: Option<BatchSource>,
+    /// The solver command together with the flag that requested it.
+    solve: Option<(&'static str, Command)>,
+    /// Set when the first argument names a subcommand.
+    subcommand: Option<&'static str>,
+    /// The flag that selected `options.derived`.
+    derived: Option<&'static str>,
+    exact: bool,
+    options: Options,
+}
+
+impl Parser {
+    fn set_vertex(&mut self, idx: usize, raw: &str) -> Result<(), CliError> {
+        let label = VERTEX_LABELS[idx];
+        if self.slots[idx].is_some() {
+            return Err(CliError::RepeatedVertex { label });
+        }
+        self.slots[idx] = Some(raw.to_string());
+        Ok(())
+    }
+
+    fn set_solve(&mut self, flag: &'static str, command: Command) -> Result<(), CliError> {
+        if let Some((first, _)) = self.solve {
+            return Err(CliError::ConflictingOptions {
+                first,
+                second: flag,
+            });
+        }
+        self.solve = Some((flag, command));
+        Ok(())
+    }
+
+    const fn set_derived(&mut self, flag: &'static str, kind: DerivedKind) -> Result<(), CliError> {
+        if let Some(first) = self.derived {
+            return Err(CliError::ConflictingOptions {
+                first,
+                second: flag,
+            });
+        }
+        self.derived = Some(flag);
+        self.options.derived = Some(kind);
+        Ok(())
+    }
+
+    /// Applies one flag. Returns a command when the flag ends parsing early.
+    fn apply_flag<I>(
+        &mut self,
+        flag: &str,
+        inline: Option<&str>,
+        args: &mut I,
+    ) -> Result<Option<Command>, CliError>
+    where
+        I: Iterator<Item = String>,
+    {
+        match flag {
+            "-h" | "--help" => return Ok(Some(Command::Help)),
+            "-V" | "--version" => return Ok(Some(Command::Version)),
+            "--a" => self.set_vertex(0, &take_value(flag, inline, args)?)?,
+            "--b" => self.set_vertex(1, &take_value(flag, inline, args)?)?,
+            "--c" => self.set_vertex(2, &take_value(flag, inline, args)?)?,
+            "--batch" => {
+                self.batch = Some(BatchSource::from_arg(&take_value(flag, inline, args)?));
+            }
+            "--sss" | "--sas" | "--asa" | "--aas" | "--ssa" => {
+                let value = take_value(flag, inline, args)?;
+                let (flag, command) = parse_solve_input(flag, value)?;
+                self.set_solve(flag, command)?;
+            }
+            "--format" => {
+                let value = take_value(flag, inline, args)?;
+                self.options.format =
+                    OutputFormat::from_name(&value).ok_or(CliError::InvalidValue {
+                        flag: "--format",
+                        got: value,
+                        expected: OutputFormat::NAMES,
+                    })?;
+            }
+            "--derived" => {
+                let value = take_value(flag, inline, args)?;
+                let kind = DerivedKind::from_name(&value).ok_or(CliError::InvalidValue {
+                    flag: "--derived",
+                    got: value,
+                    expected: DerivedKind::NAMES,
+                })?;
+                self.set_derived("--derived", kind)?;
+            }
+            "--transform" => {
+                let value = take_value(flag, inline, args)?;
+                let step = Transform::from_spec(&value).ok_or(CliError::InvalidValue {
+                    flag: "--transform",
+                    got: value,
+                    expected: Transform::SPECS,
+                })?;
+                let composed = self.options.transform.unwrap_or_default().then(step);
+                self.options.transform = Some(composed);
+            }
+            "--pedal" | "--cevian" | "--antipedal" => {
+                let (flag, kind): (&'static str, fn(Point) -> DerivedKind) = match flag {
+                    "--pedal" => ("--pedal", DerivedKind::Pedal),
+                    "--cevian" => ("--cevian", DerivedKind::Cevian),
+                    _ => ("--antipedal", DerivedKind::Antipedal),
+                };
+                let value = take_value(flag, inline, args)?;
+                let point = to_point(&value).map_err(|_| CliError::InvalidValue {
+                    flag,
+                    got: value,
+                    expected: "a point 'x,y'",
+                })?;
+                self.set_derived(flag, kind(point))?;
+            }
+            "--tolerance" => {
+                self.options.tolerance = parse_tolerance(take_value(flag, inline, args)?)?;
+            }
+            "--fields" => {
+                let value = take_value(flag, inline, args)?;
+                let fields =
+                    parse_fields(&value).map_err(|name| CliError::UnknownField { name })?;
+                if fields.is_empty() {
+                    return Err(CliError::MissingValue {
+                        flag: flag.to_string(),
+                    });
+                }
+                self.options.fields = Some(fields);
+            }
+            "--svg" => self.options.svg = Some(PathBuf::from(take_value(flag, inline, args)?)),
+            "--svg-show" => {
+                let value = take_value(flag, inline, args)?;
+                self.options.svg_layers =
+                    SvgLayers::from_list(&value).map_err(|got| CliError::InvalidValue {
+                        flag: "--svg-show",
+                        got,
+                        expected: SvgLayers::NAMES,
+                    })?;
+            }
+            "--plot" => self.options.plot = true,
+            "--exact" => self.exact = true,
+            "--plot-width" => {
+                self.options.plot = true;
+                self.options.plot_columns =
+                    Some(parse_plot_width(take_value(flag, inline, args)?)?);
+            }
+            _ => {
+                return Err(CliError::UnknownFlag {
+                    flag: flag.to_string(),
+                });
+            }
+        }
+        Ok(None)
+    }
+
+    fn finish(mut self) -> Result<Cli, CliError> {
+        if self.options.fields.is_some() && self.options.format.delimiter().is_none() {
+            return Err(CliError::ConflictingOptions {
+                first: "--fields",
+                second: "text or json output",
+            });
+        }
+        if self.options.plot && self.options.format != OutputFormat::Text {
+            return Err(CliError::ConflictingOptions {
+                first: "--plot",
+                second: "json, csv or tsv output",
+            });
+        }
+
+        if self.exact {
+            self.check_exact_options()?;
+        }
+        if let Some(name) = self.subcommand {
+            return self.finish_subcommand(name);
+        }
+
+        let has_vertices = !self.positional.is_empty() || self.slots.iter().any(Option::is_some);
+        if let Some((flag, command)) = self.solve.take() {
+            if has_vertices {
+                return Err(CliError::ConflictingOptions {
+                    first: flag,
+                    second: "vertex arguments",
+                });
+            }
+            if self.batch.is_some() {
+                return Err(CliError::ConflictingOptions {
+                    first: flag,
+                    second: "--batch",
+                });
+            }
+            return Ok(self.with_command(command));
+        }
+
+        if let Some(source) = self.batch.take() {
+            if has_vertices {
+                return Err(CliError::ConflictingOptions {
+                    first: "--batch",
+                    second: "vertex arguments",
+                });
+            }
+            if self.options.svg.is_some() {
+                return Err(CliError::ConflictingOptions {
+                    first: "--svg",
+                    second: "--batch",
+                });
+            }
+            if self.options.plot {
+                return Err(CliError::ConflictingOptions {
+                    first: "--plot",
+                    second: "--batch",
+                });
+            }
+            if let Some(flag) = self.derived {
+                return Err(CliError::ConflictingOptions {
+                    first: flag,
+                    second: "--batch",
+                });
+            }
+            if self.options.transform.is_some() {
+                return Err(CliError::ConflictingOptions {
+                    first: "--transform",
+                    second: "--batch",
+                });
+            }
+            return Ok(self.with_command(Command::Batch(source)));
+        }
+
+        self.finish_vertices()
+    }
+
+    /// Fills the vertices from positional arguments and parses them, or falls
+    /// back to prompting when none were given.
+    fn finish_vertices(mut self) -> Result<Cli, CliError> {
+        for raw in std::mem::take(&mut self.positional) {
+            let Some(idx) = self.slots.iter().position(Option::is_none) else {
+                return Err(CliError::UnexpectedArgument { got: raw });
+            };
+            self.set_vertex(idx, &raw)?;
+        }
+
+        if self.slots.iter().all(Option::is_none) {
+            if self.exact {
+                return Err(CliError::ConflictingOptions {
+                    first: "--exact",
+                    second: "interactive input",
+                });
+            }
+            return Ok(self.with_command(Command::Interactive));
+        }
+
+        let mut points = [Point::zero(); 3];
+        let mut exact_points: [ExactPoint; 3] = Default::default();
+        for (idx, label) in VERTEX_LABELS.into_iter().enumerate() {
+            let raw = self.slots[idx]
+                .as_deref()
+                .ok_or(CliError::MissingVertex { label })?;
+            if self.exact {
+                exact_points[idx] = to_exact_point(raw)
+                    .map_err(|source| CliError::InvalidVertex { label, source })?;
+            } else {
+                points[idx] = parse_vertex(raw, label)?;
+            }
+        }
+        let command = if self.exact {
+            Command::SummarizeExact(Box::new(exact_points))
+        } else {
+            Command::Summarize(points)
+        };
+        Ok(self.with_command(command))
+    }
+
+    /// Rejects options that `--exact` cannot honour: it summarizes a single
+    /// triangle given by its vertices, without deriving or transforming it.
+    fn check_exact_options(&self) -> Result<(), CliError> {
+        let conflicts = [
+            self.subcommand,
+            self.solve.as_ref().map(|(flag, _)| *flag),
+            self.batch.as_ref().map(|_| "--batch"),
+            self.derived,
+            self.options.transform.as_ref().map(|_| "--transform"),
+            self.options.format.delimiter().map(|_| "csv or tsv output"),
+        ];
+        conflicts
+            .into_iter()
+            .flatten()
+            .next()
+            .map_or(Ok(()), |second| {
+                Err(CliError::ConflictingOptions {
+                    first: "--exact",
+                    second,
+                })
+            })
+    }
+
+    /// Rejects options that only make sense for a single summary.
+    fn check_subcommand_options(&self, name: &'static str) -> Result<(), CliError> {
+        let conflicts = [
+            self.solve.as_ref().map(|(flag, _)| *flag),
+            self.batch.as_ref().map(|_| "--batch"),
+            self.options.svg.as_ref().map(|_| "--svg"),
+            self.options.plot.then_some("--plot"),
+            self.options.fields.as_ref().map(|_| "--fields"),
+            self.derived,
+            self.options.transform.as_ref().map(|_| "--transform"),
+        ];
+        conflicts
+            .into_iter()
+            .flatten()
+            .next()
+            .map_or(Ok(()), |flag| {
+                Err(CliError::ConflictingOptions {
+                    first: name,
+                    second: flag,
+                })
+            })
+    }
+
+    fn finish_subcommand(mut self, name: &'static str) -> Result<Cli, CliError> {
+        self.check_subcommand_options(name)?;
+
+        // Positional arguments fill the missing vertices first.
+        let mut positional = std::mem::take(&mut self.positional).into_iter();
+        let mut vertices = [Point::zero(); 3];
+        for (idx, (slot, vertex)) in self.slots.iter().zip(vertices.iter_mut()).enumerate() {
+            *vertex = match slot {
+                Some(raw) => parse_vertex(raw, VERTEX_LABELS[idx])?,
+                None => positional_vertex(&mut positional, VERTEX_LABELS[idx])?,
+            };
+        }
+
+        if name == "compare" {
+            let mut second = [Point::zero(); 3];
+            for (vertex, label) in second.iter_mut().zip(OTHER_VERTEX_LABELS) {
+                *vertex = positional_vertex(&mut positional, label)?;
+            }
+            if let Some(got) = positional.next() {
+                return Err(CliError::UnexpectedArgument { got });
+            }
+            return Ok(self.with_command(Command::Compare {
+                first: vertices,
+                second,
+            }));
+        }
+
+        let points = positional
+            .enumerate()
+            .map(|(idx, raw)| {
+                to_point(&raw).map_err(|source| CliError::InvalidQueryPoint {
+                    index: idx + 1,
+                    source,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if points.is_empty() {
+            return Err(CliError::MissingQueryPoint);
+        }
+        Ok(self.with_command(Command::Locate { vertices, points }))
+    }
+
+    fn with_command(self, command: Command) -> Cli {
+        Cli {
+            command,
+            options: self.options,
+        }
+    }
+}
+
+fn parse_plot_width(value: String) -> Result<usize, CliError> {
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|&cols| cols > 0)
+        .ok_or(CliError::InvalidValue {
+            flag: "--plot-width",
+            got: value,
+            expected: "a positive number of columns",
+        })
+}
+
+fn parse_tolerance(value: String) -> Result<Tolerance, CliError> {
+    Tolerance::from_spec(&value).ok_or(CliError::InvalidValue {
+        flag: "--tolerance",
+        got: value,
+        expected: Tolerance::SPECS,
+    })
+}
+
+/// Parses the next positional argument as the vertex `label`.
+fn positional_vertex<I>(positional: &mut I, label: &'static str) -> Result<Point, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let raw = positional.next().ok_or(CliError::MissingVertex { label })?;
+    parse_vertex(&raw, label)
+}
+
+fn parse_vertex(raw: &str, label: &'static str) -> Result<Point, CliError> {
+    to_point(raw).map_err(|source| CliError::InvalidVertex { label, source })
+}
+
+/// Parses the process arguments (without the program name) into a [`Cli`].
+///
+/// # Errors
+/// Returns a [`CliError`] for unknown flags, invalid option values, missing or
+/// repeated vertices and vertices that are not valid points.
+pub fn parse_args<I>(args: I) -> Result<Cli, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut parser = Parser::default();
+    let mut args = args.into_iter().peekable();
+    let mut only_positional = false;
+
+    if let Some(arg) = args.next_if(|arg| SUBCOMMANDS.contains(&arg.as_str())) {
+        parser.subcommand = SUBCOMMANDS.into_iter().find(|name| *name == arg);
+    }
+
+    while let Some(arg) = args.next() {
+        if only_positional || !is_flag(&arg) {
+            parser.positional.push(arg);
+            continue;
+        }
+        if arg == "--" {
+            only_positional = true;
+            continue;
+        }
+
+        let (flag, inline) = split_inline_value(&arg);
+        if let Some(command) = parser.apply_flag(flag, inline, &mut args)? {
+            return Ok(parser.with_command(command));
+        }
+    }
+
+    parser.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_cli(args: &[&str]) -> Result<Cli, CliError> {
+        parse_args(args.iter().map(ToString::to_string))
+    }
+
+    fn parse(args: &[&str]) -> Result<Command, CliError> {
+        parse_cli(args).map(|cli| cli.command)
+    }
+
+    fn right_triangle() -> Command {
+        Command::Summarize([
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        ])
+    }
+
+    #[test]
+    fn test_no_arguments_is_interactive() {
+        assert_eq!(parse(&[]), Ok(Command::Interactive));
+    }
+
+    #[test]
+    fn test_named_and_positional_vertices() {
+        assert_eq!(
+            parse(&["--a", "0,0", "--b", "4,0", "--c", "0,3"]),
+            Ok(right_triangle())
+        );
+        assert_eq!(parse(&["0,0", "4,0", "0,3"]), Ok(right_triangle()));
+        assert_eq!(
+            parse(&["--c=0,3", "--a", "0, 0", "4,0"]),
+            Ok(right_triangle())
+        );
+    }
+
+    #[test]
+    fn test_negative_coordinates_are_not_flags() {
+        assert_eq!(
+            parse(&["-1,0", "--b", "-.5,2", "3,-1"]),
+            Ok(Command::Summarize([
+                Point::new(-1.0, 0.0),
+                Point::new(-0.5, 2.0),
+                Point::new(3.0, -1.0),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_batch_source() {
+        assert_eq!(
+            parse(&["--batch", "-"]),
+            Ok(Command::Batch(BatchSource::Stdin))
+        );
+        assert_eq!(
+            parse(&["--batch=triangles.txt"]),
+            Ok(Command::Batch(BatchSource::File("triangles.txt".into())))
+        );
+        assert_eq!(
+            parse(&["--batch", "-", "0,0"]),
+            Err(CliError::ConflictingOptions {
+                first: "--batch",
+                second: "vertex arguments"
+            })
+        );
+    }
+
+    #[test]
+    fn test_output_format() {
+        assert_eq!(
+            parse_cli(&["0,0", "4,0", "0,3"]).map(|cli| cli.options.format),
+            Ok(OutputFormat::Text)
+        );
+        assert_eq!(
+            parse_cli(&["--format", "JSON", "0,0", "4,0", "0,3"]).map(|cli| cli.options.format),
+            Ok(OutputFormat::Json)
+        );
+        assert_eq!(
+            parse_cli(&["--format=xml"]),
+            Err(CliError::InvalidValue {
+                flag: "--format",
+                got: "xml".to_string(),
+                expected: OutputFormat::NAMES
+            })
+        );
+    }
+
+    #[test]
+    fn test_fields() {
+        assert_eq!(
+            parse_cli(&[
+                "--format",
+                "csv",
+                "--fields",
+                "area,perimeter",
+                "--batch",
+                "-"
+            ])
+            .map(|cli| cli.options.fields),
+            Ok(Some(vec![Field::Area, Field::Perimeter]))
+        );
+        assert_eq!(
+            parse_cli(&["--fields", "area,volume"]),
+            Err(CliError::UnknownField {
+                name: "volume".to_string()
+            })
+        );
+        assert_eq!(
+            parse_cli(&["--fields", " , "]),
+            Err(CliError::MissingValue {
+                flag: "--fields".to_string()
+            })
+        );
+        assert_eq!(
+            parse_cli(&["--fields", "area", "0,0", "4,0", "0,3"]),
+            Err(CliError::ConflictingOptions {
+                first: "--fields",
+                second: "text or json output"
+            })
+        );
+    }
+
+    #[test]
+    fn test_svg_options() {
+        let cli = parse_cli(&[
+            "--svg",
+            "out.svg",
+            "--svg-show=altitudes",
+            "0,0",
+            "4,0",
+            "0,3",
+        ]);
+        assert_eq!(
+            cli.as_ref().map(|cli| cli.options.svg.clone()),
+            Ok(Some(PathBuf::from("out.svg")))
+        );
+        assert_eq!(cli.map(|cli| cli.options.svg_layers.altitudes), Ok(true));
+        assert_eq!(
+            parse_cli(&["--svg", "out.svg", "--batch", "-"]),
+            Err(CliError::ConflictingOptions {
+                first: "--svg",
+                second: "--batch"
+            })
+        );
+        assert!(matches!(
+            parse_cli(&["--svg-show", "circles"]),
+            Err(CliError::InvalidValue {
+                flag: "--svg-show",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_plot_options() {
+        let cli = parse_cli(&["--plot-width", "60", "0,0", "4,0", "0,3"]);
+        assert_eq!(
+            cli.map(|cli| (cli.options.plot, cli.options.plot_columns)),
+            Ok((true, Some(60)))
+        );
+        assert!(matches!(
+            parse_cli(&["--plot-width", "0"]),
+            Err(CliError::InvalidValue {
+                flag: "--plot-width",
+                ..
+            })
+        ));
+        assert_eq!(
+            parse_cli(&["--plot", "--format", "json", "0,0", "4,0", "0,3"]),
+            Err(CliError::ConflictingOptions {
+                first: "--plot",
+                second: "json, csv or tsv output"
+            })
+        );
+    }
+
+    #[test]
+    fn test_compare_subcommand() {
+        let first = [
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        ];
+        let second = [
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 5.0),
+            Point::new(-2.0, 1.0),
+        ];
+        assert_eq!(
+            parse(&["compare", "0,0", "4,0", "0,3", "1,1", "1,5", "-2,1"]),
+            Ok(Command::Compare { first, second })
+        );
+        assert_eq!(
+            parse(&["compare", "--c", "0,3", "0,0", "4,0", "1,1", "1,5", "-2,1"]),
+            Ok(Command::Compare { first, second })
+        );
+        assert_eq!(
+            parse(&["compare", "0,0", "4,0", "0,3", "1,1", "1,5"]),
+            Err(CliError::MissingVertex { label: "F" })
+        );
+        assert!(matches!(
+            parse(&["compare", "0,0", "4,0", "0,3", "1,1", "x", "-2,1"]),
+            Err(CliError::InvalidVertex { label: "E", .. })
+        ));
+        assert_eq!(
+            parse(&["compare", "0,0", "4,0", "0,3", "1,1", "1,5", "-2,1", "7,7"]),
+            Err(CliError::UnexpectedArgument {
+                got: "7,7".to_string()
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "compare", "--plot", "0,0", "4,0", "0,3", "1,1", "1,5", "-2,1"
+            ]),
+            Err(CliError::ConflictingOptions {
+                first: "compare",
+                second: "--plot"
+            })
+        );
+    }
+
+    #[test]
+    fn test_derived_option() {
+        let cli = parse_cli(&["0,0", "4,0", "0,3", "--derived", "Orthic"]);
+        assert_eq!(
+            cli.map(|cli| cli.options.derived),
+            Ok(Some(DerivedKind::Orthic))
+        );
+        assert!(matches!(
+            parse_cli(&["--derived=pedal"]),
+            Err(CliError::InvalidValue {
+                flag: "--derived",
+                ..
+            })
+        ));
+        assert_eq!(
+            parse_cli(&["--derived", "medial", "--batch", "-"]),
+            Err(CliError::ConflictingOptions {
+                first: "--derived",
+                second: "--batch"
+            })
+        );
+    }
+
+    #[test]
+    fn test_point_derived_options() {
+        let cli = parse_cli(&["0,0", "4,0", "0,3", "--cevian=1,1"]);
+        assert_eq!(
+            cli.map(|cli| cli.options.derived),
+            Ok(Some(DerivedKind::Cevian(Point::new(1.0, 1.0))))
+        );
+        assert!(matches!(
+            parse_cli(&["--pedal", "1"]),
+            Err(CliError::InvalidValue {
+                flag: "--pedal",
+                ..
+            })
+        ));
+        assert_eq!(
+            parse_cli(&["--antipedal", "1,1", "--derived", "medial"]),
+            Err(CliError::ConflictingOptions {
+                first: "--antipedal",
+                second: "--derived"
+            })
+        );
+        assert_eq!(
+            parse_cli(&["--pedal", "1,1", "--batch", "-"]),
+            Err(CliError::ConflictingOptions {
+                first: "--pedal",
+                second: "--batch"
+            })
+        );
+    }
+
+    #[test]
+    fn test_transform_option() {
+        let cli = parse_cli(&[
+            "0,0",
+            "4,0",
+            "0,3",
+            "--transform",
+            "scale:2",
+            "--transform=translate:1,0",
+        ]);
+        assert_eq!(
+            cli.map(|cli| cli.options.transform),
+            Ok(Some(Transform::new(2.0, 0.0, 0.0, 2.0, 1.0, 0.0)))
+        );
+        assert!(matches!(
+            parse_cli(&["--transform", "spin:3"]),
+            Err(CliError::InvalidValue {
+                flag: "--transform",
+                ..
+            })
+        ));
+        assert_eq!(
+            parse_cli(&["--transform", "scale:2", "--batch", "-"]),
+            Err(CliError::ConflictingOptions {
+                first: "--transform",
+                second: "--batch"
+            })
+        );
+    }
+
+    #[test]
+    fn test_tolerance_option() {
+        assert_eq!(
+            parse_cli(&["0,0", "4,0", "0,3"]).map(|cli| cli.options.tolerance),
+            Ok(Tolerance::default())
+        );
+        assert_eq!(
+            parse_cli(&["--tolerance", "1e-6,1e-12", "--batch", "-"])
+                .map(|cli| cli.options.tolerance),
+            Ok(Tolerance::new(1e-6, 1e-12))
+        );
+        assert!(matches!(
+            parse_cli(&["--tolerance=-1"]),
+            Err(CliError::InvalidValue {
+                flag: "--tolerance",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_exact_option() {
+        // --exact may follow the vertices it applies to.
+        let vertices =
+            parse(&["1/3,0", "--c", "-1/2, 0.5", "0,0.1", "--exact"]).map(
+                |command| match command {
+                    Command::SummarizeExact(points) => {
+                        points.map(|point| point.to_string()).to_vec()
+                    }
+                    _ => Vec::new(),
+                },
+            );
+        assert_eq!(
+            vertices,
+            Ok(vec![
+                "(1/3, 0)".to_string(),
+                "(0, 1/10)".to_string(),
+                "(-1/2, 1/2)".to_string(),
+            ])
+        );
+        assert!(matches!(
+            parse(&["1/3,0", "0,0", "0,1"]),
+            Err(CliError::InvalidVertex { label: "A", .. })
+        ));
+        assert!(matches!(
+            parse(&["--exact", "0,0", "1/0,0", "0,1"]),
+            Err(CliError::InvalidVertex { label: "B", .. })
+        ));
+        for (args, second) in [
+            (&["--exact", "--batch", "-"][..], "--batch"),
+            (&["--exact", "--sss", "3,4,5"][..], "--sss"),
+            (
+                &["locate", "--exact", "0,0", "1,0", "0,1", "1,1"][..],
+                "locate",
+            ),
+            (
+                &["--exact", "--derived", "medial", "0,0", "1,0", "0,1"][..],
+                "--derived",
+            ),
+            (
+                &["--exact", "--format", "csv", "0,0", "1,0", "0,1"][..],
+                "csv or tsv output",
+            ),
+            (&["--exact"][..], "interactive input"),
+        ] {
+            assert_eq!(
+                parse(args),
+                Err(CliError::ConflictingOptions {
+                    first: "--exact",
+                    second
+                }),
+                "{args:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_inputs() {
+        assert_eq!(
+            parse(&["--sss", "5, 3, 4"]),
+            Ok(Command::Solve(SolveInput::Sss {
+                a: 5.0,
+                b: 3.0,
+                c: 4.0
+            }))
+        );
+        assert!(matches!(
+            parse(&["--sss", "5,3"]),
+            Err(CliError::InvalidValue { flag: "--sss", .. })
+        ));
+        assert!(matches!(
+            parse(&["--sss", "5,3,x"]),
+            Err(CliError::InvalidValue { flag: "--sss", .. })
+        ));
+        assert_eq!(
+            parse(&["--sas", "3,90,4"]),
+            Ok(Command::Solve(SolveInput::Sas {
+                b: 3.0,
+                angle_a: 90f64.to_radians(),
+                c: 4.0
+            }))
+        );
+        assert_eq!(
+            parse(&["--asa=60,2,60"]),
+            Ok(Command::Solve(SolveInput::Asa {
+                angle_a: 60f64.to_radians(),
+                c: 2.0,
+                angle_b: 60f64.to_radians()
+            }))
+        );
+        assert_eq!(
+            parse(&["--aas", "30,60,1"]),
+            Ok(Command::Solve(SolveInput::Aas {
+                angle_a: 30f64.to_radians(),
+                angle_b: 60f64.to_radians(),
+                a: 1.0
+            }))
+        );
+        assert_eq!(
+            parse(&["--ssa", "30,3,5"]),
+            Ok(Command::SolveSsa(SsaInput {
+                angle_a: 30f64.to_radians(),
+                a: 3.0,
+                b: 5.0
+            }))
+        );
+        assert_eq!(
+            parse(&["--ssa", "30,3,5", "--sas", "3,90,4"]),
+            Err(CliError::ConflictingOptions {
+                first: "--ssa",
+                second: "--sas"
+            })
+        );
+        assert_eq!(
+            parse(&["--sss", "5,3,4", "--aas", "30,60,1"]),
+            Err(CliError::ConflictingOptions {
+                first: "--sss",
+                second: "--aas"
+            })
+        );
+        assert_eq!(
+            parse(&["--sss", "5,3,4", "0,0"]),
+            Err(CliError::ConflictingOptions {
+                first: "--sss",
+                second: "vertex arguments"
+            })
+        );
+        assert_eq!(
+            parse(&["--sss", "5,3,4", "--batch", "-"]),
+            Err(CliError::ConflictingOptions {
+                first: "--sss",
+                second: "--batch"
+            })
+        );
+    }
+
+    #[test]
+    fn test_locate_subcommand() {
+        assert_eq!(
+            parse(&["locate", "0,0", "4,0", "0,3", "1,1", "-1,5"]),
+            Ok(Command::Locate {
+                vertices: [
+                    Point::new(0.0, 0.0),
+                    Point::new(4.0, 0.0),
+                    Point::new(0.0, 3.0),
+                ],
+                points: vec![Point::new(1.0, 1.0), Point::new(-1.0, 5.0)],
+            })
+        );
+        // Named vertices leave every positional argument to the queries.
+        assert_eq!(
+            parse(&["locate", "--a", "0,0", "--b", "4,0", "--c", "0,3", "1,1"]),
+            Ok(Command::Locate {
+                vertices: [
+                    Point::new(0.0, 0.0),
+                    Point::new(4.0, 0.0),
+                    Point::new(0.0, 3.0),
+                ],
+                points: vec![Point::new(1.0, 1.0)],
+            })
+        );
+        assert_eq!(
+            parse(&["locate", "0,0", "4,0", "0,3"]),
+            Err(CliError::MissingQueryPoint)
+        );
+        assert_eq!(
+            parse(&["locate", "0,0", "4,0"]),
+            Err(CliError::MissingVertex { label: "C" })
+        );
+        assert!(matches!(
+            parse(&["locate", "0,0", "4,0", "0,3", "1,1", "2"]),
+            Err(CliError::InvalidQueryPoint { index: 2, .. })
+        ));
+        assert_eq!(
+            parse(&["locate", "0,0", "4,0", "0,3", "1,1", "--svg", "out.svg"]),
+            Err(CliError::ConflictingOptions {
+                first: "locate",
+                second: "--svg"
+            })
+        );
+        // Only the first argument names a subcommand.
+        assert!(matches!(
+            parse(&["0,0", "locate"]),
+            Err(CliError::InvalidVertex { label: "B", .. })
+        ));
+    }
+
+    #[test]
+    fn test_help_and_version() {
+        assert_eq!(parse(&["0,0", "--help"]), Ok(Command::Help));
+        assert_eq!(parse(&["-V"]), Ok(Command::Version));
+    }
+
+    #[test]
+    fn test_argument_errors() {
+        assert_eq!(
+            parse(&["--d", "1,1"]),
+            Err(CliError::UnknownFlag {
+                flag: "--d".to_string()
+            })
+        );
+        assert_eq!(
+            parse(&["--a"]),
+            Err(CliError::MissingValue {
+                flag: "--a".to_string()
+            })
+        );
+        assert_eq!(
+            parse(&["0,0", "4,0"]),
+            Err(CliError::MissingVertex { label: "C" })
+        );
+        assert_eq!(
+            parse(&["--a", "0,0", "--a", "1,1"]),
+            Err(CliError::RepeatedVertex { label: "A" })
+        );
+        assert_eq!(
+            parse(&["0,0", "4,0", "0,3", "1,1"]),
+            Err(CliError::UnexpectedArgument {
+                got: "1,1".to_string()
+            })
+        );
+        assert!(matches!(
+            parse(&["0,0", "4,0", "zero,3"]),
+            Err(CliError::InvalidVertex { label: "C", .. })
+        ));
+    }
+}
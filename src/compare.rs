@@ -0,0 +1,526 @@
+//! Whether two triangles are congruent or similar, and how one maps onto the
+//! other.
+//!
+//! Points are treated as complex numbers: a direct similarity is
+//! `z -> alpha z + beta` and a mirrored one `z -> alpha conj(z) + beta`, where
+//! `|alpha|` is the scale factor and `arg(alpha)` the rotation.
+//!
+//! Triangles that are not similar can still be matched approximately:
+//! [`Triangle::best_fit`] solves the Procrustes problem, the similarity that
+//! minimizes the summed squared distances between matched vertices.
+
+use crate::point::Point;
+use crate::query::Vertex;
+use crate::tolerance::Tolerance;
+use crate::transform::Transform;
+use crate::triangle::Triangle;
+use std::fmt::{Display, Formatter};
+
+const VERTICES: [Vertex; 3] = [Vertex::A, Vertex::B, Vertex::C];
+
+/// Vertex orders tried for the other triangle, the cyclic ones first.
+const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [0, 2, 1],
+    [2, 1, 0],
+    [1, 0, 2],
+];
+
+/// How two triangles relate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Relation {
+    /// Same shape and size.
+    Congruent,
+    /// Same shape, different size.
+    Similar,
+    Dissimilar,
+}
+
+impl Relation {
+    /// Stable identifier used in machine readable output.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Congruent => "congruent",
+            Self::Similar => "similar",
+            Self::Dissimilar => "dissimilar",
+        }
+    }
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The similarity carrying one triangle onto another.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Correspondence {
+    /// The vertices of the other triangle matched with A, B and C.
+    pub vertices: [Vertex; 3],
+    /// The triangles are mirror images: the map reflects across the x-axis
+    /// before rotating.
+    pub mirrored: bool,
+    pub scale: f64,
+    /// Counter-clockwise rotation in radians, in `(-pi, pi]`.
+    pub rotation: f64,
+    pub translation: Point,
+    /// The whole map: reflection (if mirrored), rotation, scaling, then
+    /// translation.
+    pub transform: Transform,
+}
+
+impl Correspondence {
+    /// The matched vertices named D, E and F, as the second triangle's
+    /// vertices are called on the command line.
+    #[must_use]
+    pub fn labels(&self) -> [&'static str; 3] {
+        self.vertices.map(|vertex| match vertex {
+            Vertex::A => "D",
+            Vertex::B => "E",
+            Vertex::C => "F",
+        })
+    }
+}
+
+impl Display for Correspondence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [a, b, c] = self.labels();
+        write!(f, "A -> {a}, B -> {b}, C -> {c}")
+    }
+}
+
+/// The outcome of [`Triangle::compare`]. `correspondence` is `None` exactly
+/// when the triangles are dissimilar.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Comparison {
+    pub relation: Relation,
+    pub correspondence: Option<Correspondence>,
+}
+
+fn complex_mul(lhs: Point, rhs: Point) -> Point {
+    Point::new(
+        lhs.x.mul_add(rhs.x, -lhs.y * rhs.y),
+        lhs.x.mul_add(rhs.y, lhs.y * rhs.x),
+    )
+}
+
+fn complex_div(lhs: Point, rhs: Point) -> Point {
+    complex_mul(lhs, Point::new(rhs.x, -rhs.y)) / rhs.length_sq()
+}
+
+/// The map `z -> alpha z + beta`, or `alpha conj(z) + beta` when mirrored.
+fn similarity_transform(alpha: Point, beta: Point, mirrored: bool) -> Transform {
+    let flip = if mirrored { -1.0 } else { 1.0 };
+    Transform::new(
+        alpha.x,
+        -alpha.y * flip,
+        alpha.y,
+        alpha.x * flip,
+        beta.x,
+        beta.y,
+    )
+}
+
+fn reflect_if(points: [Point; 3], mirrored: bool) -> [Point; 3] {
+    if mirrored {
+        points.map(|p| Point::new(p.x, -p.y))
+    } else {
+        points
+    }
+}
+
+/// `arg(alpha)` in `(-pi, pi]`. Adding zero turns a `-0.0` imaginary part,
+/// for which `atan2` gives `-pi`, into `+0.0`.
+fn rotation_of(alpha: Point) -> f64 {
+    (alpha.y + 0.0).atan2(alpha.x)
+}
+
+/// The longest distance between two of `points`.
+fn extent(points: [Point; 3]) -> f64 {
+    let [p, q, r] = points;
+    p.distance_to(q).max(q.distance_to(r)).max(r.distance_to(p))
+}
+
+/// The similarity sending `from[i]` to `to[i]`, if there is one, with the
+/// third point matched within `tolerance` of the size of `to`.
+fn fit(
+    from: [Point; 3],
+    to: [Point; 3],
+    mirrored: bool,
+    tolerance: Tolerance,
+) -> Option<(Point, Point)> {
+    let from = reflect_if(from, mirrored);
+    let base = from[1] - from[0];
+    if base.length_sq() == 0.0 {
+        return None;
+    }
+    let alpha = complex_div(to[1] - to[0], base);
+    let beta = to[0] - complex_mul(alpha, from[0]);
+    let predicted = complex_mul(alpha, from[2]) + beta;
+    tolerance
+        .is_negligible(predicted.distance_to(to[2]), extent(to))
+        .then_some((alpha, beta))
+}
+
+/// The least-squares similarity found by [`Triangle::best_fit`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct BestFit {
+    /// The map and vertex correspondence, described as for exact matches.
+    pub correspondence: Correspondence,
+    /// Distance from each mapped vertex A, B and C to its matched vertex.
+    pub residuals: [f64; 3],
+    /// Root mean square of the residuals.
+    pub rms: f64,
+}
+
+/// Least-squares `alpha` and `beta` for `z -> alpha z + beta` from `from` to
+/// `to`, the closed form solution after moving both centroids to the origin.
+fn procrustes(from: [Point; 3], to: [Point; 3]) -> Option<(Point, Point)> {
+    let from_mean = (from[0] + from[1] + from[2]) / 3.0;
+    let to_mean = (to[0] + to[1] + to[2]) / 3.0;
+    let mut cross = Point::zero();
+    let mut spread = 0.0;
+    for (p, q) in from.into_iter().zip(to) {
+        let p = p - from_mean;
+        cross += complex_mul(Point::new(p.x, -p.y), q - to_mean);
+        spread += p.length_sq();
+    }
+    if spread == 0.0 {
+        return None;
+    }
+    let alpha = cross / spread;
+    Some((alpha, to_mean - complex_mul(alpha, from_mean)))
+}
+
+impl Triangle {
+    /// Compares the shape and size of `self` with `other`, looking for the
+    /// vertex correspondence under which one is a scaled, rotated and possibly
+    /// reflected copy of the other. Direct matches and the identity order are
+    /// preferred when several exist, as for isosceles triangles.
+    #[must_use]
+    pub fn compare(&self, other: &Self) -> Comparison {
+        self.compare_with(other, Tolerance::default())
+    }
+
+    /// [`Self::compare`], with vertices matched within `tolerance` of the size
+    /// of `other` and the scale factor compared with one within `tolerance`.
+    #[must_use]
+    pub fn compare_with(&self, other: &Self, tolerance: Tolerance) -> Comparison {
+        let from = [self.a, self.b, self.c];
+        let to = [other.a, other.b, other.c];
+        for mirrored in [false, true] {
+            for order in PERMUTATIONS {
+                let Some((alpha, beta)) = fit(from, order.map(|idx| to[idx]), mirrored, tolerance)
+                else {
+                    continue;
+                };
+                let scale = alpha.length_sq().sqrt();
+                let correspondence = Correspondence {
+                    vertices: order.map(|idx| VERTICES[idx]),
+                    mirrored,
+                    scale,
+                    rotation: rotation_of(alpha),
+                    translation: beta,
+                    transform: similarity_transform(alpha, beta, mirrored),
+                };
+                let relation = if tolerance.approx_eq(scale, 1.0) {
+                    Relation::Congruent
+                } else {
+                    Relation::Similar
+                };
+                return Comparison {
+                    relation,
+                    correspondence: Some(correspondence),
+                };
+            }
+        }
+        Comparison {
+            relation: Relation::Dissimilar,
+            correspondence: None,
+        }
+    }
+
+    /// The rotation, uniform scaling and translation that best map `self`
+    /// onto `target` in the least-squares sense, over every vertex
+    /// correspondence. With `allow_reflection` mirrored maps are considered
+    /// too. Exact matches, see [`Self::compare`], have residuals of zero.
+    /// Returns `None` when all vertices of `self` coincide.
+    #[must_use]
+    pub fn best_fit(&self, target: &Self, allow_reflection: bool) -> Option<BestFit> {
+        self.best_fit_with(target, allow_reflection, Tolerance::default())
+    }
+
+    /// [`Self::best_fit`], with residuals within `tolerance` of the size of
+    /// `target` counted as ties.
+    #[must_use]
+    pub fn best_fit_with(
+        &self,
+        target: &Self,
+        allow_reflection: bool,
+        tolerance: Tolerance,
+    ) -> Option<BestFit> {
+        let from = [self.a, self.b, self.c];
+        let to = [target.a, target.b, target.c];
+        let mirror_options: &[bool] = if allow_reflection {
+            &[false, true]
+        } else {
+            &[false]
+        };
+
+        let size = extent(to);
+        let mut best: Option<BestFit> = None;
+        for &mirrored in mirror_options {
+            for order in PERMUTATIONS {
+                let ordered = order.map(|idx| to[idx]);
+                let Some((alpha, beta)) = procrustes(reflect_if(from, mirrored), ordered) else {
+                    continue;
+                };
+                let transform = similarity_transform(alpha, beta, mirrored);
+                let mut residuals = [0.0; 3];
+                for ((residual, point), target) in residuals.iter_mut().zip(from).zip(ordered) {
+                    *residual = transform.apply(point).distance_to(target);
+                }
+                let rms = (residuals.iter().map(|r| r * r).sum::<f64>() / 3.0).sqrt();
+                // Ties, as for symmetric triangles, keep the earlier order.
+                if best.is_some_and(|best| {
+                    best.rms <= rms || tolerance.is_negligible(best.rms - rms, size)
+                }) {
+                    continue;
+                }
+                best = Some(BestFit {
+                    correspondence: Correspondence {
+                        vertices: order.map(|idx| VERTICES[idx]),
+                        mirrored,
+                        scale: alpha.length_sq().sqrt(),
+                        rotation: rotation_of(alpha),
+                        translation: beta,
+                        transform,
+                    },
+                    residuals,
+                    rms,
+                });
+            }
+        }
+        best
+    }
+
+    #[must_use]
+    pub fn is_congruent(&self, other: &Self) -> bool {
+        self.is_congruent_with(other, Tolerance::default())
+    }
+
+    #[must_use]
+    pub fn is_congruent_with(&self, other: &Self, tolerance: Tolerance) -> bool {
+        self.compare_with(other, tolerance).relation == Relation::Congruent
+    }
+
+    /// Congruent triangles count as similar too.
+    #[must_use]
+    pub fn is_similar(&self, other: &Self) -> bool {
+        self.is_similar_with(other, Tolerance::default())
+    }
+
+    #[must_use]
+    pub fn is_similar_with(&self, other: &Self, tolerance: Tolerance) -> bool {
+        self.compare_with(other, tolerance).relation != Relation::Dissimilar
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::{approx_eq, approx_eq_pt, scalene};
+
+    fn assert_maps_onto(tri: &Triangle, other: &Triangle, correspondence: &Correspondence) {
+        let to = [other.a, other.b, other.c];
+        for (point, vertex) in [tri.a, tri.b, tri.c]
+            .into_iter()
+            .zip(correspondence.vertices)
+        {
+            let target = to[VERTICES.iter().position(|v| *v == vertex).unwrap()];
+            assert!(approx_eq_pt(correspondence.transform.apply(point), target));
+        }
+    }
+
+    #[test]
+    fn test_congruent_after_rotation_and_relabelling() {
+        let tri = scalene();
+        let moved = Transform::rotation(1.0).then(Transform::translation(Point::new(5.0, -2.0)));
+        let image = moved.apply_triangle(&tri);
+        // Relabel so that the image of A is the third vertex.
+        let other = Triangle::new(image.b, image.c, image.a);
+
+        let comparison = tri.compare(&other);
+        assert_eq!(comparison.relation, Relation::Congruent);
+        let correspondence = comparison.correspondence.unwrap();
+        assert_eq!(correspondence.vertices, [Vertex::C, Vertex::A, Vertex::B]);
+        assert!(!correspondence.mirrored);
+        assert!(approx_eq(correspondence.rotation, 1.0));
+        assert!(approx_eq_pt(
+            correspondence.translation,
+            Point::new(5.0, -2.0)
+        ));
+        assert_maps_onto(&tri, &other, &correspondence);
+    }
+
+    #[test]
+    fn test_similar_mirror_image() {
+        let tri = scalene();
+        let mirror = Transform::scaling(-2.0, 2.0).then(Transform::translation(Point::one()));
+        let other = mirror.apply_triangle(&tri);
+
+        let comparison = tri.compare(&other);
+        assert_eq!(comparison.relation, Relation::Similar);
+        let correspondence = comparison.correspondence.unwrap();
+        assert!(correspondence.mirrored);
+        assert!(approx_eq(correspondence.scale, 2.0));
+        assert_eq!(correspondence.vertices, [Vertex::A, Vertex::B, Vertex::C]);
+        assert_maps_onto(&tri, &other, &correspondence);
+        assert!(tri.is_similar(&other) && !tri.is_congruent(&other));
+    }
+
+    #[test]
+    fn test_isosceles_prefers_direct_match() {
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(2.0, 5.0),
+        );
+        let mirrored = Transform::scaling(1.0, -1.0).apply_triangle(&tri);
+        let correspondence = tri.compare(&mirrored).correspondence.unwrap();
+        // Swapping A and B turns the reflection into a rotation.
+        assert!(!correspondence.mirrored);
+        assert_eq!(correspondence.vertices, [Vertex::B, Vertex::A, Vertex::C]);
+        assert_maps_onto(&tri, &mirrored, &correspondence);
+    }
+
+    #[test]
+    fn test_best_fit_recovers_exact_similarity() {
+        let tri = scalene();
+        let map = Transform::rotation(-2.0)
+            .then(Transform::scaling(0.5, 0.5))
+            .then(Transform::translation(Point::new(3.0, 3.0)));
+        let image = map.apply_triangle(&tri);
+        let target = Triangle::new(image.c, image.a, image.b);
+
+        let fit = tri.best_fit(&target, false).unwrap();
+        assert!(fit.rms < 1e-12);
+        assert_eq!(
+            fit.correspondence.vertices,
+            [Vertex::B, Vertex::C, Vertex::A]
+        );
+        assert!(approx_eq(fit.correspondence.scale, 0.5));
+        assert!(approx_eq(fit.correspondence.rotation, -2.0));
+    }
+
+    #[test]
+    fn test_best_fit_of_noisy_triangle() {
+        let tri = scalene();
+        let noise = [
+            Point::new(0.1, -0.05),
+            Point::new(-0.08, 0.02),
+            Point::new(0.03, 0.09),
+        ];
+        let map = Transform::rotation(0.4).then(Transform::scaling(3.0, 3.0));
+        let image = map.apply_triangle(&tri);
+        let target = Triangle::new(image.a + noise[0], image.b + noise[1], image.c + noise[2]);
+
+        let fit = tri.best_fit(&target, false).unwrap();
+        assert_eq!(
+            fit.correspondence.vertices,
+            [Vertex::A, Vertex::B, Vertex::C]
+        );
+        assert!((fit.correspondence.scale - 3.0).abs() < 0.02);
+        assert!((fit.correspondence.rotation - 0.4).abs() < 0.01);
+        assert!(fit.rms > 0.0 && fit.rms < 0.1);
+        // The least-squares fit beats the map the noise was added to.
+        let exact_error: f64 = noise.iter().map(|n| n.length_sq()).sum();
+        let fit_error: f64 = fit.residuals.iter().map(|r| r * r).sum();
+        assert!(fit_error <= exact_error);
+        // Residuals are measured against the matched vertices.
+        let mapped = fit.correspondence.transform.apply(tri.b);
+        assert!(approx_eq(mapped.distance_to(target.b), fit.residuals[1]));
+    }
+
+    #[test]
+    fn test_best_fit_reflection() {
+        // Clearly scalene, so no relabelling comes close to a mirror image.
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(2.0, 3.0),
+        );
+        let mirrored = Transform::scaling(-1.0, 1.0).apply_triangle(&tri);
+
+        let direct = tri.best_fit(&mirrored, false).unwrap();
+        assert!(direct.rms > 0.1);
+        let fit = tri.best_fit(&mirrored, true).unwrap();
+        assert!(fit.correspondence.mirrored);
+        assert!(fit.rms < 1e-12);
+
+        let point = Triangle::new(Point::one(), Point::one(), Point::one());
+        assert_eq!(point.best_fit(&tri, true), None);
+    }
+
+    #[test]
+    fn test_dissimilar_and_tolerance() {
+        let tri = scalene();
+        let other = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(3.0, 7.1),
+        );
+        assert_eq!(
+            tri.compare(&other),
+            Comparison {
+                relation: Relation::Dissimilar,
+                correspondence: None
+            }
+        );
+
+        // Rounding noise far below the tolerance still matches.
+        let noisy = Triangle::new(tri.a, tri.b, tri.c + Point::new(1e-12, -1e-12));
+        assert!(tri.is_congruent(&noisy));
+
+        // The same relative noise matches at any scale, and a looser
+        // tolerance accepts more.
+        for scale in [1e-9, 1e9] {
+            let scaled = Triangle::new(tri.a * scale, tri.b * scale, tri.c * scale);
+            let noisy = Triangle::new(
+                scaled.a,
+                scaled.b,
+                scaled.c + Point::new(1e-12, -1e-12) * scale,
+            );
+            assert!(scaled.is_congruent(&noisy), "{scale}");
+        }
+        assert!(tri.is_similar_with(&other, Tolerance::relative(0.1)));
+    }
+
+    #[test]
+    fn test_half_turn_rotation_is_positive() {
+        // With -0.0 on the x axis the rotation's imaginary part comes out as
+        // -0.0, for which `atan2` alone would give -pi.
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, -0.0),
+            Point::new(3.0, 7.0),
+        );
+        let turned = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(-10.0, -0.0),
+            Point::new(-3.0, -7.0),
+        );
+        let correspondence = tri.compare(&turned).correspondence.unwrap();
+        assert!(approx_eq(correspondence.rotation, std::f64::consts::PI));
+        let fit = tri.best_fit(&turned, false).unwrap();
+        assert!(approx_eq(fit.correspondence.rotation, std::f64::consts::PI));
+    }
+}
@@ -0,0 +1,618 @@
+//! Triangles constructed from another triangle, such as the medial triangle
+//! through the midpoints of the sides.
+//!
+//! The vertices of every derived triangle are labelled after the vertex of the
+//! reference triangle they correspond to: the first vertex of the medial
+//! triangle is the midpoint of BC, opposite A.
+//!
+//! The pedal, cevian and antipedal triangles are defined relative to an
+//! arbitrary point P as well.
+//!
+//! Constructions that can collapse check the result for collinearity. The
+//! plain functions use the default [`Tolerance`], the `_with` variants take
+//! one.
+
+use crate::barycentric::Barycentric;
+use crate::geometry::{excenter, foot_on_line, incenter};
+use crate::point::Point;
+use crate::primitives::{Line, Segment};
+use crate::tolerance::Tolerance;
+use crate::triangle::{DegenerateTriangleError, Triangle, check_collinear};
+use std::fmt::{Display, Formatter};
+
+/// Which derived triangle to construct.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DerivedKind {
+    Medial,
+    Orthic,
+    Intouch,
+    Excentral,
+    Tangential,
+    Anticomplementary,
+    Pedal(Point),
+    Cevian(Point),
+    Antipedal(Point),
+}
+
+impl DerivedKind {
+    pub const NAMES: &'static str =
+        "medial, orthic, intouch, excentral, tangential, anticomplementary";
+
+    /// Every kind that needs no point, see [`Self::from_name`].
+    pub const ALL: [Self; 6] = [
+        Self::Medial,
+        Self::Orthic,
+        Self::Intouch,
+        Self::Excentral,
+        Self::Tangential,
+        Self::Anticomplementary,
+    ];
+
+    /// Kinds relative to a point are not named here, they are built directly.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "medial" => Some(Self::Medial),
+            "orthic" => Some(Self::Orthic),
+            "intouch" | "contact" => Some(Self::Intouch),
+            "excentral" => Some(Self::Excentral),
+            "tangential" => Some(Self::Tangential),
+            "anticomplementary" => Some(Self::Anticomplementary),
+            _ => None,
+        }
+    }
+
+    /// The point P of the pedal, cevian and antipedal triangles.
+    #[must_use]
+    pub const fn point(self) -> Option<Point> {
+        match self {
+            Self::Pedal(point) | Self::Cevian(point) | Self::Antipedal(point) => Some(point),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DerivedKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Medial => write!(f, "medial"),
+            Self::Orthic => write!(f, "orthic"),
+            Self::Intouch => write!(f, "intouch"),
+            Self::Excentral => write!(f, "excentral"),
+            Self::Tangential => write!(f, "tangential"),
+            Self::Anticomplementary => write!(f, "anticomplementary"),
+            Self::Pedal(_) => write!(f, "pedal"),
+            Self::Cevian(_) => write!(f, "cevian"),
+            Self::Antipedal(_) => write!(f, "antipedal"),
+        }
+    }
+}
+
+/// Checks that a constructed triangle still has an area.
+fn non_degenerate(
+    tri: Triangle,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    check_collinear(&[tri.a, tri.b, tri.c], tolerance)?;
+    Ok(tri)
+}
+
+/// Vertices through the midpoints of BC, CA and AB.
+#[must_use]
+pub fn medial_triangle(tri: &Triangle) -> Triangle {
+    Triangle::new(
+        (tri.b + tri.c) / 2.0,
+        (tri.c + tri.a) / 2.0,
+        (tri.a + tri.b) / 2.0,
+    )
+}
+
+/// The triangle whose medial triangle is `tri`: every vertex is reflected
+/// through the midpoint of the opposite side.
+#[must_use]
+pub fn anticomplementary_triangle(tri: &Triangle) -> Triangle {
+    Triangle::new(
+        tri.b + tri.c - tri.a,
+        tri.c + tri.a - tri.b,
+        tri.a + tri.b - tri.c,
+    )
+}
+
+/// Vertices at the feet of the altitudes.
+///
+/// # Errors
+/// A right triangle has two feet on the right-angle vertex, so its orthic
+/// triangle is [`DegenerateTriangleError::Collinear`].
+pub fn orthic_triangle(tri: &Triangle) -> Result<Triangle, DegenerateTriangleError> {
+    orthic_triangle_with(tri, Tolerance::default())
+}
+
+/// [`orthic_triangle`] with the collinearity check done within `tolerance`.
+///
+/// # Errors
+/// As for [`orthic_triangle`].
+pub fn orthic_triangle_with(
+    tri: &Triangle,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    non_degenerate(pedal_feet(tri, [tri.a, tri.b, tri.c])?, tolerance)
+}
+
+/// Vertices where the incircle touches BC, CA and AB.
+///
+/// # Errors
+/// [`DegenerateTriangleError::Collinear`] when two vertices of `tri`
+/// coincide.
+pub fn intouch_triangle(tri: &Triangle) -> Result<Triangle, DegenerateTriangleError> {
+    let [a, b, c] = tri.side_lengths();
+    let center = incenter(tri.a, tri.b, tri.c, a, b, c).ok_or_else(|| collinear(tri))?;
+    pedal_feet(tri, [center; 3])
+}
+
+/// Vertices at the excenters opposite A, B and C.
+///
+/// # Errors
+/// [`DegenerateTriangleError::Collinear`] when a vertex of `tri` lies
+/// between the other two, whose excircle then degenerates.
+pub fn excentral_triangle(tri: &Triangle) -> Result<Triangle, DegenerateTriangleError> {
+    let [a, b, c] = tri.side_lengths();
+    let excenter = |pa, pb, pc, side_a, side_b, side_c| {
+        excenter(pa, pb, pc, side_a, side_b, side_c).ok_or_else(|| collinear(tri))
+    };
+    Ok(Triangle::new(
+        excenter(tri.a, tri.b, tri.c, a, b, c)?,
+        excenter(tri.b, tri.c, tri.a, b, c, a)?,
+        excenter(tri.c, tri.a, tri.b, c, a, b)?,
+    ))
+}
+
+/// Bounded by the tangents to the circumcircle at A, B and C; the vertex
+/// opposite A is where the tangents at B and C meet.
+///
+/// # Errors
+/// The tangents at the ends of a diameter are parallel, so a right triangle
+/// has a vertex at infinity and yields
+/// [`DegenerateTriangleError::VertexAtInfinity`].
+pub fn tangential_triangle(tri: &Triangle) -> Result<Triangle, DegenerateTriangleError> {
+    tangential_triangle_with(tri, Tolerance::default())
+}
+
+/// [`tangential_triangle`] with vertices at infinity and collinearity
+/// recognised within `tolerance`.
+///
+/// # Errors
+/// As for [`tangential_triangle`].
+pub fn tangential_triangle_with(
+    tri: &Triangle,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    let [a, b, c] = tri.side_lengths();
+    let (a2, b2, c2) = (a * a, b * b, c * c);
+    let vertex = |bary: Barycentric, label: &'static str| {
+        bary.to_cartesian_with(tri, tolerance)
+            .ok_or(DegenerateTriangleError::VertexAtInfinity { label })
+    };
+    non_degenerate(
+        Triangle::new(
+            vertex(Barycentric::new(-a2, b2, c2), "A")?,
+            vertex(Barycentric::new(a2, -b2, c2), "B")?,
+            vertex(Barycentric::new(a2, b2, -c2), "C")?,
+        ),
+        tolerance,
+    )
+}
+
+/// Vertices at the feet of the perpendiculars from `point` to BC, CA and AB.
+///
+/// # Errors
+/// The feet are collinear, on the Simson line, exactly when `point` lies on
+/// the circumcircle; this gives [`DegenerateTriangleError::Collinear`].
+pub fn pedal_triangle(tri: &Triangle, point: Point) -> Result<Triangle, DegenerateTriangleError> {
+    pedal_triangle_with(tri, point, Tolerance::default())
+}
+
+/// [`pedal_triangle`] with the collinearity check done within `tolerance`.
+///
+/// # Errors
+/// As for [`pedal_triangle`].
+pub fn pedal_triangle_with(
+    tri: &Triangle,
+    point: Point,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    non_degenerate(pedal_feet(tri, [point; 3])?, tolerance)
+}
+
+/// Feet of the perpendiculars from the three `points` to BC, CA and AB in
+/// turn.
+fn pedal_feet(tri: &Triangle, points: [Point; 3]) -> Result<Triangle, DegenerateTriangleError> {
+    let [to_a, to_b, to_c] = points;
+    let foot = |point, from, to| foot_on_line(point, from, to).ok_or_else(|| collinear(tri));
+    Ok(Triangle::new(
+        foot(to_a, tri.b, tri.c)?,
+        foot(to_b, tri.c, tri.a)?,
+        foot(to_c, tri.a, tri.b)?,
+    ))
+}
+
+/// The error for a construction that breaks down because two vertices of
+/// `tri` coincide or all three are collinear.
+const fn collinear(tri: &Triangle) -> DegenerateTriangleError {
+    DegenerateTriangleError::Collinear {
+        a: tri.a,
+        b: tri.b,
+        c: tri.c,
+    }
+}
+
+/// Barycentrics of `point`, rejecting points on a side line where the cevian
+/// and antipedal constructions break down. A coordinate counts as zero when
+/// it is negligible next to the largest of the three.
+fn off_side_lines(
+    tri: &Triangle,
+    point: Point,
+    tolerance: Tolerance,
+) -> Result<Barycentric, DegenerateTriangleError> {
+    let bary =
+        Barycentric::from_cartesian(point, tri).ok_or(DegenerateTriangleError::Collinear {
+            a: tri.a,
+            b: tri.b,
+            c: tri.c,
+        })?;
+    let coords = [bary.u, bary.v, bary.w];
+    let magnitude = coords
+        .iter()
+        .fold(0.0, |max: f64, coord| max.max(coord.abs()));
+    if coords
+        .iter()
+        .any(|&coord| tolerance.is_negligible(coord, magnitude))
+    {
+        return Err(DegenerateTriangleError::PointOnSideLine { point });
+    }
+    Ok(bary)
+}
+
+/// Vertices where the lines AP, BP and CP meet the opposite side lines.
+///
+/// # Errors
+/// Returns [`DegenerateTriangleError::PointOnSideLine`] when `point` lies on
+/// a side line and [`DegenerateTriangleError::VertexAtInfinity`] when a cevian
+/// is parallel to its side.
+pub fn cevian_triangle(tri: &Triangle, point: Point) -> Result<Triangle, DegenerateTriangleError> {
+    cevian_triangle_with(tri, point, Tolerance::default())
+}
+
+/// [`cevian_triangle`] with side lines, vertices at infinity and
+/// collinearity recognised within `tolerance`.
+///
+/// # Errors
+/// As for [`cevian_triangle`].
+pub fn cevian_triangle_with(
+    tri: &Triangle,
+    point: Point,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    let Barycentric { u, v, w } = off_side_lines(tri, point, tolerance)?;
+    let vertex = |bary: Barycentric, label: &'static str| {
+        bary.to_cartesian_with(tri, tolerance)
+            .ok_or(DegenerateTriangleError::VertexAtInfinity { label })
+    };
+    non_degenerate(
+        Triangle::new(
+            vertex(Barycentric::new(0.0, v, w), "A")?,
+            vertex(Barycentric::new(u, 0.0, w), "B")?,
+            vertex(Barycentric::new(u, v, 0.0), "C")?,
+        ),
+        tolerance,
+    )
+}
+
+/// The triangle whose pedal triangle for `point` is `tri`: its sides pass
+/// through A, B and C perpendicular to PA, PB and PC.
+///
+/// # Errors
+/// Returns [`DegenerateTriangleError::PointOnSideLine`] when `point` lies on
+/// a side line, where two of those sides become parallel.
+pub fn antipedal_triangle(
+    tri: &Triangle,
+    point: Point,
+) -> Result<Triangle, DegenerateTriangleError> {
+    antipedal_triangle_with(tri, point, Tolerance::default())
+}
+
+/// [`antipedal_triangle`] with side lines and collinearity recognised within
+/// `tolerance`.
+///
+/// # Errors
+/// As for [`antipedal_triangle`].
+pub fn antipedal_triangle_with(
+    tri: &Triangle,
+    point: Point,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    off_side_lines(tri, point, tolerance)?;
+    let side = |vertex: Point| Line::new(point, vertex - point).perpendicular_through(vertex);
+    let vertex = |p: Point, q: Point, label: &'static str| {
+        side(p)
+            .intersect(&side(q))
+            .ok_or(DegenerateTriangleError::VertexAtInfinity { label })
+    };
+    non_degenerate(
+        Triangle::new(
+            vertex(tri.b, tri.c, "A")?,
+            vertex(tri.c, tri.a, "B")?,
+            vertex(tri.a, tri.b, "C")?,
+        ),
+        tolerance,
+    )
+}
+
+/// Position of `point` along the line `from -> to` as the signed ratio
+/// `from-point : point-to`.
+fn signed_ratio(from: Point, point: Point, to: Point) -> f64 {
+    let t = Segment::new(from, to).parameter_of(point);
+    t / (1.0 - t)
+}
+
+/// The Ceva product `(BA'/A'C) (CB'/B'A) (AC'/C'B)` with signed ratios for
+/// points `feet.a` on BC, `feet.b` on CA and `feet.c` on AB.
+#[must_use]
+pub fn ceva_product(tri: &Triangle, feet: &Triangle) -> f64 {
+    signed_ratio(tri.b, feet.a, tri.c)
+        * signed_ratio(tri.c, feet.b, tri.a)
+        * signed_ratio(tri.a, feet.c, tri.b)
+}
+
+/// Ceva's theorem: the lines AA', BB' and CC' are concurrent (or parallel)
+/// exactly when the Ceva product is one.
+#[must_use]
+pub fn cevians_concurrent(tri: &Triangle, feet: &Triangle) -> bool {
+    cevians_concurrent_with(tri, feet, Tolerance::default())
+}
+
+/// [`cevians_concurrent`] with the Ceva product compared to one within
+/// `tolerance`.
+#[must_use]
+pub fn cevians_concurrent_with(tri: &Triangle, feet: &Triangle, tolerance: Tolerance) -> bool {
+    tolerance.approx_eq(ceva_product(tri, feet), 1.0)
+}
+
+/// Constructs the derived triangle of the given kind.
+///
+/// # Errors
+/// Returns a [`DegenerateTriangleError`] when the construction collapses for
+/// this shape or point, see the individual constructors.
+pub fn derived_triangle(
+    tri: &Triangle,
+    kind: DerivedKind,
+) -> Result<Triangle, DegenerateTriangleError> {
+    derived_triangle_with(tri, kind, Tolerance::default())
+}
+
+/// [`derived_triangle`] with degeneracy recognised within `tolerance`.
+///
+/// # Errors
+/// As for [`derived_triangle`].
+pub fn derived_triangle_with(
+    tri: &Triangle,
+    kind: DerivedKind,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    match kind {
+        DerivedKind::Medial => Ok(medial_triangle(tri)),
+        DerivedKind::Orthic => orthic_triangle_with(tri, tolerance),
+        DerivedKind::Intouch => intouch_triangle(tri),
+        DerivedKind::Excentral => excentral_triangle(tri),
+        DerivedKind::Tangential => tangential_triangle_with(tri, tolerance),
+        DerivedKind::Anticomplementary => Ok(anticomplementary_triangle(tri)),
+        DerivedKind::Pedal(point) => pedal_triangle_with(tri, point, tolerance),
+        DerivedKind::Cevian(point) => cevian_triangle_with(tri, point, tolerance),
+        DerivedKind::Antipedal(point) => antipedal_triangle_with(tri, point, tolerance),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::compute_summary;
+    use crate::test_support::{approx_eq, approx_eq_pt, right_triangle, scalene};
+
+    #[test]
+    fn test_medial_and_anticomplementary_are_inverse() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+        let medial = compute_summary(&medial_triangle(&tri));
+        assert!(approx_eq(medial.area, s.area / 4.0));
+        assert!(approx_eq_pt(medial.centroid, s.centroid));
+        // The medial triangle's circumcircle is the nine-point circle.
+        assert!(approx_eq_pt(medial.circumcenter, s.nine_point_center));
+
+        let back = medial_triangle(&anticomplementary_triangle(&tri));
+        assert!(approx_eq_pt(back.a, tri.a));
+        assert!(approx_eq_pt(back.b, tri.b));
+        assert!(approx_eq_pt(back.c, tri.c));
+        assert!(approx_eq(
+            compute_summary(&anticomplementary_triangle(&tri)).area,
+            4.0 * s.area
+        ));
+    }
+
+    #[test]
+    fn test_orthic_triangle() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+        let orthic = orthic_triangle(&tri).unwrap();
+        // For an acute triangle the orthocenter is the orthic incenter.
+        assert!(approx_eq_pt(
+            compute_summary(&orthic).incenter,
+            s.orthocenter
+        ));
+        assert!(approx_eq_pt(orthic.c, Point::new(3.0, 0.0)));
+
+        assert!(matches!(
+            orthic_triangle(&right_triangle()),
+            Err(DegenerateTriangleError::Collinear { .. })
+        ));
+    }
+
+    #[test]
+    fn test_intouch_and_excentral_triangles() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+
+        let intouch = compute_summary(&intouch_triangle(&tri).unwrap());
+        assert!(approx_eq_pt(intouch.circumcenter, s.incenter));
+        assert!(approx_eq(intouch.circumradius, s.inradius));
+
+        let excentral = compute_summary(&excentral_triangle(&tri).unwrap());
+        assert!(approx_eq_pt(excentral.vertex_a, s.excenter_a));
+        // The incenter is the orthocenter of the excentral triangle.
+        assert!(approx_eq_pt(excentral.orthocenter, s.incenter));
+        // and the circumcircle its nine-point circle.
+        assert!(approx_eq(excentral.nine_point_radius, s.circumradius));
+
+        let flat = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+        );
+        assert!(excentral_triangle(&flat).is_err());
+        let pinched = Triangle::new(flat.a, flat.a, flat.c);
+        assert!(intouch_triangle(&pinched).is_err());
+    }
+
+    #[test]
+    fn test_tangential_triangle() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+        let tangential = tangential_triangle(&tri).unwrap();
+        // The tangential triangle's incircle is the circumcircle for an acute
+        // triangle, so every side touches it.
+        let summary = compute_summary(&tangential);
+        assert!(approx_eq_pt(summary.incenter, s.circumcenter));
+        assert!(approx_eq(summary.inradius, s.circumradius));
+        // The vertex opposite A lies on the tangents at B and C.
+        let radius_b = tri.b - s.circumcenter;
+        assert!(approx_eq((tangential.a - tri.b).dot(radius_b), 0.0));
+
+        assert_eq!(
+            tangential_triangle(&right_triangle()),
+            Err(DegenerateTriangleError::VertexAtInfinity { label: "A" })
+        );
+    }
+
+    #[test]
+    fn test_pedal_triangle() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+
+        // The pedal triangle of the circumcenter is the medial triangle,
+        // that of the orthocenter the orthic triangle.
+        let pedal = pedal_triangle(&tri, s.circumcenter).unwrap();
+        assert!(approx_eq_pt(pedal.a, medial_triangle(&tri).a));
+        let pedal = pedal_triangle(&tri, s.orthocenter).unwrap();
+        assert!(approx_eq_pt(pedal.b, orthic_triangle(&tri).unwrap().b));
+
+        // Points on the circumcircle have a Simson line instead.
+        let on_circle = s.circumcenter * 2.0 - tri.a;
+        assert!(matches!(
+            pedal_triangle(&tri, on_circle),
+            Err(DegenerateTriangleError::Collinear { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cevian_triangle_and_ceva() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+
+        // The cevian triangle of the centroid is the medial triangle.
+        let cevian = cevian_triangle(&tri, s.centroid).unwrap();
+        assert!(approx_eq_pt(cevian.a, medial_triangle(&tri).a));
+        assert!(approx_eq_pt(cevian.c, medial_triangle(&tri).c));
+
+        for point in [s.incenter, Point::new(4.0, 2.0), Point::new(20.0, 9.0)] {
+            let cevian = cevian_triangle(&tri, point).unwrap();
+            assert!(cevians_concurrent(&tri, &cevian));
+            // Every vertex lies on its side line and on the line through P.
+            assert!(approx_eq((cevian.a - tri.b).cross(tri.c - tri.b), 0.0));
+            assert!(approx_eq((cevian.a - tri.a).cross(point - tri.a), 0.0));
+        }
+
+        // Midpoints of two sides and a point off centre do not concur.
+        let skewed = Triangle::new(
+            (tri.b + tri.c) / 2.0,
+            (tri.c + tri.a) / 2.0,
+            tri.a + (tri.b - tri.a) * 0.25,
+        );
+        assert!(!cevians_concurrent(&tri, &skewed));
+
+        assert_eq!(
+            cevian_triangle(&tri, Point::new(5.0, 0.0)),
+            Err(DegenerateTriangleError::PointOnSideLine {
+                point: Point::new(5.0, 0.0)
+            })
+        );
+        // A point just off AB only counts as on it under a looser tolerance.
+        let near = Point::new(5.0, 1e-6);
+        assert!(cevian_triangle(&tri, near).is_ok());
+        assert_eq!(
+            cevian_triangle_with(&tri, near, Tolerance::relative(1e-3)),
+            Err(DegenerateTriangleError::PointOnSideLine { point: near })
+        );
+        // AP parallel to BC puts the first vertex at infinity.
+        assert_eq!(
+            cevian_triangle(&tri, Point::new(-7.0, 7.0)),
+            Err(DegenerateTriangleError::VertexAtInfinity { label: "A" })
+        );
+    }
+
+    #[test]
+    fn test_checks_do_not_depend_on_scale() {
+        for scale in [1e-9, 1.0, 1e9] {
+            let tri = scalene();
+            let tri = Triangle::new(tri.a * scale, tri.b * scale, tri.c * scale);
+            let point = Point::new(4.0, 2.0) * scale;
+            let cevian = cevian_triangle(&tri, point).unwrap();
+            assert!(cevians_concurrent(&tri, &cevian), "{scale}");
+            assert!(orthic_triangle(&tri).is_ok() && antipedal_triangle(&tri, point).is_ok());
+            assert!(matches!(
+                pedal_triangle(&tri, compute_summary(&tri).circumcenter * 2.0 - tri.a),
+                Err(DegenerateTriangleError::Collinear { .. }
+                    | DegenerateTriangleError::NearlyCollinear { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_antipedal_triangle() {
+        let tri = scalene();
+        for point in [Point::new(4.0, 2.0), Point::new(-3.0, 5.0)] {
+            let antipedal = antipedal_triangle(&tri, point).unwrap();
+            // Its pedal triangle for the same point is the original.
+            let back = pedal_triangle(&antipedal, point).unwrap();
+            assert!(approx_eq_pt(back.a, tri.a));
+            assert!(approx_eq_pt(back.b, tri.b));
+            assert!(approx_eq_pt(back.c, tri.c));
+        }
+        assert!(matches!(
+            antipedal_triangle(&tri, tri.b),
+            Err(DegenerateTriangleError::PointOnSideLine { .. })
+        ));
+    }
+
+    #[test]
+    fn test_derived_kind_names() {
+        for kind in DerivedKind::ALL {
+            assert_eq!(DerivedKind::from_name(&kind.to_string()), Some(kind));
+            assert!(DerivedKind::NAMES.contains(&kind.to_string()));
+        }
+        assert_eq!(
+            DerivedKind::from_name("Contact"),
+            Some(DerivedKind::Intouch)
+        );
+        assert_eq!(DerivedKind::from_name("pedal"), None);
+        assert!(derived_triangle(&scalene(), DerivedKind::Excentral).is_ok());
+    }
+}
@@ -1,60 +1,61 @@
 use crate::point::PointCreateError;
+use crate::solve::SolveError;
 use crate::triangle::{DegenerateTriangleError, TriangleCreateError};
-use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::io;
 
-/// Top-level application error that unifies all possible failure modes.
-#[derive(Debug)]
-pub enum AppError {
-    Io(io::Error),
+/// Any failure the library can report while parsing points or building and
+/// solving triangles.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
     PointCreate(PointCreateError),
     TriangleCreate(TriangleCreateError),
     DegenerateTriangle(DegenerateTriangleError),
+    Solve(SolveError),
 }
 
-impl Display for AppError {
+impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Io(err) => write!(f, "I/O error: {err}"),
             Self::PointCreate(err) => write!(f, "Point error: {err}"),
             Self::TriangleCreate(err) => write!(f, "Triangle error: {err}"),
             Self::DegenerateTriangle(err) => write!(f, "Triangle error: {err}"),
+            Self::Solve(err) => write!(f, "Triangle error: {err}"),
         }
     }
 }
 
-impl Error for AppError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::Io(err) => Some(err),
             Self::PointCreate(err) => Some(err),
             Self::TriangleCreate(err) => Some(err),
             Self::DegenerateTriangle(err) => Some(err),
+            Self::Solve(err) => Some(err),
         }
     }
 }
 
-impl From<io::Error> for AppError {
-    fn from(err: io::Error) -> Self {
-        Self::Io(err)
-    }
-}
-
-impl From<PointCreateError> for AppError {
+impl From<PointCreateError> for Error {
     fn from(err: PointCreateError) -> Self {
         Self::PointCreate(err)
     }
 }
 
-impl From<TriangleCreateError> for AppError {
+impl From<TriangleCreateError> for Error {
     fn from(err: TriangleCreateError) -> Self {
         Self::TriangleCreate(err)
     }
 }
 
-impl From<DegenerateTriangleError> for AppError {
+impl From<DegenerateTriangleError> for Error {
     fn from(err: DegenerateTriangleError) -> Self {
         Self::DegenerateTriangle(err)
     }
 }
+
+impl From<SolveError> for Error {
+    fn from(err: SolveError) -> Self {
+        Self::Solve(err)
+    }
+}
@@ -0,0 +1,290 @@
+//! Triangles with rational vertex coordinates, computed without rounding.
+//!
+//! Coordinates, squared side lengths, the area and the centroid,
+//! circumcenter, orthocenter and nine-point center of a triangle with
+//! rational vertices are themselves rational, so they are computed here
+//! exactly. Side and angle classes follow from comparing squared lengths and
+//! signs of dot products, and are exact too. Quantities that need square
+//! roots or trigonometry, such as side lengths, angles and the incenter, are
+//! left to the `f64` [`TriangleSummary`](crate::geometry::TriangleSummary) of
+//! [`Triangle::to_f64`].
+//!
+//! No rational triangle is equilateral: its area would be `sqrt(3)/4` times a
+//! rational square, yet the shoelace formula makes the area rational.
+
+use crate::errors::Error;
+use crate::geometry::{
+    AngleClassification, SideClassification, angle_class_of, centroid, circumcenter,
+    nine_point_center, orthocenter, side_class_of,
+};
+use crate::point::{Point, PointCreateError, split_coordinates};
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+use crate::triangle::{DegenerateTriangleError, Triangle, TriangleCreateError};
+use std::fmt::{Display, Formatter};
+
+pub type ExactPoint = Point<Rational>;
+pub type ExactTriangle = Triangle<Rational>;
+
+impl ExactPoint {
+    /// The exact value of a point with finite coordinates.
+    #[must_use]
+    pub fn from_point(point: Point) -> Option<Self> {
+        Some(Self::new(
+            Rational::from_f64(point.x)?,
+            Rational::from_f64(point.y)?,
+        ))
+    }
+}
+
+/// Parses a point written as `x,y`, where each coordinate is an integer, a
+/// decimal or a fraction such as `1/3`.
+///
+/// # Errors
+/// Returns a [`PointCreateError`] when the input is empty, does not hold
+/// exactly two comma-separated values or a value is not a rational number.
+pub fn to_exact_point(input: &str) -> Result<ExactPoint, PointCreateError> {
+    let [x_raw, y_raw] = split_coordinates(input)?;
+    let parse = |raw: &str, label: &str| {
+        Rational::parse(raw).ok_or_else(|| PointCreateError::InvalidFormat {
+            got: raw.to_string(),
+            example: format!("{label}: a decimal or fraction e.g. 1/3"),
+        })
+    };
+    Ok(ExactPoint::new(parse(x_raw, "x")?, parse(y_raw, "y")?))
+}
+
+impl ExactTriangle {
+    /// Builds a triangle from three distinct, non-collinear points. Both
+    /// checks are exact, so no tolerance applies.
+    ///
+    /// # Errors
+    /// Returns [`TriangleCreateError::DuplicatePoint`] when two points
+    /// coincide and [`DegenerateTriangleError::Collinear`] when all three lie
+    /// on a line.
+    pub fn build(points: [ExactPoint; 3]) -> Result<Self, Error> {
+        let [a, b, c] = points;
+        for (p, q) in [(&a, &b), (&b, &c), (&a, &c)] {
+            if p == q {
+                return Err(TriangleCreateError::DuplicatePoint { point: p.to_f64() }.into());
+            }
+        }
+        let triangle = Self::new(a, b, c);
+        if triangle.twice_signed_area().is_zero() {
+            let Triangle { a, b, c } = triangle.to_f64();
+            return Err(DegenerateTriangleError::Collinear { a, b, c }.into());
+        }
+        Ok(triangle)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExactSummary {
+    pub triangle: ExactTriangle,
+    /// Squared lengths of the sides opposite A, B and C.
+    pub side_sq_a: Rational,
+    pub side_sq_b: Rational,
+    pub side_sq_c: Rational,
+    pub area: Rational,
+    /// Never [`SideClassification::Equilateral`], see the module docs.
+    pub side_class: SideClassification,
+    pub angle_class: AngleClassification,
+    pub centroid: ExactPoint,
+    pub circumcenter: ExactPoint,
+    pub orthocenter: ExactPoint,
+    pub nine_point_center: ExactPoint,
+    pub circumradius_sq: Rational,
+}
+
+/// Computes every rational quantity of `tri` exactly.
+///
+/// # Errors
+/// [`DegenerateTriangleError::Collinear`] when the vertices span no area, as
+/// collinear points have no circumcenter.
+pub fn compute_exact_summary(tri: &ExactTriangle) -> Result<ExactSummary, DegenerateTriangleError> {
+    let collinear = || DegenerateTriangleError::Collinear {
+        a: tri.a.to_f64(),
+        b: tri.b.to_f64(),
+        c: tri.c.to_f64(),
+    };
+    let twice_area = tri.twice_signed_area();
+    if twice_area.is_zero() {
+        return Err(collinear());
+    }
+    let [side_sq_a, side_sq_b, side_sq_c] = tri.side_lengths_sq();
+    let area = twice_area.abs().half();
+    let Triangle { a, b, c } = tri.clone();
+    let circumcenter = circumcenter(a.clone(), b.clone(), c.clone()).ok_or_else(collinear)?;
+    let circumradius_sq = (a.clone() - circumcenter.clone()).length_sq();
+    let orthocenter = orthocenter(a.clone(), b.clone(), c.clone(), circumcenter.clone());
+    let nine_point_center = nine_point_center(circumcenter.clone(), orthocenter.clone());
+    // Rational comparisons always decide, so the classes come straight from
+    // the squared sides, e.g. the angle at A from `b^2 + c^2` against `a^2`.
+    let side_class = side_class_of(
+        side_sq_a == side_sq_b,
+        side_sq_b == side_sq_c,
+        side_sq_a == side_sq_c,
+    );
+    let angle_class = angle_class_of(&[
+        (&side_sq_b + &side_sq_c).cmp(&side_sq_a),
+        (&side_sq_a + &side_sq_c).cmp(&side_sq_b),
+        (&side_sq_a + &side_sq_b).cmp(&side_sq_c),
+    ]);
+
+    Ok(ExactSummary {
+        triangle: tri.clone(),
+        side_sq_a,
+        side_sq_b,
+        side_sq_c,
+        area,
+        side_class,
+        angle_class,
+        centroid: centroid(a, b, c),
+        circumcenter,
+        orthocenter,
+        nine_point_center,
+        circumradius_sq,
+    })
+}
+
+impl Display for ExactSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let w = 22; // label column width
+        let sep = "─".repeat(60);
+
+        writeln!(f, "\n{sep}")?;
+        writeln!(f, "           ▲  EXACT RESULTS  ▲")?;
+        writeln!(f, "{sep}")?;
+
+        writeln!(f, "\n┌─ Vertices")?;
+        let ExactTriangle { a, b, c } = &self.triangle;
+        for (label, vertex) in [("Vertex A:", a), ("Vertex B:", b), ("Vertex C:", c)] {
+            writeln!(f, "│  {label:>w$}  {vertex}")?;
+        }
+
+        writeln!(f, "│")?;
+        writeln!(f, "├─ Squared Side Lengths (opposite vertex)")?;
+        for (label, value, side) in [
+            ("Side a²:", &self.side_sq_a, "BC"),
+            ("Side b²:", &self.side_sq_b, "AC"),
+            ("Side c²:", &self.side_sq_c, "AB"),
+        ] {
+            writeln!(f, "│  {label:>w$}  {value}  ({side})")?;
+        }
+
+        writeln!(f, "│")?;
+        writeln!(f, "├─ Measures")?;
+        writeln!(f, "│  {:>w$}  {}", "Area:", self.area)?;
+        writeln!(f, "│  {:>w$}  {}", "Circumradius²:", self.circumradius_sq)?;
+
+        writeln!(f, "│")?;
+        writeln!(f, "├─ Classification")?;
+        writeln!(f, "│  {:>w$}  {}", "By sides:", self.side_class)?;
+        writeln!(f, "│  {:>w$}  {}", "By angles:", self.angle_class)?;
+
+        writeln!(f, "│")?;
+        writeln!(f, "└─ Centers")?;
+        for (label, center) in [
+            ("Centroid:", &self.centroid),
+            ("Circumcenter:", &self.circumcenter),
+            ("Orthocenter:", &self.orthocenter),
+            ("Nine-point center:", &self.nine_point_center),
+        ] {
+            writeln!(f, "   {label:>w$}  {center}")?;
+        }
+
+        write!(f, "\n{sep}")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn triangle(points: [&str; 3]) -> Result<ExactTriangle, Error> {
+        ExactTriangle::build(points.map(|point| to_exact_point(point).unwrap()))
+    }
+
+    fn q(text: &str) -> Rational {
+        Rational::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_exact_centers() {
+        let summary = compute_exact_summary(&triangle(["0,0", "4,0", "1,3"]).unwrap()).unwrap();
+        assert_eq!(summary.area, q("6"));
+        assert_eq!(summary.side_sq_a, q("18"));
+        assert_eq!(summary.side_sq_b, q("10"));
+        assert_eq!(summary.side_sq_c, q("16"));
+        assert_eq!(summary.centroid.to_string(), "(5/3, 1)");
+        assert_eq!(summary.circumcenter.to_string(), "(2, 1)");
+        assert_eq!(summary.orthocenter.to_string(), "(1, 1)");
+        assert_eq!(summary.nine_point_center.to_string(), "(3/2, 1)");
+        assert_eq!(summary.circumradius_sq, q("5"));
+        assert_eq!(summary.angle_class, AngleClassification::Acute);
+        assert_eq!(summary.side_class, SideClassification::Scalene);
+    }
+
+    #[test]
+    fn test_classification_without_rounding() {
+        // 0.1 and 0.3 are not exact in f64, but these legs are exactly
+        // perpendicular and equal in length.
+        let right =
+            compute_exact_summary(&triangle(["0.1,0.1", "0.4,0.1", "0.1,0.4"]).unwrap()).unwrap();
+        assert_eq!(right.angle_class, AngleClassification::Right);
+        assert_eq!(right.side_class, SideClassification::Isosceles);
+        assert_eq!(right.area, q("9/200"));
+        assert_eq!(right.circumcenter.to_string(), "(1/4, 1/4)");
+
+        let nearly = compute_exact_summary(
+            &triangle(["0,0", "1,0", "1/3,1/3000000000000000000000"]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(nearly.angle_class, AngleClassification::Obtuse);
+        assert_eq!(nearly.side_class, SideClassification::Scalene);
+    }
+
+    #[test]
+    fn test_large_exponents_stay_fast() {
+        // Hundreds of digits in every coordinate; the arithmetic behind this
+        // used to take seconds even with optimizations.
+        let start = std::time::Instant::now();
+        let tri = triangle(["1e-300,0", "1,1e-200", "0.123456789123456789,1/7"]).unwrap();
+        let summary = compute_exact_summary(&tri).unwrap();
+        assert_eq!(summary.angle_class, AngleClassification::Obtuse);
+        assert!(summary.circumradius_sq.denominator().bit_length() > 1000);
+        assert!(start.elapsed() < std::time::Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_exact_degeneracy() {
+        assert!(matches!(
+            triangle(["0,0", "1/3,1/3", "1,1"]),
+            Err(Error::DegenerateTriangle(
+                DegenerateTriangleError::Collinear { .. }
+            ))
+        ));
+        assert!(matches!(
+            triangle(["1/2,0", "0.5,0", "1,1"]),
+            Err(Error::TriangleCreate(
+                TriangleCreateError::DuplicatePoint { .. }
+            ))
+        ));
+        // A tolerance would merge these; exact arithmetic does not.
+        assert!(triangle(["0,0", "1,0", "1/2,1e-300"]).is_ok());
+        // Unvalidated collinear vertices have no circumcenter to divide out.
+        let collinear = ExactTriangle::new(
+            to_exact_point("0,0").unwrap(),
+            to_exact_point("1,1").unwrap(),
+            to_exact_point("2,2").unwrap(),
+        );
+        assert!(matches!(
+            compute_exact_summary(&collinear),
+            Err(DegenerateTriangleError::Collinear { .. })
+        ));
+        assert!(to_exact_point("1/0,1").is_err());
+        assert!(to_exact_point("1,2,3").is_err());
+    }
+}
@@ -1,5 +1,9 @@
 use crate::point::Point;
+use crate::primitives::{Circle, Line, Segment};
+use crate::scalar::{Real, Scalar};
+use crate::tolerance::Tolerance;
 use crate::triangle::Triangle;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -37,74 +41,298 @@ impl Display for AngleClassification {
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct TriangleSummary {
-    pub vertex_a: Point,
-    pub vertex_b: Point,
-    pub vertex_c: Point,
-    pub side_a: f64,
-    pub side_b: f64,
-    pub side_c: f64,
-    pub angle_a_rad: f64,
-    pub angle_b_rad: f64,
-    pub angle_c_rad: f64,
-    pub perimeter: f64,
-    pub semi_perimeter: f64,
-    pub area: f64,
+#[non_exhaustive]
+pub struct TriangleSummary<T = f64> {
+    pub vertex_a: Point<T>,
+    pub vertex_b: Point<T>,
+    pub vertex_c: Point<T>,
+    pub side_a: T,
+    pub side_b: T,
+    pub side_c: T,
+    pub angle_a_rad: T,
+    pub angle_b_rad: T,
+    pub angle_c_rad: T,
+    pub perimeter: T,
+    pub semi_perimeter: T,
+    pub area: T,
+    /// Classes up to the tolerance: sides and angles within it of being
+    /// equal or right count as such. Never less symmetric than the exact
+    /// classes.
     pub side_class: SideClassification,
     pub angle_class: AngleClassification,
-    pub centroid: Point,
-    pub incenter: Point,
-    pub circumcenter: Point,
-    pub orthocenter: Point,
-    pub nine_point_center: Point,
-    pub inradius: f64,
-    pub circumradius: f64,
-    pub nine_point_radius: f64,
-    pub median_a: f64,
-    pub median_b: f64,
-    pub median_c: f64,
-    pub altitude_a: f64,
-    pub altitude_b: f64,
-    pub altitude_c: f64,
-    pub bisector_a: f64,
-    pub bisector_b: f64,
-    pub bisector_c: f64,
-}
-
-pub fn approx_eq(a: f64, b: f64) -> bool {
-    let abs_tol = 1e-9;
-    let rel_tol = 1e-9;
-    let diff = (a - b).abs();
-    diff <= abs_tol || diff <= rel_tol * a.abs().max(b.abs())
-}
-
-pub fn angle_from_sides(opposite: f64, adj1: f64, adj2: f64) -> f64 {
+    /// Classes decided with exact predicates on the vertex coordinates.
+    pub exact_side_class: SideClassification,
+    pub exact_angle_class: AngleClassification,
+    pub centroid: Point<T>,
+    pub incenter: Point<T>,
+    pub circumcenter: Point<T>,
+    pub orthocenter: Point<T>,
+    pub nine_point_center: Point<T>,
+    pub inradius: T,
+    pub circumradius: T,
+    pub nine_point_radius: T,
+    /// Centres of the excircles opposite A, B and C.
+    pub excenter_a: Point<T>,
+    pub excenter_b: Point<T>,
+    pub excenter_c: Point<T>,
+    pub exradius_a: T,
+    pub exradius_b: T,
+    pub exradius_c: T,
+    /// Where each excircle touches the lines BC, CA and AB, in that order.
+    /// Two of the three lie on the extensions of the sides.
+    pub excircle_touch_a: [Point<T>; 3],
+    pub excircle_touch_b: [Point<T>; 3],
+    pub excircle_touch_c: [Point<T>; 3],
+    pub median_a: T,
+    pub median_b: T,
+    pub median_c: T,
+    pub altitude_a: T,
+    pub altitude_b: T,
+    pub altitude_c: T,
+    pub bisector_a: T,
+    pub bisector_b: T,
+    pub bisector_c: T,
+    /// Feet on the opposite sides: the midpoints of BC, CA and AB.
+    pub median_foot_a: Point<T>,
+    pub median_foot_b: Point<T>,
+    pub median_foot_c: Point<T>,
+    /// Feet of the perpendiculars to the lines BC, CA and AB. For obtuse
+    /// triangles two of them lie on the extensions of the sides.
+    pub altitude_foot_a: Point<T>,
+    pub altitude_foot_b: Point<T>,
+    pub altitude_foot_c: Point<T>,
+    /// Where the internal angle bisectors meet BC, CA and AB.
+    pub bisector_foot_a: Point<T>,
+    pub bisector_foot_b: Point<T>,
+    pub bisector_foot_c: Point<T>,
+    /// Used for the classifications and the verification checks.
+    pub tolerance: Tolerance,
+}
+
+impl<T: Real> TriangleSummary<T> {
+    /// Magnitude of the cross product of OG and OH, zero when the
+    /// circumcenter, centroid and orthocenter lie on one line.
+    #[must_use]
+    pub fn euler_line_residual(&self) -> T {
+        let og = self.centroid - self.circumcenter;
+        let oh = self.orthocenter - self.circumcenter;
+        og.cross(oh).abs()
+    }
+
+    /// Relative deviation from `1/r = 1/r_a + 1/r_b + 1/r_c`, zero for a
+    /// consistent set of inradius and exradii.
+    #[must_use]
+    pub fn excircle_residual(&self) -> T {
+        let one = T::from_i32(1);
+        let reciprocal_sum = one / self.exradius_a + one / self.exradius_b + one / self.exradius_c;
+        self.inradius.mul_add(reciprocal_sum, -one).abs()
+    }
+
+    /// Whether the Euler line residual vanishes relative to the squared
+    /// circumradius.
+    #[must_use]
+    pub fn euler_line_holds(&self) -> bool {
+        self.tolerance.is_negligible(
+            self.euler_line_residual().to_f64(),
+            (self.circumradius * self.circumradius).to_f64(),
+        )
+    }
+
+    /// Whether `1/r = 1/r_a + 1/r_b + 1/r_c` holds up to the tolerance.
+    #[must_use]
+    pub fn excircle_identity_holds(&self) -> bool {
+        self.tolerance
+            .is_negligible(self.excircle_residual().to_f64(), 1.0)
+    }
+
+    /// The same summary in `f64`, e.g. to print or encode it.
+    #[must_use]
+    pub fn to_f64(&self) -> TriangleSummary {
+        TriangleSummary {
+            vertex_a: self.vertex_a.to_f64(),
+            vertex_b: self.vertex_b.to_f64(),
+            vertex_c: self.vertex_c.to_f64(),
+            side_a: self.side_a.to_f64(),
+            side_b: self.side_b.to_f64(),
+            side_c: self.side_c.to_f64(),
+            angle_a_rad: self.angle_a_rad.to_f64(),
+            angle_b_rad: self.angle_b_rad.to_f64(),
+            angle_c_rad: self.angle_c_rad.to_f64(),
+            perimeter: self.perimeter.to_f64(),
+            semi_perimeter: self.semi_perimeter.to_f64(),
+            area: self.area.to_f64(),
+            side_class: self.side_class,
+            angle_class: self.angle_class,
+            exact_side_class: self.exact_side_class,
+            exact_angle_class: self.exact_angle_class,
+            centroid: self.centroid.to_f64(),
+            incenter: self.incenter.to_f64(),
+            circumcenter: self.circumcenter.to_f64(),
+            orthocenter: self.orthocenter.to_f64(),
+            nine_point_center: self.nine_point_center.to_f64(),
+            inradius: self.inradius.to_f64(),
+            circumradius: self.circumradius.to_f64(),
+            nine_point_radius: self.nine_point_radius.to_f64(),
+            excenter_a: self.excenter_a.to_f64(),
+            excenter_b: self.excenter_b.to_f64(),
+            excenter_c: self.excenter_c.to_f64(),
+            exradius_a: self.exradius_a.to_f64(),
+            exradius_b: self.exradius_b.to_f64(),
+            exradius_c: self.exradius_c.to_f64(),
+            excircle_touch_a: self.excircle_touch_a.map(|point| point.to_f64()),
+            excircle_touch_b: self.excircle_touch_b.map(|point| point.to_f64()),
+            excircle_touch_c: self.excircle_touch_c.map(|point| point.to_f64()),
+            median_a: self.median_a.to_f64(),
+            median_b: self.median_b.to_f64(),
+            median_c: self.median_c.to_f64(),
+            altitude_a: self.altitude_a.to_f64(),
+            altitude_b: self.altitude_b.to_f64(),
+            altitude_c: self.altitude_c.to_f64(),
+            bisector_a: self.bisector_a.to_f64(),
+            bisector_b: self.bisector_b.to_f64(),
+            bisector_c: self.bisector_c.to_f64(),
+            median_foot_a: self.median_foot_a.to_f64(),
+            median_foot_b: self.median_foot_b.to_f64(),
+            median_foot_c: self.median_foot_c.to_f64(),
+            altitude_foot_a: self.altitude_foot_a.to_f64(),
+            altitude_foot_b: self.altitude_foot_b.to_f64(),
+            altitude_foot_c: self.altitude_foot_c.to_f64(),
+            bisector_foot_a: self.bisector_foot_a.to_f64(),
+            bisector_foot_b: self.bisector_foot_b.to_f64(),
+            bisector_foot_c: self.bisector_foot_c.to_f64(),
+            tolerance: self.tolerance,
+        }
+    }
+}
+
+impl TriangleSummary {
+    #[must_use]
+    pub const fn circumcircle(&self) -> Circle {
+        Circle::new(self.circumcenter, self.circumradius)
+    }
+
+    #[must_use]
+    pub const fn incircle(&self) -> Circle {
+        Circle::new(self.incenter, self.inradius)
+    }
+
+    #[must_use]
+    pub const fn nine_point_circle(&self) -> Circle {
+        Circle::new(self.nine_point_center, self.nine_point_radius)
+    }
+
+    /// The line through the circumcenter and the orthocenter, directed from
+    /// O to H. `None` for equilateral triangles, where every centre coincides.
+    #[must_use]
+    pub fn euler_line(&self) -> Option<Line> {
+        Line::through(self.circumcenter, self.orthocenter)
+    }
+
+    /// The sides BC, CA and AB, each running between the other two vertices.
+    #[must_use]
+    pub const fn sides(&self) -> [Segment; 3] {
+        [
+            Segment::new(self.vertex_b, self.vertex_c),
+            Segment::new(self.vertex_c, self.vertex_a),
+            Segment::new(self.vertex_a, self.vertex_b),
+        ]
+    }
+
+    /// Medians from A, B and C to their feet.
+    #[must_use]
+    pub const fn median_segments(&self) -> [Segment; 3] {
+        [
+            Segment::new(self.vertex_a, self.median_foot_a),
+            Segment::new(self.vertex_b, self.median_foot_b),
+            Segment::new(self.vertex_c, self.median_foot_c),
+        ]
+    }
+
+    /// Altitudes from A, B and C to their feet.
+    #[must_use]
+    pub const fn altitude_segments(&self) -> [Segment; 3] {
+        [
+            Segment::new(self.vertex_a, self.altitude_foot_a),
+            Segment::new(self.vertex_b, self.altitude_foot_b),
+            Segment::new(self.vertex_c, self.altitude_foot_c),
+        ]
+    }
+
+    /// Angle bisectors from A, B and C to their feet.
+    #[must_use]
+    pub const fn bisector_segments(&self) -> [Segment; 3] {
+        [
+            Segment::new(self.vertex_a, self.bisector_foot_a),
+            Segment::new(self.vertex_b, self.bisector_foot_b),
+            Segment::new(self.vertex_c, self.bisector_foot_c),
+        ]
+    }
+}
+
+#[must_use]
+pub fn angle_from_sides<T: Real>(opposite: T, adj1: T, adj2: T) -> T {
+    let one = T::from_i32(1);
     let numerator = opposite.mul_add(-opposite, adj1.mul_add(adj1, adj2 * adj2));
-    let denominator = 2.0 * adj1 * adj2;
-    let cos_val = (numerator / denominator).clamp(-1.0, 1.0);
+    let denominator = T::from_i32(2) * adj1 * adj2;
+    let cos_val = (numerator / denominator).clamp(-one, one);
     cos_val.acos()
 }
 
-pub fn median_length(opposite_side: f64, adj1: f64, adj2: f64) -> f64 {
-    let two_b_squared = 2.0 * adj1 * adj1;
-    let two_c_squared = 2.0 * adj2 * adj2;
+#[must_use]
+pub fn median_length<T: Real>(opposite_side: T, adj1: T, adj2: T) -> T {
+    let two = T::from_i32(2);
+    let two_b_squared = two * adj1 * adj1;
+    let two_c_squared = two * adj2 * adj2;
     let a_sq = opposite_side * opposite_side;
-    0.5 * (two_b_squared + two_c_squared - a_sq).max(0.0).sqrt()
+    T::from_f64(0.5) * (two_b_squared + two_c_squared - a_sq).max(T::zero()).sqrt()
+}
+
+/// `None` when `opposite_side` is zero.
+#[must_use]
+pub fn altitude_from_area<T: Scalar>(area: T, opposite_side: T) -> Option<T> {
+    (T::from_i32(2) * area).checked_div(opposite_side)
+}
+
+#[must_use]
+pub fn bisector_length<T: Real>(adj1: T, adj2: T, angle_rad: T) -> T {
+    let two = T::from_i32(2);
+    let half_cos = (angle_rad / two).cos();
+    (two * adj1 * adj2 * half_cos) / (adj1 + adj2)
 }
 
-pub fn altitude_from_area(area: f64, opposite_side: f64) -> f64 {
-    2.0 * area / opposite_side
+/// Classifies side lengths, treating lengths within `tolerance` as equal.
+#[must_use]
+pub fn classify_sides<T: Scalar>(
+    side_a: T,
+    side_b: T,
+    side_c: T,
+    tolerance: Tolerance,
+) -> SideClassification {
+    let [side_a, side_b, side_c] = [side_a, side_b, side_c].map(|side| side.to_f64());
+    side_class_of(
+        tolerance.approx_eq(side_a, side_b),
+        tolerance.approx_eq(side_b, side_c),
+        tolerance.approx_eq(side_a, side_c),
+    )
 }
 
-pub fn bisector_length(adj1: f64, adj2: f64, angle_rad: f64) -> f64 {
-    let half_cos = (angle_rad / 2.0).cos();
-    (2.0 * adj1 * adj2 * half_cos) / (adj1 + adj2)
+/// Classifies the sides of `tri` by comparing squared lengths exactly.
+/// `None` when a comparison cannot be decided, as for coordinates that are
+/// not finite.
+#[must_use]
+pub fn classify_sides_exact<T: Scalar>(tri: &Triangle<T>) -> Option<SideClassification> {
+    let Triangle { a, b, c } = tri;
+    let equal = |p, q, r, s| Some(T::compare_distances(p, q, r, s)? == Ordering::Equal);
+    Some(side_class_of(
+        equal(b, c, a, c)?,
+        equal(a, c, a, b)?,
+        equal(b, c, a, b)?,
+    ))
 }
 
-pub fn classify_sides(side_a: f64, side_b: f64, side_c: f64) -> SideClassification {
-    let ab = approx_eq(side_a, side_b);
-    let bc = approx_eq(side_b, side_c);
-    let ac = approx_eq(side_a, side_c);
+/// The class implied by which pairs of sides are equal: `a` and `b`, `b` and
+/// `c`, `a` and `c`.
+pub(crate) const fn side_class_of(ab: bool, bc: bool, ac: bool) -> SideClassification {
     if ab && bc && ac {
         SideClassification::Equilateral
     } else if ab || bc || ac {
@@ -114,9 +342,19 @@ pub fn classify_sides(side_a: f64, side_b: f64, side_c: f64) -> SideClassificati
     }
 }
 
-pub fn classify_angles(angle_a: f64, angle_b: f64, angle_c: f64) -> AngleClassification {
+/// Classifies angles in radians, treating angles within `tolerance` of a
+/// right angle as right.
+#[must_use]
+pub fn classify_angles<T: Scalar>(
+    angle_a: T,
+    angle_b: T,
+    angle_c: T,
+    tolerance: Tolerance,
+) -> AngleClassification {
+    let [angle_a, angle_b, angle_c] = [angle_a, angle_b, angle_c].map(|angle| angle.to_f64());
     let pi_half = std::f64::consts::PI / 2.0;
-    if approx_eq(angle_a, pi_half) || approx_eq(angle_b, pi_half) || approx_eq(angle_c, pi_half) {
+    let right = |angle| tolerance.approx_eq(angle, pi_half);
+    if right(angle_a) || right(angle_b) || right(angle_c) {
         AngleClassification::Right
     } else if angle_a > pi_half || angle_b > pi_half || angle_c > pi_half {
         AngleClassification::Obtuse
@@ -125,40 +363,177 @@ pub fn classify_angles(angle_a: f64, angle_b: f64, angle_c: f64) -> AngleClassif
     }
 }
 
-pub fn circumcenter(a: Point, b: Point, c: Point) -> Point {
-    let d = 2.0
-        * c.x
-            .mul_add(a.y - b.y, a.x.mul_add(b.y - c.y, b.x * (c.y - a.y)));
-    let a_sq = a.length_sq();
-    let b_sq = b.length_sq();
-    let c_sq = c.length_sq();
+/// Classifies the angles of `tri` from the exact signs of the dot products at
+/// each vertex. `None` when a sign cannot be decided, as for coordinates that
+/// are not finite.
+#[must_use]
+pub fn classify_angles_exact<T: Scalar>(tri: &Triangle<T>) -> Option<AngleClassification> {
+    let Triangle { a, b, c } = tri;
+    let signs = [
+        T::dot_sign(a, b, c)?,
+        T::dot_sign(b, c, a)?,
+        T::dot_sign(c, a, b)?,
+    ];
+    Some(angle_class_of(&signs))
+}
+
+/// The class implied by the signs of the dot products at the three vertices.
+pub(crate) fn angle_class_of(signs: &[Ordering]) -> AngleClassification {
+    if signs.contains(&Ordering::Equal) {
+        AngleClassification::Right
+    } else if signs.contains(&Ordering::Less) {
+        AngleClassification::Obtuse
+    } else {
+        AngleClassification::Acute
+    }
+}
+
+/// `None` when the points are collinear.
+#[must_use]
+pub fn circumcenter<T: Scalar>(a: Point<T>, b: Point<T>, c: Point<T>) -> Option<Point<T>> {
+    let a_sq = a.clone().length_sq();
+    let b_sq = b.clone().length_sq();
+    let c_sq = c.clone().length_sq();
+    let (dy_ab, dy_bc, dy_ca) = (a.y.clone() - b.y.clone(), b.y - c.y.clone(), c.y - a.y);
+    let (dx_ba, dx_cb, dx_ac) = (
+        b.x.clone() - a.x.clone(),
+        c.x.clone() - b.x.clone(),
+        a.x.clone() - c.x.clone(),
+    );
+    let d = T::from_i32(2)
+        * c.x.mul_add(
+            dy_ab.clone(),
+            a.x.mul_add(dy_bc.clone(), b.x * dy_ca.clone()),
+        );
 
-    let ux = c_sq.mul_add(a.y - b.y, a_sq.mul_add(b.y - c.y, b_sq * (c.y - a.y))) / d;
-    let uy = c_sq.mul_add(b.x - a.x, a_sq.mul_add(c.x - b.x, b_sq * (a.x - c.x))) / d;
-    Point::new(ux, uy)
+    let ux = c_sq
+        .clone()
+        .mul_add(dy_ab, a_sq.clone().mul_add(dy_bc, b_sq.clone() * dy_ca));
+    let uy = c_sq.mul_add(dx_ba, a_sq.mul_add(dx_cb, b_sq * dx_ac));
+    Point::new(ux, uy).checked_div(d)
 }
 
-pub fn incenter(pa: Point, pb: Point, pc: Point, side_a: f64, side_b: f64, side_c: f64) -> Point {
-    let perimeter = side_a + side_b + side_c;
-    let x = side_c.mul_add(pc.x, side_a.mul_add(pa.x, side_b * pb.x)) / perimeter;
-    let y = side_c.mul_add(pc.y, side_a.mul_add(pa.y, side_b * pb.y)) / perimeter;
-    Point::new(x, y)
+/// `None` when the sides add up to zero, as for three equal points.
+#[must_use]
+pub fn incenter<T: Scalar>(
+    pa: Point<T>,
+    pb: Point<T>,
+    pc: Point<T>,
+    side_a: T,
+    side_b: T,
+    side_c: T,
+) -> Option<Point<T>> {
+    let perimeter = side_a.clone() + side_b.clone() + side_c.clone();
+    let x = side_c
+        .clone()
+        .mul_add(pc.x, side_a.clone().mul_add(pa.x, side_b.clone() * pb.x));
+    let y = side_c.mul_add(pc.y, side_a.mul_add(pa.y, side_b * pb.y));
+    Point::new(x, y).checked_div(perimeter)
+}
+
+/// Centre of the excircle opposite `pa`, i.e. the incenter formula with the
+/// weight of `pa` negated. `None` when `side_a` is the sum of the other two,
+/// as for collinear points.
+#[must_use]
+pub fn excenter<T: Scalar>(
+    pa: Point<T>,
+    pb: Point<T>,
+    pc: Point<T>,
+    side_a: T,
+    side_b: T,
+    side_c: T,
+) -> Option<Point<T>> {
+    let weight = side_b.clone() + side_c.clone() - side_a.clone();
+    let x = side_c
+        .clone()
+        .mul_add(pc.x, side_b.clone().mul_add(pb.x, -side_a.clone() * pa.x));
+    let y = side_c.mul_add(pc.y, side_b.mul_add(pb.y, -side_a * pa.y));
+    Point::new(x, y).checked_div(weight)
 }
 
-pub fn centroid(a: Point, b: Point, c: Point) -> Point {
-    (a + b + c) / 3.0
+/// Orthogonal projection of `point` onto the line through `from` and `to`.
+/// `None` when `from` and `to` coincide.
+#[must_use]
+pub fn foot_on_line<T: Scalar>(point: Point<T>, from: Point<T>, to: Point<T>) -> Option<Point<T>> {
+    let dir = to - from.clone();
+    let along = (point - from.clone())
+        .dot(dir.clone())
+        .checked_div(dir.clone().length_sq())?;
+    Some(from + dir * along)
 }
 
-pub fn orthocenter(a: Point, b: Point, c: Point, circumference: Point) -> Point {
+/// Foot of the bisector from the vertex between `from` and `to`, where
+/// `adj_from` and `adj_to` are the lengths of the sides ending there. It
+/// divides the opposite side in the ratio `adj_from : adj_to`.
+///
+/// `None` when both lengths are zero.
+#[must_use]
+pub fn bisector_foot<T: Scalar>(
+    from: Point<T>,
+    to: Point<T>,
+    adj_from: T,
+    adj_to: T,
+) -> Option<Point<T>> {
+    (from * adj_to.clone() + to * adj_from.clone()).checked_div(adj_from + adj_to)
+}
+
+/// Touch points of the circle centred at `center` on the lines BC, CA and AB.
+fn touch_points<T: Real>(center: Point<T>, a: Point<T>, b: Point<T>, c: Point<T>) -> [Point<T>; 3] {
+    [
+        foot_on_line(center, b, c),
+        foot_on_line(center, c, a),
+        foot_on_line(center, a, b),
+    ]
+    .map(point_or_nan)
+}
+
+#[must_use]
+pub fn centroid<T: Scalar>(a: Point<T>, b: Point<T>, c: Point<T>) -> Point<T> {
+    let sum = a + b + c;
+    Point::new(sum.x.third(), sum.y.third())
+}
+
+#[must_use]
+pub fn orthocenter<T: Scalar>(
+    a: Point<T>,
+    b: Point<T>,
+    c: Point<T>,
+    circumference: Point<T>,
+) -> Point<T> {
     let g = centroid(a, b, c);
-    (g * 3.0) - (circumference * 2.0)
+    (g * T::from_i32(3)) - (circumference * T::from_i32(2))
+}
+
+#[must_use]
+pub fn nine_point_center<T: Scalar>(circumference: Point<T>, orthogonal: Point<T>) -> Point<T> {
+    let sum = circumference + orthogonal;
+    Point::new(sum.x.half(), sum.y.half())
+}
+
+/// The NaN that floating-point division by zero would have given in place of
+/// a quantity a degenerate triangle lacks.
+fn or_nan<T: Real>(value: Option<T>) -> T {
+    value.unwrap_or_else(|| T::from_f64(f64::NAN))
+}
+
+/// [`or_nan`] for points.
+fn point_or_nan<T: Real>(point: Option<Point<T>>) -> Point<T> {
+    point.unwrap_or_else(|| Point::splat(T::from_f64(f64::NAN)))
 }
 
-pub fn nine_point_center(circumference: Point, orthogonal: Point) -> Point {
-    (circumference + orthogonal) / 2.0
+/// Every quantity of `tri`. Those a degenerate triangle lacks, such as the
+/// circumcenter of collinear points, are NaN.
+#[must_use]
+pub fn compute_summary<T: Real>(tri: &Triangle<T>) -> TriangleSummary<T> {
+    compute_summary_with(tri, Tolerance::default())
 }
 
-pub fn compute_summary(tri: &Triangle) -> TriangleSummary {
+/// [`compute_summary`] classifying and verifying with `tolerance`.
+#[must_use]
+pub fn compute_summary_with<T: Real>(
+    tri: &Triangle<T>,
+    tolerance: Tolerance,
+) -> TriangleSummary<T> {
     let a = tri.a;
     let b = tri.b;
     let c = tri.c;
@@ -167,36 +542,55 @@ pub fn compute_summary(tri: &Triangle) -> TriangleSummary {
     let side_b = a.distance_to(c);
     let side_c = a.distance_to(b);
 
+    let two = T::from_i32(2);
     let perimeter = side_a + side_b + side_c;
-    let s = perimeter / 2.0;
+    let s = perimeter / two;
 
     // this has better accuracy with needle like triangles than regular formula
-    let area = 0.5 * (b - a).cross(c - a).abs();
+    let area = T::from_f64(0.5) * (b - a).cross(c - a).abs();
 
     let angle_a_rad = angle_from_sides(side_a, side_b, side_c);
     let angle_b_rad = angle_from_sides(side_b, side_a, side_c);
     let angle_c_rad = angle_from_sides(side_c, side_a, side_b);
 
-    let side_class = classify_sides(side_a, side_b, side_c);
-    let angle_class = classify_angles(angle_a_rad, angle_b_rad, angle_c_rad);
-
-    let circumcenter = circumcenter(a, b, c);
+    // Undecidable exact classes, for coordinates that are not finite, fall
+    // back to the classes up to the tolerance.
+    let nearly_side_class = classify_sides(side_a, side_b, side_c, tolerance);
+    let exact_side_class = classify_sides_exact(tri).unwrap_or(nearly_side_class);
+    let nearly_angle_class = classify_angles(angle_a_rad, angle_b_rad, angle_c_rad, tolerance);
+    let exact_angle_class = classify_angles_exact(tri).unwrap_or(nearly_angle_class);
+    // Equilateral orders before Isosceles before Scalene, so the minimum is
+    // the more symmetric class.
+    let side_class = nearly_side_class.min(exact_side_class);
+    let angle_class = match nearly_angle_class {
+        AngleClassification::Right => AngleClassification::Right,
+        _ => exact_angle_class,
+    };
+
+    let circumcenter = point_or_nan(circumcenter(a, b, c));
     let centroid = centroid(a, b, c);
-    let incenter = incenter(a, b, c, side_a, side_b, side_c);
+    let incenter = point_or_nan(incenter(a, b, c, side_a, side_b, side_c));
     let orthocenter = orthocenter(a, b, c, circumcenter);
     let nine_point_center = nine_point_center(circumcenter, orthocenter);
 
     let inradius = area / s;
-    let circumradius = (side_a * side_b * side_c) / (4.0 * area);
-    let nine_point_radius = circumradius / 2.0;
+    let circumradius = (side_a * side_b * side_c) / (T::from_i32(4) * area);
+    let nine_point_radius = circumradius / two;
+
+    let excenter_a = point_or_nan(excenter(a, b, c, side_a, side_b, side_c));
+    let excenter_b = point_or_nan(excenter(b, c, a, side_b, side_c, side_a));
+    let excenter_c = point_or_nan(excenter(c, a, b, side_c, side_a, side_b));
+    let exradius_a = area / (s - side_a);
+    let exradius_b = area / (s - side_b);
+    let exradius_c = area / (s - side_c);
 
     let median_a = median_length(side_a, side_b, side_c);
     let median_b = median_length(side_b, side_a, side_c);
     let median_c = median_length(side_c, side_a, side_b);
 
-    let altitude_a = altitude_from_area(area, side_a);
-    let altitude_b = altitude_from_area(area, side_b);
-    let altitude_c = altitude_from_area(area, side_c);
+    let altitude_a = or_nan(altitude_from_area(area, side_a));
+    let altitude_b = or_nan(altitude_from_area(area, side_b));
+    let altitude_c = or_nan(altitude_from_area(area, side_c));
 
     let bisector_a = bisector_length(side_b, side_c, angle_a_rad);
     let bisector_b = bisector_length(side_a, side_c, angle_b_rad);
@@ -217,6 +611,8 @@ pub fn compute_summary(tri: &Triangle) -> TriangleSummary {
         area,
         side_class,
         angle_class,
+        exact_side_class,
+        exact_angle_class,
         centroid,
         incenter,
         circumcenter,
@@ -225,6 +621,15 @@ pub fn compute_summary(tri: &Triangle) -> TriangleSummary {
         inradius,
         circumradius,
         nine_point_radius,
+        excenter_a,
+        excenter_b,
+        excenter_c,
+        exradius_a,
+        exradius_b,
+        exradius_c,
+        excircle_touch_a: touch_points(excenter_a, a, b, c),
+        excircle_touch_b: touch_points(excenter_b, a, b, c),
+        excircle_touch_c: touch_points(excenter_c, a, b, c),
         median_a,
         median_b,
         median_c,
@@ -234,170 +639,291 @@ pub fn compute_summary(tri: &Triangle) -> TriangleSummary {
         bisector_a,
         bisector_b,
         bisector_c,
+        median_foot_a: (b + c) / two,
+        median_foot_b: (c + a) / two,
+        median_foot_c: (a + b) / two,
+        altitude_foot_a: point_or_nan(foot_on_line(a, b, c)),
+        altitude_foot_b: point_or_nan(foot_on_line(b, c, a)),
+        altitude_foot_c: point_or_nan(foot_on_line(c, a, b)),
+        bisector_foot_a: point_or_nan(bisector_foot(b, c, side_c, side_b)),
+        bisector_foot_b: point_or_nan(bisector_foot(c, a, side_a, side_c)),
+        bisector_foot_c: point_or_nan(bisector_foot(a, b, side_b, side_a)),
+        tolerance,
     }
 }
 
-// Display for TriangleSummary was generated by AI and then checked and reviewed by me.
-impl Display for TriangleSummary {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let w = 22; // label column width
-        let sep = "─".repeat(60);
-
-        writeln!(f, "\n{sep}")?;
-        writeln!(f, "           ▲  TRIANGLE SUMMARY  ▲")?;
-        writeln!(f, "{sep}")?;
+const VERTEX_NAMES: [&str; 3] = ["A", "B", "C"];
 
-        writeln!(f, "\n┌─ Vertices")?;
-        writeln!(f, "│  {:>w$}  {}", "Vertex A:", self.vertex_a)?;
-        writeln!(f, "│  {:>w$}  {}", "Vertex B:", self.vertex_b)?;
-        writeln!(f, "│  {:>w$}  {}", "Vertex C:", self.vertex_c)?;
+/// Notes the exact class next to a class that only holds up to the tolerance.
+fn exactly<T: Copy + PartialEq + Display>(nearly: T, exact: T) -> String {
+    if nearly == exact {
+        String::new()
+    } else {
+        format!(" (within tolerance, exactly {exact})")
+    }
+}
 
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Side Lengths (opposite vertex)")?;
-        writeln!(f, "│  {:>w$}  {:.10}  (BC)", "Side a:", self.side_a)?;
-        writeln!(f, "│  {:>w$}  {:.10}  (AC)", "Side b:", self.side_b)?;
-        writeln!(f, "│  {:>w$}  {:.10}  (AB)", "Side c:", self.side_c)?;
+/// Shown through the `f64` summary, so every scalar type prints alike.
+impl<T: Real> Display for TriangleSummary<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_summary(&self.to_f64(), f)
+    }
+}
 
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Angles")?;
-        writeln!(
-            f,
-            "│  {:>w$}  {:.10} rad  ({:.6}°)",
-            "Angle A:",
-            self.angle_a_rad,
-            self.angle_a_rad.to_degrees()
-        )?;
+// Display for TriangleSummary was generated by AI and then checked and reviewed by me.
+#[allow(clippy::too_many_lines)]
+fn write_summary(summary: &TriangleSummary, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let w = 22; // label column width
+    let sep = "─".repeat(60);
+
+    writeln!(f, "\n{sep}")?;
+    writeln!(f, "           ▲  TRIANGLE SUMMARY  ▲")?;
+    writeln!(f, "{sep}")?;
+
+    writeln!(f, "\n┌─ Vertices")?;
+    writeln!(f, "│  {:>w$}  {}", "Vertex A:", summary.vertex_a)?;
+    writeln!(f, "│  {:>w$}  {}", "Vertex B:", summary.vertex_b)?;
+    writeln!(f, "│  {:>w$}  {}", "Vertex C:", summary.vertex_c)?;
+
+    writeln!(f, "│")?;
+    writeln!(f, "├─ Side Lengths (opposite vertex)")?;
+    writeln!(f, "│  {:>w$}  {:.10}  (BC)", "Side a:", summary.side_a)?;
+    writeln!(f, "│  {:>w$}  {:.10}  (AC)", "Side b:", summary.side_b)?;
+    writeln!(f, "│  {:>w$}  {:.10}  (AB)", "Side c:", summary.side_c)?;
+
+    writeln!(f, "│")?;
+    writeln!(f, "├─ Angles")?;
+    writeln!(
+        f,
+        "│  {:>w$}  {:.10} rad  ({:.6}°)",
+        "Angle A:",
+        summary.angle_a_rad,
+        summary.angle_a_rad.to_degrees()
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  {:.10} rad  ({:.6}°)",
+        "Angle B:",
+        summary.angle_b_rad,
+        summary.angle_b_rad.to_degrees()
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  {:.10} rad  ({:.6}°)",
+        "Angle C:",
+        summary.angle_c_rad,
+        summary.angle_c_rad.to_degrees()
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  {:.10}°",
+        "Sum of angles:",
+        (summary.angle_a_rad + summary.angle_b_rad + summary.angle_c_rad).to_degrees()
+    )?;
+
+    writeln!(f, "│")?;
+    writeln!(f, "├─ Classification")?;
+    writeln!(
+        f,
+        "│  {:>w$}  {}{}",
+        "By sides:",
+        summary.side_class,
+        exactly(summary.side_class, summary.exact_side_class)
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  {}{}",
+        "By angles:",
+        summary.angle_class,
+        exactly(summary.angle_class, summary.exact_angle_class)
+    )?;
+
+    writeln!(f, "│")?;
+    writeln!(f, "├─ Core Metrics")?;
+    writeln!(f, "│  {:>w$}  {:.10}", "Perimeter:", summary.perimeter)?;
+    writeln!(
+        f,
+        "│  {:>w$}  {:.10}",
+        "Semi-perimeter:", summary.semi_perimeter
+    )?;
+    writeln!(f, "│  {:>w$}  {:.10}", "Area:", summary.area)?;
+
+    writeln!(f, "│")?;
+    writeln!(f, "├─ Notable Centres")?;
+    writeln!(
+        f,
+        "│  {:>w$}  ({:.8}, {:.8})",
+        "Centroid:", summary.centroid.x, summary.centroid.y
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  ({:.8}, {:.8})",
+        "Incenter:", summary.incenter.x, summary.incenter.y
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  ({:.8}, {:.8})",
+        "Circumcenter:", summary.circumcenter.x, summary.circumcenter.y
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  ({:.8}, {:.8})",
+        "Orthocenter:", summary.orthocenter.x, summary.orthocenter.y
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  ({:.8}, {:.8})",
+        "Nine-point center:", summary.nine_point_center.x, summary.nine_point_center.y
+    )?;
+
+    writeln!(f, "│")?;
+    writeln!(f, "├─ Radii")?;
+    writeln!(f, "│  {:>w$}  {:.10}", "Inradius:", summary.inradius)?;
+    writeln!(
+        f,
+        "│  {:>w$}  {:.10}",
+        "Circumradius:", summary.circumradius
+    )?;
+    writeln!(
+        f,
+        "│  {:>w$}  {:.10}",
+        "Nine-point radius:", summary.nine_point_radius
+    )?;
+
+    writeln!(f, "│")?;
+    writeln!(f, "├─ Excircles (opposite vertex)")?;
+    let excircles = [
+        (
+            "A",
+            summary.excenter_a,
+            summary.exradius_a,
+            summary.excircle_touch_a,
+        ),
+        (
+            "B",
+            summary.excenter_b,
+            summary.exradius_b,
+            summary.excircle_touch_b,
+        ),
+        (
+            "C",
+            summary.excenter_c,
+            summary.exradius_c,
+            summary.excircle_touch_c,
+        ),
+    ];
+    for (label, center, radius, [on_bc, on_ca, on_ab]) in excircles {
         writeln!(
             f,
-            "│  {:>w$}  {:.10} rad  ({:.6}°)",
-            "Angle B:",
-            self.angle_b_rad,
-            self.angle_b_rad.to_degrees()
+            "│  {:>w$}  ({:.8}, {:.8})",
+            format!("Excenter I_{label}:"),
+            center.x,
+            center.y
         )?;
         writeln!(
             f,
-            "│  {:>w$}  {:.10} rad  ({:.6}°)",
-            "Angle C:",
-            self.angle_c_rad,
-            self.angle_c_rad.to_degrees()
+            "│  {:>w$}  {:.10}",
+            format!("Exradius r_{}:", label.to_ascii_lowercase()),
+            radius
         )?;
         writeln!(
             f,
-            "│  {:>w$}  {:.10}°",
-            "Sum of angles:",
-            (self.angle_a_rad + self.angle_b_rad + self.angle_c_rad).to_degrees()
+            "│  {:>w$}  BC ({:.6}, {:.6})  CA ({:.6}, {:.6})  AB ({:.6}, {:.6})",
+            "Touch points:", on_bc.x, on_bc.y, on_ca.x, on_ca.y, on_ab.x, on_ab.y
         )?;
+    }
 
+    writeln!(f, "│")?;
+    let sides = summary.sides();
+    let side_names = ["BC", "CA", "AB"];
+    let sections = [
+        (
+            "Medians",
+            "m",
+            [summary.median_a, summary.median_b, summary.median_c],
+        ),
+        (
+            "Altitudes",
+            "h",
+            [summary.altitude_a, summary.altitude_b, summary.altitude_c],
+        ),
+        (
+            "Angle Bisectors",
+            "t",
+            [summary.bisector_a, summary.bisector_b, summary.bisector_c],
+        ),
+    ];
+    let segments = [
+        summary.median_segments(),
+        summary.altitude_segments(),
+        summary.bisector_segments(),
+    ];
+    for ((title, symbol, lengths), segments) in sections.into_iter().zip(segments) {
+        writeln!(f, "├─ {title}")?;
+        for (idx, (length, segment)) in lengths.into_iter().zip(segments).enumerate() {
+            let foot = segment.end;
+            let extended = if sides[idx].projects_outside(foot) {
+                " (extended)"
+            } else {
+                ""
+            };
+            writeln!(
+                f,
+                "│  {:>w$}  {:.10}  foot ({:.8}, {:.8}) on {}{extended}",
+                format!("{symbol}_{}:", VERTEX_NAMES[idx]),
+                length,
+                foot.x,
+                foot.y,
+                side_names[idx]
+            )?;
+        }
         writeln!(f, "│")?;
-        writeln!(f, "├─ Classification")?;
-        writeln!(f, "│  {:>w$}  {}", "By sides:", self.side_class)?;
-        writeln!(f, "│  {:>w$}  {}", "By angles:", self.angle_class)?;
-
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Core Metrics")?;
-        writeln!(f, "│  {:>w$}  {:.10}", "Perimeter:", self.perimeter)?;
-        writeln!(
-            f,
-            "│  {:>w$}  {:.10}",
-            "Semi-perimeter:", self.semi_perimeter
-        )?;
-        writeln!(f, "│  {:>w$}  {:.10}", "Area:", self.area)?;
+    }
 
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Notable Centres")?;
-        writeln!(
-            f,
-            "│  {:>w$}  ({:.8}, {:.8})",
-            "Centroid:", self.centroid.x, self.centroid.y
-        )?;
-        writeln!(
-            f,
-            "│  {:>w$}  ({:.8}, {:.8})",
-            "Incenter:", self.incenter.x, self.incenter.y
-        )?;
+    writeln!(f, "└─ Verification")?;
+    if summary.euler_line_holds() {
         writeln!(
             f,
-            "│  {:>w$}  ({:.8}, {:.8})",
-            "Circumcenter:", self.circumcenter.x, self.circumcenter.y
-        )?;
-        writeln!(
-            f,
-            "│  {:>w$}  ({:.8}, {:.8})",
-            "Orthocenter:", self.orthocenter.x, self.orthocenter.y
+            "│  Circumcenter, Centroid, and Orthocenter are collinear ✓"
         )?;
+    } else {
         writeln!(
             f,
-            "│  {:>w$}  ({:.8}, {:.8})",
-            "Nine-point center:", self.nine_point_center.x, self.nine_point_center.y
+            "│  Error: Euler line residual is large: {:.2e}",
+            summary.euler_line_residual()
         )?;
-
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Radii")?;
-        writeln!(f, "│  {:>w$}  {:.10}", "Inradius:", self.inradius)?;
-        writeln!(f, "│  {:>w$}  {:.10}", "Circumradius:", self.circumradius)?;
+    }
+    if summary.excircle_identity_holds() {
+        writeln!(f, "│  1/r = 1/r_a + 1/r_b + 1/r_c holds ✓")?;
+    } else {
         writeln!(
             f,
-            "│  {:>w$}  {:.10}",
-            "Nine-point radius:", self.nine_point_radius
+            "│  Error: exradii do not match the inradius: {:.2e}",
+            summary.excircle_residual()
         )?;
-
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Medians")?;
-        writeln!(f, "│  {:>w$}  {:.10}", "m_A:", self.median_a)?;
-        writeln!(f, "│  {:>w$}  {:.10}", "m_B:", self.median_b)?;
-        writeln!(f, "│  {:>w$}  {:.10}", "m_C:", self.median_c)?;
-
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Altitudes")?;
-        writeln!(f, "│  {:>w$}  {:.10}", "h_A:", self.altitude_a)?;
-        writeln!(f, "│  {:>w$}  {:.10}", "h_B:", self.altitude_b)?;
-        writeln!(f, "│  {:>w$}  {:.10}", "h_C:", self.altitude_c)?;
-
-        writeln!(f, "│")?;
-        writeln!(f, "├─ Angle Bisectors")?;
-        writeln!(f, "│  {:>w$}  {:.10}", "t_A:", self.bisector_a)?;
-        writeln!(f, "│  {:>w$}  {:.10}", "t_B:", self.bisector_b)?;
-        writeln!(f, "│  {:>w$}  {:.10}", "t_C:", self.bisector_c)?;
-
-        writeln!(f, "│")?;
-        writeln!(f, "└─ Euler Line Verification")?;
-        let og = self.centroid - self.circumcenter;
-        let oh = self.orthocenter - self.circumcenter;
-        let euler_cross = og.cross(oh).abs();
-        if euler_cross < 1e-6 {
-            writeln!(
-                f,
-                "│  Circumcenter, Centroid, and Orthocenter are collinear ✓"
-            )?;
-        } else {
-            writeln!(
-                f,
-                "│  Error: Euler line residual is large: {euler_cross:.2e}"
-            )?;
-        }
-
-        write!(f, "\n{sep}")
     }
+
+    write!(f, "\n{sep}")
 }
 
+#[must_use]
 pub const fn rad_to_deg(rad: f64) -> f64 {
     rad.to_degrees()
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-
-    fn approx_eq_pt(p1: Point, p2: Point) -> bool {
-        approx_eq(p1.x, p2.x) && approx_eq(p1.y, p2.y)
-    }
+    use crate::test_support::{approx_eq, approx_eq_pt, scalene};
 
     #[test]
     fn test_classifications() {
+        let tol = Tolerance::default();
         // Equilateral
         let side_eq = 1.0;
         assert_eq!(
-            classify_sides(side_eq, side_eq, side_eq),
+            classify_sides(side_eq, side_eq, side_eq, tol),
             SideClassification::Equilateral
         );
 
@@ -406,24 +932,104 @@ mod tests {
         let side_b = 1.0;
         let side_c = 2.0f64.sqrt();
         assert_eq!(
-            classify_sides(side_a, side_b, side_c),
+            classify_sides(side_a, side_b, side_c, tol),
             SideClassification::Isosceles
         );
 
         let pi_half = std::f64::consts::PI / 2.0;
         let pi_quarter = std::f64::consts::PI / 4.0;
         assert_eq!(
-            classify_angles(pi_half, pi_quarter, pi_quarter),
+            classify_angles(pi_half, pi_quarter, pi_quarter, tol),
             AngleClassification::Right
         );
 
         // Obtuse Scalene
-        assert_eq!(classify_sides(10.0, 6.0, 5.0), SideClassification::Scalene);
+        assert_eq!(
+            classify_sides(10.0, 6.0, 5.0, tol),
+            SideClassification::Scalene
+        );
         // 10^2 = 100, 6^2 + 5^2 = 36 + 25 = 61. 100 > 61 => Obtuse
         let a = angle_from_sides(10.0, 6.0, 5.0);
         let b = angle_from_sides(6.0, 10.0, 5.0);
         let c = angle_from_sides(5.0, 10.0, 6.0);
-        assert_eq!(classify_angles(a, b, c), AngleClassification::Obtuse);
+        assert_eq!(classify_angles(a, b, c, tol), AngleClassification::Obtuse);
+    }
+
+    #[test]
+    fn test_classification_is_scale_invariant() {
+        let tol = Tolerance::default();
+        for scale in [1e-9, 1.0, 1e9] {
+            // Differ by one part in a million: scalene at every size.
+            assert_eq!(
+                classify_sides(scale, scale * (1.0 + 1e-6), scale * 1.5, tol),
+                SideClassification::Scalene,
+                "{scale}"
+            );
+            let s = compute_summary(&Triangle::new(
+                Point::new(0.0, 0.0),
+                Point::new(4.0 * scale, 0.0),
+                Point::new(0.0, 3.0 * scale),
+            ));
+            assert_eq!(s.angle_class, AngleClassification::Right, "{scale}");
+            assert!(
+                s.euler_line_holds() && s.excircle_identity_holds(),
+                "{scale}"
+            );
+        }
+        // A looser tolerance merges nearly equal sides.
+        assert_eq!(
+            classify_sides(1.0, 1.0 + 1e-6, 1.5, Tolerance::relative(1e-5)),
+            SideClassification::Isosceles
+        );
+        let pi_half = std::f64::consts::FRAC_PI_2;
+        let angles = (pi_half + 1e-6, pi_half / 2.0, pi_half / 2.0 - 1e-6);
+        assert_eq!(
+            classify_angles(angles.0, angles.1, angles.2, tol),
+            AngleClassification::Obtuse
+        );
+        assert_eq!(
+            classify_angles(angles.0, angles.1, angles.2, Tolerance::relative(1e-5)),
+            AngleClassification::Right
+        );
+    }
+
+    #[test]
+    fn test_exact_and_nearly_classes() {
+        let tiny = 2f64.powi(-40);
+        // A is only a hair away from a right angle and AC from AB.
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(tiny, 1.0),
+        );
+        let s = compute_summary(&tri);
+        assert_eq!(s.exact_angle_class, AngleClassification::Acute);
+        assert_eq!(s.angle_class, AngleClassification::Right);
+        assert_eq!(s.exact_side_class, SideClassification::Scalene);
+        assert_eq!(s.side_class, SideClassification::Isosceles);
+        assert!(s.to_string().contains("(within tolerance, exactly Acute)"));
+
+        // Without a tolerance the angle is no longer taken as right.
+        let exact = compute_summary_with(&tri, Tolerance::relative(0.0));
+        assert_eq!(exact.angle_class, AngleClassification::Acute);
+
+        // An exact right isosceles triangle with coordinates whose
+        // differences round: the exact classes still see it.
+        let tri = Triangle::new(
+            Point::new(0.1, 0.1),
+            Point::new(0.1, 0.7),
+            Point::new(0.7, 0.1),
+        );
+        let s = compute_summary_with(&tri, Tolerance::relative(0.0));
+        assert_eq!(
+            classify_angles_exact(&tri),
+            Some(AngleClassification::Right)
+        );
+        assert_eq!(s.angle_class, AngleClassification::Right);
+        assert_eq!(
+            classify_sides_exact(&tri),
+            Some(SideClassification::Isosceles)
+        );
     }
 
     #[test]
@@ -499,7 +1105,7 @@ mod tests {
 
     #[test]
     fn test_large_coordinates() {
-        let tri = Triangle::new(
+        let tri: Triangle = Triangle::new(
             Point::new(0.0, 0.0),
             Point::new(1e10, 0.0),
             Point::new(0.0, 1e10),
@@ -511,12 +1117,7 @@ mod tests {
 
     #[test]
     fn test_euler_line_and_nine_point_circle() {
-        // Scalene Acute triangle
-        let tri = Triangle::new(
-            Point::new(0.0, 0.0),
-            Point::new(10.0, 0.0),
-            Point::new(3.0, 7.0),
-        );
+        let tri = scalene();
         let s = compute_summary(&tri);
 
         // Euler line: O, G, H are collinear
@@ -550,4 +1151,181 @@ mod tests {
             s.nine_point_radius
         );
     }
+
+    #[test]
+    fn test_summary_primitives() {
+        let tri = scalene();
+        let s = compute_summary(&tri);
+
+        let euler = s.euler_line().unwrap();
+        assert!(approx_eq(euler.distance_to(s.centroid), 0.0));
+        assert!(approx_eq(euler.distance_to(s.nine_point_center), 0.0));
+
+        for vertex in [tri.a, tri.b, tri.c] {
+            assert!(approx_eq(
+                s.circumcircle().center.distance_to(vertex),
+                s.circumradius
+            ));
+        }
+        // The incircle touches every side once, the nine-point circle passes
+        // through the midpoints.
+        for side in s.sides() {
+            assert_eq!(s.incircle().intersect_line(&side.line().unwrap()).len(), 1);
+            assert!(approx_eq(
+                s.nine_point_circle().center.distance_to(side.midpoint()),
+                s.nine_point_radius
+            ));
+        }
+
+        let h = 3.0f64.sqrt() / 2.0;
+        let equilateral = compute_summary(&Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.5, h),
+        ));
+        assert!(
+            equilateral
+                .euler_line()
+                .is_none_or(|line| line.direction.length_sq() < 1e-24)
+        );
+    }
+
+    #[test]
+    fn test_excircles_right_3_4_5() {
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        );
+        let s = compute_summary(&tri);
+
+        // r_a = area / (s - a) = 6 / (6 - 5)
+        assert!(approx_eq(s.exradius_a, 6.0));
+        assert!(approx_eq(s.exradius_b, 2.0));
+        assert!(approx_eq(s.exradius_c, 3.0));
+        // The A-excircle sits beyond the hypotenuse, tangent to both legs.
+        assert!(approx_eq_pt(s.excenter_a, Point::new(6.0, 6.0)));
+        assert!(approx_eq_pt(s.excenter_b, Point::new(-2.0, 2.0)));
+        assert!(approx_eq_pt(s.excenter_c, Point::new(3.0, -3.0)));
+        assert!(approx_eq_pt(s.excircle_touch_a[2], Point::new(6.0, 0.0)));
+        assert!(approx_eq_pt(s.excircle_touch_a[1], Point::new(0.0, 6.0)));
+        assert!(s.excircle_residual() < 1e-12);
+    }
+
+    #[test]
+    fn test_cevian_feet_right_3_4_5() {
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        );
+        let s = compute_summary(&tri);
+        // The right angle at A projects onto the hypotenuse at (36/25, 48/25).
+        assert!(approx_eq_pt(s.altitude_foot_a, Point::new(1.44, 1.92)));
+        // The legs are altitudes of each other.
+        assert!(approx_eq_pt(s.altitude_foot_b, tri.a));
+        // BD / DC = AB / AC = 4 / 3
+        let foot = s.bisector_foot_a;
+        assert!(approx_eq(
+            foot.distance_to(tri.b) / foot.distance_to(tri.c),
+            4.0 / 3.0
+        ));
+    }
+
+    #[test]
+    fn test_feet_of_medians_altitudes_and_bisectors() {
+        // Obtuse at A: the altitudes from B and C fall outside the triangle.
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(-2.0, 3.0),
+        );
+        let s = compute_summary(&tri);
+        assert_eq!(s.angle_class, AngleClassification::Obtuse);
+
+        assert!(approx_eq_pt(s.median_foot_a, Point::new(1.0, 1.5)));
+        assert!(approx_eq_pt(s.altitude_foot_c, Point::new(-2.0, 0.0)));
+        let sides = s.sides();
+        assert!(sides[2].projects_outside(s.altitude_foot_c));
+        assert!(!sides[0].projects_outside(s.altitude_foot_a));
+
+        let lengths = [
+            [s.median_a, s.median_b, s.median_c],
+            [s.altitude_a, s.altitude_b, s.altitude_c],
+            [s.bisector_a, s.bisector_b, s.bisector_c],
+        ];
+        let segments = [
+            s.median_segments(),
+            s.altitude_segments(),
+            s.bisector_segments(),
+        ];
+        for (lengths, segments) in lengths.into_iter().zip(segments) {
+            for ((length, segment), side) in lengths.into_iter().zip(segments).zip(sides) {
+                assert!(approx_eq(segment.length(), length));
+                // Every foot lies on the line through the opposite side.
+                assert!(approx_eq(
+                    (segment.end - side.start).cross(side.end - side.start),
+                    0.0
+                ));
+            }
+        }
+
+        // Altitudes are perpendicular to their side.
+        for (segment, side) in s.altitude_segments().into_iter().zip(sides) {
+            let along = segment.end - segment.start;
+            assert!(approx_eq(along.dot(side.end - side.start), 0.0));
+        }
+        // The bisector from A divides BC in the ratio c : b.
+        assert!(approx_eq(
+            s.vertex_b.distance_to(s.bisector_foot_a) / s.vertex_c.distance_to(s.bisector_foot_a),
+            s.side_c / s.side_b
+        ));
+    }
+
+    #[test]
+    fn test_excircle_invariants() {
+        let triangles = [
+            [(0.0, 0.0), (10.0, 0.0), (3.0, 7.0)],
+            [(0.0, 0.0), (10.0, 0.0), (5.0, 0.5)],
+            [(-2.5, 1.0), (7.0, 3.0), (1.0, -9.0)],
+        ];
+        for [a, b, c] in triangles {
+            let s = compute_summary(&Triangle::new(a.into(), b.into(), c.into()));
+
+            // 1/r = 1/r_a + 1/r_b + 1/r_c
+            assert!(s.excircle_residual() < 1e-12);
+            // r_a + r_b + r_c = 4R + r
+            assert!(approx_eq(
+                s.exradius_a + s.exradius_b + s.exradius_c,
+                4.0f64.mul_add(s.circumradius, s.inradius)
+            ));
+            // r_a r_b r_c = r s^2
+            assert!(approx_eq(
+                s.exradius_a * s.exradius_b * s.exradius_c,
+                s.inradius * s.semi_perimeter * s.semi_perimeter
+            ));
+
+            let excircles = [
+                (s.excenter_a, s.exradius_a, s.excircle_touch_a),
+                (s.excenter_b, s.exradius_b, s.excircle_touch_b),
+                (s.excenter_c, s.exradius_c, s.excircle_touch_c),
+            ];
+            for (center, radius, touch) in excircles {
+                for point in touch {
+                    assert!(approx_eq(center.distance_to(point), radius));
+                }
+            }
+
+            // The A-excircle touches BC at distance s - c from B.
+            assert!(approx_eq(
+                s.vertex_b.distance_to(s.excircle_touch_a[0]),
+                s.semi_perimeter - s.side_c
+            ));
+            // and the line AB at distance s from A.
+            assert!(approx_eq(
+                s.vertex_a.distance_to(s.excircle_touch_a[2]),
+                s.semi_perimeter
+            ));
+        }
+    }
 }
@@ -1,6 +1,9 @@
-use crate::errors::AppError;
-use crate::point::{to_point, Point};
+use crate::app_error::AppError;
 use std::io;
+use triale::barycentric::to_barycentric;
+use triale::point::{Point, to_point};
+use triale::tolerance::Tolerance;
+use triale::triangle::Triangle;
 
 pub fn get_input(prompt: &str) -> Result<String, io::Error> {
     print!("{prompt}");
@@ -29,3 +32,33 @@ pub fn read_point_with_retries(label: &str) -> Result<Point, AppError> {
         }
     }
 }
+
+/// Reads a point to locate relative to `tri`, either Cartesian `x, y` or
+/// barycentric `u : v : w`, whose sum must not vanish within `tolerance`.
+/// Returns `None` on a blank line or end of input.
+pub fn read_located_point(tri: &Triangle, tolerance: Tolerance) -> Result<Option<Point>, AppError> {
+    loop {
+        let input =
+            match get_input("Locate a point (x, y or barycentric u : v : w, blank to finish): ") {
+                Ok(val) if val.is_empty() => return Ok(None),
+                Ok(val) => val,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+                Err(e) => return Err(AppError::Io(e)),
+            };
+
+        if !input.contains(':') {
+            match to_point(&input) {
+                Ok(pt) => return Ok(Some(pt)),
+                Err(e) => println!("  ⚠  {e}"),
+            }
+            continue;
+        }
+        match to_barycentric(&input) {
+            Ok(bary) => match bary.to_cartesian_with(tri, tolerance) {
+                Some(pt) => return Ok(Some(pt)),
+                None => println!("  ⚠  Coordinates summing to zero describe a point at infinity"),
+            },
+            Err(e) => println!("  ⚠  {e}"),
+        }
+    }
+}
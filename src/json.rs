@@ -0,0 +1,509 @@
+//! Dependency-free JSON encoding of [`TriangleSummary`].
+//!
+//! The summary object has the following shape. Every number is a JSON number
+//! (non-finite values become `null`), points are `{"x": .., "y": ..}` and
+//! angles carry both radians and degrees. Keys are only ever added, never
+//! renamed or removed, so consumers can rely on them.
+//!
+//! ```text
+//! {
+//!   "vertices":   {"a": point, "b": point, "c": point},
+//!   "sides":      {"a": number, "b": number, "c": number},
+//!   "angles":     {"a": angle, "b": angle, "c": angle},
+//!   "perimeter": number, "semi_perimeter": number, "area": number,
+//!   "classification": {"sides": "Scalene", "angles": "Right",
+//!                      "exact_sides": "Scalene", "exact_angles": "Right"},
+//!   "centres":    {"centroid": point, "incenter": point, "circumcenter": point,
+//!                  "orthocenter": point, "nine_point_center": point},
+//!   "radii":      {"inradius": number, "circumradius": number, "nine_point_radius": number},
+//!   "excircles":  {"a": excircle, "b": excircle, "c": excircle},
+//!   "medians":    {"a": number, "b": number, "c": number},
+//!   "altitudes":  {"a": number, "b": number, "c": number},
+//!   "bisectors":  {"a": number, "b": number, "c": number},
+//!   "feet":       {"medians": abc_points, "altitudes": abc_points, "bisectors": abc_points},
+//!   "euler_line_residual": number
+//! }
+//! ```
+//!
+//! where `angle` is `{"rad": number, "deg": number}` and `excircle` is
+//! `{"center": point, "radius": number, "touch_points": {"bc": point, "ca": point, "ab": point}}`.
+//! `abc_points` is `{"a": point, "b": point, "c": point}`, keyed by the vertex
+//! each segment starts from; its foot lies on the opposite side line.
+//!
+//! The SSA solver writes a single `{"case": "two_solutions", "solutions": [..]}`
+//! object whose array holds zero, one or two summaries.
+//!
+//! `triale locate` writes JSON Lines, one object per query point:
+//! `{"point": point, "containment": "inside", "feature": "AB",
+//! "closest": point, "signed_distance": number}`, where `feature` names the
+//! edge or vertex and is only present for `edge` and `vertex`.
+//!
+//! `triale compare` writes a single object. For congruent and similar
+//! triangles it is `{"relation": "congruent", "mirrored": false,
+//! "correspondence": {"a": "E", "b": "F", "c": "D"}, "scale": number,
+//! "rotation": angle, "translation": point}`, where the correspondence names
+//! the vertex D, E or F matched with A, B and C. Dissimilar triangles give
+//! `{"relation": "dissimilar", "best_fit": fit}`, where `fit` has the same
+//! keys plus `"residuals": {"a": number, "b": number, "c": number}` and
+//! `"rms": number` for the least-squares match.
+//!
+//! `--exact` writes `{"exact": exact, "summary": {..}}`, where `exact` holds
+//! rational numbers as strings such as `"7/2"` or `"-3"`:
+//! `{"vertices": abc_points, "sides_squared": abc, "area": q,
+//! "classification": {"sides": "Isosceles", "angles": "Right"},
+//! "centres": {"centroid": point, "circumcenter": point, "orthocenter": point,
+//! "nine_point_center": point}, "circumradius_squared": q}`. `summary` is the
+//! usual `f64` summary of the rounded vertices and is absent when rounding
+//! made them degenerate.
+//!
+//! Batch mode emits JSON Lines: one `{"line": n, "summary": {..}}` object per
+//! triangle, or `{"line": n, "error": "message"}` when the line was rejected.
+
+use crate::batch::BatchItem;
+use std::fmt::Write;
+use triale::compare::{BestFit, Comparison, Correspondence};
+use triale::exact::{ExactPoint, ExactSummary};
+use triale::geometry::TriangleSummary;
+use triale::point::Point;
+use triale::primitives::Segment;
+use triale::query::PointQuery;
+use triale::rational::Rational;
+use triale::solve::SsaCase;
+
+/// Builds a single JSON object. Values are written in insertion order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonObject {
+    buf: String,
+}
+
+impl JsonObject {
+    pub fn new() -> Self {
+        Self {
+            buf: String::from("{"),
+        }
+    }
+
+    fn key(&mut self, key: &str) {
+        if self.buf.len() > 1 {
+            self.buf.push(',');
+        }
+        write_escaped(&mut self.buf, key);
+        self.buf.push(':');
+    }
+
+    #[must_use]
+    pub fn number(mut self, key: &str, value: f64) -> Self {
+        self.key(key);
+        if value.is_finite() {
+            // Debug gives the shortest representation that round-trips.
+            let _ = write!(self.buf, "{value:?}");
+        } else {
+            self.buf.push_str("null");
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn integer(mut self, key: &str, value: usize) -> Self {
+        self.key(key);
+        let _ = write!(self.buf, "{value}");
+        self
+    }
+
+    #[must_use]
+    pub fn boolean(mut self, key: &str, value: bool) -> Self {
+        self.key(key);
+        let _ = write!(self.buf, "{value}");
+        self
+    }
+
+    #[must_use]
+    pub fn string(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        write_escaped(&mut self.buf, value);
+        self
+    }
+
+    #[must_use]
+    pub fn object(mut self, key: &str, value: Self) -> Self {
+        self.key(key);
+        self.buf.push_str(&value.finish());
+        self
+    }
+
+    #[must_use]
+    pub fn array(mut self, key: &str, values: impl IntoIterator<Item = Self>) -> Self {
+        self.key(key);
+        self.buf.push('[');
+        for (idx, value) in values.into_iter().enumerate() {
+            if idx > 0 {
+                self.buf.push(',');
+            }
+            self.buf.push_str(&value.finish());
+        }
+        self.buf.push(']');
+        self
+    }
+
+    pub fn finish(mut self) -> String {
+        self.buf.push('}');
+        self.buf
+    }
+}
+
+impl Default for JsonObject {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_escaped(buf: &mut String, value: &str) {
+    buf.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            ch if ch.is_control() => {
+                let _ = write!(buf, "\\u{:04x}", u32::from(ch));
+            }
+            ch => buf.push(ch),
+        }
+    }
+    buf.push('"');
+}
+
+pub fn point_json(point: Point) -> JsonObject {
+    JsonObject::new().number("x", point.x).number("y", point.y)
+}
+
+fn angle_json(rad: f64) -> JsonObject {
+    JsonObject::new()
+        .number("rad", rad)
+        .number("deg", rad.to_degrees())
+}
+
+fn abc_json(a: f64, b: f64, c: f64) -> JsonObject {
+    JsonObject::new()
+        .number("a", a)
+        .number("b", b)
+        .number("c", c)
+}
+
+fn abc_points_json([a, b, c]: [Segment; 3]) -> JsonObject {
+    JsonObject::new()
+        .object("a", point_json(a.end))
+        .object("b", point_json(b.end))
+        .object("c", point_json(c.end))
+}
+
+fn excircle_json(center: Point, radius: f64, [on_bc, on_ca, on_ab]: [Point; 3]) -> JsonObject {
+    JsonObject::new()
+        .object("center", point_json(center))
+        .number("radius", radius)
+        .object(
+            "touch_points",
+            JsonObject::new()
+                .object("bc", point_json(on_bc))
+                .object("ca", point_json(on_ca))
+                .object("ab", point_json(on_ab)),
+        )
+}
+
+/// Encodes every field of `summary` following the schema in the module docs.
+pub fn summary_json(summary: &TriangleSummary) -> JsonObject {
+    JsonObject::new()
+        .object(
+            "vertices",
+            JsonObject::new()
+                .object("a", point_json(summary.vertex_a))
+                .object("b", point_json(summary.vertex_b))
+                .object("c", point_json(summary.vertex_c)),
+        )
+        .object(
+            "sides",
+            abc_json(summary.side_a, summary.side_b, summary.side_c),
+        )
+        .object(
+            "angles",
+            JsonObject::new()
+                .object("a", angle_json(summary.angle_a_rad))
+                .object("b", angle_json(summary.angle_b_rad))
+                .object("c", angle_json(summary.angle_c_rad)),
+        )
+        .number("perimeter", summary.perimeter)
+        .number("semi_perimeter", summary.semi_perimeter)
+        .number("area", summary.area)
+        .object(
+            "classification",
+            JsonObject::new()
+                .string("sides", &summary.side_class.to_string())
+                .string("angles", &summary.angle_class.to_string())
+                .string("exact_sides", &summary.exact_side_class.to_string())
+                .string("exact_angles", &summary.exact_angle_class.to_string()),
+        )
+        .object(
+            "centres",
+            JsonObject::new()
+                .object("centroid", point_json(summary.centroid))
+                .object("incenter", point_json(summary.incenter))
+                .object("circumcenter", point_json(summary.circumcenter))
+                .object("orthocenter", point_json(summary.orthocenter))
+                .object("nine_point_center", point_json(summary.nine_point_center)),
+        )
+        .object(
+            "radii",
+            JsonObject::new()
+                .number("inradius", summary.inradius)
+                .number("circumradius", summary.circumradius)
+                .number("nine_point_radius", summary.nine_point_radius),
+        )
+        .object(
+            "excircles",
+            JsonObject::new()
+                .object(
+                    "a",
+                    excircle_json(
+                        summary.excenter_a,
+                        summary.exradius_a,
+                        summary.excircle_touch_a,
+                    ),
+                )
+                .object(
+                    "b",
+                    excircle_json(
+                        summary.excenter_b,
+                        summary.exradius_b,
+                        summary.excircle_touch_b,
+                    ),
+                )
+                .object(
+                    "c",
+                    excircle_json(
+                        summary.excenter_c,
+                        summary.exradius_c,
+                        summary.excircle_touch_c,
+                    ),
+                ),
+        )
+        .object(
+            "medians",
+            abc_json(summary.median_a, summary.median_b, summary.median_c),
+        )
+        .object(
+            "altitudes",
+            abc_json(summary.altitude_a, summary.altitude_b, summary.altitude_c),
+        )
+        .object(
+            "bisectors",
+            abc_json(summary.bisector_a, summary.bisector_b, summary.bisector_c),
+        )
+        .object(
+            "feet",
+            JsonObject::new()
+                .object("medians", abc_points_json(summary.median_segments()))
+                .object("altitudes", abc_points_json(summary.altitude_segments()))
+                .object("bisectors", abc_points_json(summary.bisector_segments())),
+        )
+        .number("euler_line_residual", summary.euler_line_residual())
+}
+
+/// Encodes the outcome of the SSA solver, see the module docs.
+pub fn ssa_json(case: SsaCase, summaries: &[TriangleSummary]) -> JsonObject {
+    JsonObject::new()
+        .string("case", case.name())
+        .array("solutions", summaries.iter().map(summary_json))
+}
+
+/// Encodes the answer for one query point, see the module docs.
+pub fn point_query_json(point: Point, query: &PointQuery) -> JsonObject {
+    let json = JsonObject::new()
+        .object("point", point_json(point))
+        .string("containment", query.containment.name());
+    let json = match query.containment.feature() {
+        Some(feature) => json.string("feature", &feature),
+        None => json,
+    };
+    json.object("closest", point_json(query.closest))
+        .number("signed_distance", query.signed_distance)
+}
+
+fn correspondence_json(json: JsonObject, found: &Correspondence) -> JsonObject {
+    let [a, b, c] = found.labels();
+    json.boolean("mirrored", found.mirrored)
+        .object(
+            "correspondence",
+            JsonObject::new()
+                .string("a", a)
+                .string("b", b)
+                .string("c", c),
+        )
+        .number("scale", found.scale)
+        .object("rotation", angle_json(found.rotation))
+        .object("translation", point_json(found.translation))
+}
+
+/// Encodes the outcome of `triale compare`, see the module docs.
+pub fn comparison_json(comparison: &Comparison, best_fit: Option<&BestFit>) -> JsonObject {
+    let json = JsonObject::new().string("relation", comparison.relation.name());
+    if let Some(found) = &comparison.correspondence {
+        return correspondence_json(json, found);
+    }
+    let Some(fit) = best_fit else {
+        return json;
+    };
+    let [a, b, c] = fit.residuals;
+    let fit_json = correspondence_json(JsonObject::new(), &fit.correspondence)
+        .object("residuals", abc_json(a, b, c))
+        .number("rms", fit.rms);
+    json.object("best_fit", fit_json)
+}
+
+fn exact_point_json(point: &ExactPoint) -> JsonObject {
+    JsonObject::new()
+        .string("x", &point.x.to_string())
+        .string("y", &point.y.to_string())
+}
+
+fn exact_abc_json(a: &Rational, b: &Rational, c: &Rational) -> JsonObject {
+    JsonObject::new()
+        .string("a", &a.to_string())
+        .string("b", &b.to_string())
+        .string("c", &c.to_string())
+}
+
+/// Encodes the output of `--exact`, see the module docs.
+pub fn exact_json(exact: &ExactSummary, summary: Option<&TriangleSummary>) -> JsonObject {
+    let vertices = &exact.triangle;
+    let exact_json = JsonObject::new()
+        .object(
+            "vertices",
+            JsonObject::new()
+                .object("a", exact_point_json(&vertices.a))
+                .object("b", exact_point_json(&vertices.b))
+                .object("c", exact_point_json(&vertices.c)),
+        )
+        .object(
+            "sides_squared",
+            exact_abc_json(&exact.side_sq_a, &exact.side_sq_b, &exact.side_sq_c),
+        )
+        .string("area", &exact.area.to_string())
+        .object(
+            "classification",
+            JsonObject::new()
+                .string("sides", &exact.side_class.to_string())
+                .string("angles", &exact.angle_class.to_string()),
+        )
+        .object(
+            "centres",
+            JsonObject::new()
+                .object("centroid", exact_point_json(&exact.centroid))
+                .object("circumcenter", exact_point_json(&exact.circumcenter))
+                .object("orthocenter", exact_point_json(&exact.orthocenter))
+                .object(
+                    "nine_point_center",
+                    exact_point_json(&exact.nine_point_center),
+                ),
+        )
+        .string("circumradius_squared", &exact.circumradius_sq.to_string());
+    let json = JsonObject::new().object("exact", exact_json);
+    match summary {
+        Some(summary) => json.object("summary", summary_json(summary)),
+        None => json,
+    }
+}
+
+/// Encodes one batch row, see the module docs.
+pub fn batch_item_json(item: &BatchItem) -> JsonObject {
+    let row = JsonObject::new().integer("line", item.line);
+    match &item.outcome {
+        Ok(summary) => row.object("summary", summary_json(summary)),
+        Err(err) => row.string("error", &err.to_string()),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use triale::exact::{ExactTriangle, compute_exact_summary, to_exact_point};
+    use triale::geometry::compute_summary;
+    use triale::triangle::Triangle;
+
+    #[test]
+    fn test_number_encoding() {
+        let json = JsonObject::new()
+            .number("whole", 6.0)
+            .number("tiny", 1e-7)
+            .number("nan", f64::NAN)
+            .number("inf", f64::INFINITY)
+            .finish();
+        assert_eq!(json, r#"{"whole":6.0,"tiny":1e-7,"nan":null,"inf":null}"#);
+    }
+
+    #[test]
+    fn test_string_escaping() {
+        let json = JsonObject::new()
+            .string("msg", "a \"quoted\"\\path\n\u{1}")
+            .finish();
+        assert_eq!(json, r#"{"msg":"a \"quoted\"\\path\n\u0001"}"#);
+    }
+
+    #[test]
+    fn test_array_encoding() {
+        let json = JsonObject::new()
+            .array("empty", [])
+            .array("pair", [point_json(Point::zero()), JsonObject::new()])
+            .finish();
+        assert_eq!(json, r#"{"empty":[],"pair":[{"x":0.0,"y":0.0},{}]}"#);
+    }
+
+    #[test]
+    fn test_summary_contains_every_section() {
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        );
+        let json = summary_json(&compute_summary(&tri)).finish();
+
+        assert!(json.starts_with(r#"{"vertices":{"a":{"x":0.0,"y":0.0}"#));
+        assert!(json.contains(r#""sides":{"a":5.0,"b":3.0,"c":4.0}"#));
+        assert!(json.contains(r#""classification":{"sides":"Scalene","angles":"Right","exact_sides":"Scalene","exact_angles":"Right"}"#));
+        assert!(json.contains(r#""deg":90.0"#));
+        for key in [
+            "angles",
+            "perimeter",
+            "semi_perimeter",
+            "area",
+            "centres",
+            "nine_point_center",
+            "radii",
+            "excircles",
+            "touch_points",
+            "medians",
+            "altitudes",
+            "bisectors",
+            "feet",
+            "euler_line_residual",
+        ] {
+            assert!(json.contains(&format!("\"{key}\":")), "missing {key}");
+        }
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+    }
+
+    #[test]
+    fn test_exact_values_are_strings() {
+        let points = ["0,0", "1/2,0", "0,1/3"].map(|point| to_exact_point(point).unwrap());
+        let exact = compute_exact_summary(&ExactTriangle::build(points).unwrap()).unwrap();
+        let json = exact_json(&exact, None).finish();
+
+        assert!(json.starts_with(r#"{"exact":{"vertices":{"a":{"x":"0","y":"0"}"#));
+        assert!(json.contains(r#""sides_squared":{"a":"13/36","b":"1/9","c":"1/4"}"#));
+        assert!(json.contains(r#""area":"1/12""#));
+        assert!(json.contains(r#""circumcenter":{"x":"1/4","y":"1/6"}"#));
+        assert!(!json.contains("summary"));
+    }
+}
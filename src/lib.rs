@@ -0,0 +1,136 @@
+//! Triangle geometry: construction and validation, side and angle measures,
+//! classical centres, derived triangles, solving from measurements, point
+//! location, affine maps and similarity comparison.
+//!
+//! The `triale` binary is a thin front-end over this crate. Everything it
+//! prints is computed here, so other programs can depend on the same types.
+//!
+//! ```
+//! use triale::{Point, build_triangle, compute_summary};
+//!
+//! let triangle = build_triangle([
+//!     Point::new(0.0, 0.0),
+//!     Point::new(4.0, 0.0),
+//!     Point::new(0.0, 3.0),
+//! ])?;
+//! let summary = compute_summary(&triangle);
+//! assert!((summary.area - 6.0).abs() < 1e-12);
+//! assert!((summary.side_a - 5.0).abs() < 1e-12);
+//! # Ok::<(), triale::Error>(())
+//! ```
+//!
+//! [`Point`], [`Triangle`] and the [`geometry`] functions are generic over the
+//! coordinate type, which defaults to `f64`; see [`scalar`] for `f32` and
+//! exact rationals.
+//!
+//! Error enums are `#[non_exhaustive]` so new failure modes can be added in a
+//! minor release, and so are the summary and result structs such as
+//! [`TriangleSummary`], so they can gain fields. Read their fields freely but
+//! obtain them from the functions that compute them.
+
+// baseline lints, general purpose
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+#![warn(clippy::nursery)]
+#![warn(clippy::cargo)]
+// compatibility lints, will switch to deny in first major release
+#![warn(warnings)]
+#![deny(rust_2018_idioms)]
+#![deny(rust_2021_compatibility)]
+#![deny(rust_2024_compatibility)]
+// code quality lints
+// public names such as `triangle::TriangleCreateError` predate the library
+#![allow(clippy::module_name_repetitions)]
+#![warn(clippy::missing_errors_doc)]
+#![warn(clippy::missing_panics_doc)]
+#![warn(clippy::must_use_candidate)]
+#![warn(clippy::return_self_not_must_use)]
+#![warn(clippy::wildcard_imports)]
+#![warn(clippy::single_match_else)]
+#![allow(clippy::similar_names)]
+#![warn(clippy::struct_excessive_bools)]
+#![warn(clippy::too_many_lines)]
+#![warn(clippy::too_many_arguments)]
+#![warn(clippy::type_repetition_in_bounds)]
+#![warn(clippy::doc_markdown)]
+#![warn(clippy::match_bool)]
+#![warn(clippy::match_same_arms)]
+#![warn(clippy::unnested_or_patterns)]
+// denies, mainly just ensure no panics possible
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(clippy::todo)]
+#![deny(clippy::unimplemented)]
+
+pub mod barycentric;
+// The integers behind `rational`; reachable, but not part of the stable API.
+#[doc(hidden)]
+pub mod bigint;
+pub mod centers;
+pub mod compare;
+pub mod derived;
+pub mod errors;
+pub mod exact;
+pub mod geometry;
+pub mod point;
+// The exact sign tests behind `scalar`; reachable, but not part of the
+// stable API.
+#[doc(hidden)]
+pub mod predicates;
+pub mod primitives;
+pub mod query;
+pub mod rational;
+pub mod scalar;
+pub mod solve;
+pub mod tolerance;
+pub mod transform;
+pub mod triangle;
+
+pub use crate::errors::Error;
+pub use crate::geometry::{TriangleSummary, compute_summary, compute_summary_with};
+pub use crate::point::Point;
+pub use crate::scalar::{Real, Scalar};
+pub use crate::tolerance::Tolerance;
+pub use crate::triangle::{Triangle, build_triangle, build_triangle_with};
+
+/// Loose comparisons and fixtures shared by the unit tests; the library
+/// itself compares through [`Tolerance`].
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::point::Point;
+    use crate::triangle::Triangle;
+
+    #[must_use]
+    pub fn approx_eq(a: f64, b: f64) -> bool {
+        let abs_tol = 1e-9;
+        let rel_tol = 1e-9;
+        let diff = (a - b).abs();
+        diff <= abs_tol || diff <= rel_tol * a.abs().max(b.abs())
+    }
+
+    #[must_use]
+    pub fn approx_eq_pt(p1: Point, p2: Point) -> bool {
+        approx_eq(p1.x, p2.x) && approx_eq(p1.y, p2.y)
+    }
+
+    /// Acute, with no two sides or angles alike.
+    #[must_use]
+    pub const fn scalene() -> Triangle {
+        Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(3.0, 7.0),
+        )
+    }
+
+    /// The 3-4-5 triangle, right-angled at A.
+    #[must_use]
+    pub const fn right_triangle() -> Triangle {
+        Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        )
+    }
+}
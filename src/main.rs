@@ -31,35 +31,324 @@
 #![deny(clippy::panic)]
 #![deny(clippy::todo)]
 #![deny(clippy::unimplemented)]
-#![allow(dead_code)]
 
-mod errors;
-mod geometry;
+mod app_error;
+mod batch;
+mod cli;
 mod io;
-mod point;
-mod triangle;
+mod json;
+mod output;
+mod plot;
+mod svg;
+mod table;
 
-use crate::errors::AppError;
-use crate::geometry::compute_summary;
-use crate::io::read_point_with_retries;
-use crate::point::Point;
-use crate::triangle::build_triangle;
+use crate::app_error::AppError;
+use crate::batch::{BatchSource, process_lines};
+use crate::cli::{Command, Options, USAGE, parse_args};
+use crate::io::{read_located_point, read_point_with_retries};
+use crate::json::ssa_json;
+use crate::output::{OutputFormat, SummaryWriter};
+use crate::plot::{render_plot, terminal_columns};
+use crate::svg::render_svg;
+use crate::table::{batch_fields, default_fields};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use triale::barycentric::{Barycentric, Trilinear};
+use triale::derived::{DerivedKind, ceva_product, cevians_concurrent_with, derived_triangle_with};
+use triale::exact::{ExactPoint, ExactTriangle, compute_exact_summary};
+use triale::geometry::compute_summary_with;
+use triale::point::Point;
+use triale::query::query_point_with;
+use triale::solve::SsaSolution;
+use triale::tolerance::Tolerance;
+use triale::triangle::{Triangle, build_triangle_with};
 
 const POINT_LABELS: [&str; 3] = ["A", "B", "C"];
 
-fn main() -> Result<(), AppError> {
+fn read_points_interactively() -> Result<[Point; 3], AppError> {
     let mut points: [Point; 3] = [Point::zero(); 3];
 
     for (idx, point_slot) in points.iter_mut().enumerate() {
         let label = POINT_LABELS.get(idx).unwrap_or(&"?");
         *point_slot = read_point_with_retries(label)?;
     }
+    Ok(points)
+}
+
+fn summary_writer(options: &Options, batch: bool) -> SummaryWriter<impl Write> {
+    let fields = match (options.fields.clone(), batch) {
+        (Some(fields), true) => batch_fields(fields),
+        (Some(fields), false) => fields,
+        (None, _) => default_fields(batch),
+    };
+    SummaryWriter::new(
+        BufWriter::new(std::io::stdout().lock()),
+        options.format,
+        fields,
+    )
+}
+
+/// Applies `--transform` to an input triangle, rejecting maps that flatten it.
+fn transformed(triangle: Triangle, options: &Options) -> Result<Triangle, AppError> {
+    match options.transform {
+        Some(transform) => {
+            let Triangle { a, b, c } = transform.apply_triangle(&triangle);
+            Ok(build_triangle_with([a, b, c], options.tolerance)?)
+        }
+        None => Ok(triangle),
+    }
+}
+
+/// Swaps in the triangle requested with `--derived`, if any.
+fn selected_triangle(triangle: &Triangle, options: &Options) -> Result<Triangle, AppError> {
+    match options.derived {
+        Some(kind) => Ok(derived_triangle_with(triangle, kind, options.tolerance)?),
+        None => Ok(*triangle),
+    }
+}
+
+fn summarize(triangle: &Triangle, options: &Options) -> Result<(), AppError> {
+    let input = triangle;
+    let triangle = &selected_triangle(input, options)?;
+    let summary = compute_summary_with(triangle, options.tolerance);
+
+    if let Some(path) = &options.svg {
+        std::fs::write(path, render_svg(&summary, options.svg_layers))?;
+    }
+
+    let mut writer = summary_writer(options, false);
+    if let (Some(kind), OutputFormat::Text) = (options.derived, options.format) {
+        let of_point = kind
+            .point()
+            .map(|p| format!(" for P = {p}"))
+            .unwrap_or_default();
+        writer.write_raw(&format!(
+            "Summarizing the {kind} triangle{of_point} of {input}\n"
+        ))?;
+        if let DerivedKind::Cevian(_) = kind {
+            let product = ceva_product(input, triangle);
+            let check = if cevians_concurrent_with(input, triangle, options.tolerance) {
+                "✓"
+            } else {
+                "✗"
+            };
+            writer.write_raw(&format!(
+                "Ceva: (BA'/A'C)(CB'/B'A)(AC'/C'B) = {product:.6} {check}\n"
+            ))?;
+        }
+    }
+    if options.plot {
+        let columns = options.plot_columns.unwrap_or_else(terminal_columns);
+        writer.write_raw(&render_plot(&summary, columns))?;
+    }
+    writer.write_summary(triangle, &summary)?;
+    writer.finish()?;
+    Ok(())
+}
 
-    let triangle = build_triangle(points)?;
-    println!("Successfully created triangle: {triangle}");
+/// Prints the exact quantities of a rational triangle, followed by the `f64`
+/// summary of its rounded vertices for everything that needs square roots.
+fn summarize_exact(points: [ExactPoint; 3], options: &Options) -> Result<(), AppError> {
+    let exact = ExactTriangle::build(points)?;
+    let summary = compute_exact_summary(&exact)?;
+    let Triangle { a, b, c } = exact.to_f64();
+    let rounded = match build_triangle_with([a, b, c], options.tolerance) {
+        Ok(triangle) => Some((triangle, compute_summary_with(&triangle, options.tolerance))),
+        Err(err) => {
+            eprintln!("Skipping the floating-point summary: {err}");
+            None
+        }
+    };
 
-    let summary = compute_summary(&triangle);
-    println!("{summary}");
+    if let (Some(path), Some((_, rounded))) = (&options.svg, &rounded) {
+        std::fs::write(path, render_svg(rounded, options.svg_layers))?;
+    }
 
+    let mut writer = summary_writer(options, false);
+    if let (true, Some((_, rounded))) = (options.plot, &rounded) {
+        let columns = options.plot_columns.unwrap_or_else(terminal_columns);
+        writer.write_raw(&render_plot(rounded, columns))?;
+    }
+    writer.write_exact_summary(
+        &summary,
+        rounded
+            .as_ref()
+            .map(|(triangle, rounded)| (triangle, rounded)),
+    )?;
+    writer.finish()?;
     Ok(())
 }
+
+/// Prompts for points until a blank line and prints where each lies relative
+/// to `triangle`.
+fn locate_points_interactively(triangle: &Triangle, tolerance: Tolerance) -> Result<(), AppError> {
+    while let Some(point) = read_located_point(triangle, tolerance)? {
+        println!("  Cartesian:   {point}");
+        if let Some(bary) = Barycentric::from_cartesian(point, triangle) {
+            println!("  Barycentric: {bary}");
+        }
+        if let Some(trilinear) = Trilinear::from_cartesian(point, triangle) {
+            println!("  Trilinear:   {trilinear}");
+        }
+    }
+    Ok(())
+}
+
+fn locate(vertices: [Point; 3], points: &[Point], options: &Options) -> Result<(), AppError> {
+    let triangle = build_triangle_with(vertices, options.tolerance)?;
+    let mut writer = summary_writer(options, false);
+    for (idx, point) in points.iter().enumerate() {
+        let query = query_point_with(*point, &triangle, options.tolerance);
+        writer.write_point_query(idx + 1, *point, &query)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+fn compare(first: [Point; 3], second: [Point; 3], options: &Options) -> Result<(), AppError> {
+    let (first, second) = (
+        build_triangle_with(first, options.tolerance)?,
+        build_triangle_with(second, options.tolerance)?,
+    );
+    let comparison = first.compare_with(&second, options.tolerance);
+    let best_fit = first.best_fit_with(&second, true, options.tolerance);
+    let mut writer = summary_writer(options, false);
+    writer.write_comparison(&comparison, best_fit.as_ref())?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// `drawing.svg` becomes `drawing-2.svg` for the second of several solutions.
+fn numbered_path(path: &Path, number: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut name = format!("{stem}-{number}");
+    if let Some(ext) = path.extension() {
+        name.push('.');
+        name.push_str(&ext.to_string_lossy());
+    }
+    path.with_file_name(name)
+}
+
+fn summarize_ssa(solution: &SsaSolution, options: &Options) -> Result<(), AppError> {
+    let triangles = solution
+        .triangles
+        .iter()
+        .map(|triangle| selected_triangle(&transformed(*triangle, options)?, options))
+        .collect::<Result<Vec<_>, _>>()?;
+    let summaries: Vec<_> = triangles
+        .iter()
+        .map(|triangle| compute_summary_with(triangle, options.tolerance))
+        .collect();
+    let count = summaries.len();
+
+    if let Some(path) = &options.svg {
+        for (idx, summary) in summaries.iter().enumerate() {
+            let target = if count > 1 {
+                numbered_path(path, idx + 1)
+            } else {
+                path.clone()
+            };
+            std::fs::write(target, render_svg(summary, options.svg_layers))?;
+        }
+    }
+
+    let mut writer = summary_writer(options, true);
+    match options.format {
+        OutputFormat::Json => {
+            let json = ssa_json(solution.case, &summaries).finish();
+            writer.write_raw(&format!("{json}\n"))?;
+        }
+        OutputFormat::Text => {
+            writer.write_raw(&format!("SSA case: {}\n", solution.case))?;
+            for (idx, (triangle, summary)) in triangles.iter().zip(&summaries).enumerate() {
+                writer.write_raw(&format!("\nSolution {} of {count}\n", idx + 1))?;
+                if options.plot {
+                    let columns = options.plot_columns.unwrap_or_else(terminal_columns);
+                    writer.write_raw(&render_plot(summary, columns))?;
+                }
+                writer.write_summary(triangle, summary)?;
+            }
+        }
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            eprintln!("SSA case: {}", solution.case);
+            for (idx, (triangle, summary)) in triangles.iter().zip(&summaries).enumerate() {
+                writer.write_solution(idx + 1, triangle, summary)?;
+            }
+        }
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Returns `true` when every line produced a summary.
+fn run_batch(source: &BatchSource, options: &Options) -> Result<bool, AppError> {
+    let reader: Box<dyn BufRead> = match source {
+        BatchSource::Stdin => Box::new(std::io::stdin().lock()),
+        BatchSource::File(path) => Box::new(BufReader::new(File::open(path)?)),
+    };
+    let mut writer = summary_writer(options, true);
+
+    let report = process_lines(reader, options.tolerance, |item| {
+        writer.write_batch_item(item)
+    })?;
+    writer.finish()?;
+
+    if report.failed > 0 {
+        eprintln!(
+            "{} of {} triangles could not be processed",
+            report.failed, report.total
+        );
+    }
+    Ok(report.failed == 0)
+}
+
+fn run() -> Result<bool, AppError> {
+    let cli = parse_args(std::env::args().skip(1))?;
+    match cli.command {
+        Command::Help => print!("{USAGE}"),
+        Command::Version => println!("triale {}", env!("CARGO_PKG_VERSION")),
+        Command::Interactive => {
+            let triangle =
+                build_triangle_with(read_points_interactively()?, cli.options.tolerance)?;
+            let triangle = transformed(triangle, &cli.options)?;
+            summarize(&triangle, &cli.options)?;
+            if cli.options.format == OutputFormat::Text {
+                locate_points_interactively(&triangle, cli.options.tolerance)?;
+            }
+        }
+        Command::Summarize(points) => {
+            let triangle = build_triangle_with(points, cli.options.tolerance)?;
+            let triangle = transformed(triangle, &cli.options)?;
+            summarize(&triangle, &cli.options)?;
+        }
+        Command::SummarizeExact(points) => summarize_exact(*points, &cli.options)?,
+        Command::Solve(input) => {
+            let triangle = transformed(input.solve_with(cli.options.tolerance)?, &cli.options)?;
+            summarize(&triangle, &cli.options)?;
+        }
+        Command::SolveSsa(input) => {
+            summarize_ssa(&input.solve_with(cli.options.tolerance)?, &cli.options)?;
+        }
+        Command::Locate { vertices, points } => locate(vertices, &points, &cli.options)?,
+        Command::Compare { first, second } => compare(first, second, &cli.options)?,
+        Command::Batch(source) => return run_batch(&source, &cli.options),
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{err}");
+            if matches!(err, AppError::Cli(_)) {
+                eprintln!("Run 'triale --help' for usage.");
+                return ExitCode::from(2);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use crate::batch::{BatchItem, write_text_row};
+use crate::json::{batch_item_json, comparison_json, exact_json, point_query_json, summary_json};
+use crate::table::{Field, write_cells, write_header, write_row};
+use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
+use triale::compare::{BestFit, Comparison};
+use triale::exact::ExactSummary;
+use triale::geometry::TriangleSummary;
+use triale::point::Point;
+use triale::query::PointQuery;
+use triale::triangle::Triangle;
+
+/// How results are written to stdout.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Tsv,
+}
+
+impl OutputFormat {
+    pub const NAMES: &'static str = "text, json, csv, tsv";
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            "tsv" => Some(Self::Tsv),
+            _ => None,
+        }
+    }
+
+    /// Column separator for tabular formats.
+    pub const fn delimiter(self) -> Option<char> {
+        match self {
+            Self::Csv => Some(','),
+            Self::Tsv => Some('\t'),
+            Self::Text | Self::Json => None,
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Json => write!(f, "json"),
+            Self::Csv => write!(f, "csv"),
+            Self::Tsv => write!(f, "tsv"),
+        }
+    }
+}
+
+/// Columns of the `triale compare` table.
+const COMPARISON_COLUMNS: [&str; 10] = [
+    "relation",
+    "mirrored",
+    "a",
+    "b",
+    "c",
+    "scale",
+    "rotation_deg",
+    "translation_x",
+    "translation_y",
+    "rms_residual",
+];
+
+/// Columns of the `triale locate` table.
+const POINT_QUERY_COLUMNS: [&str; 8] = [
+    "point",
+    "x",
+    "y",
+    "containment",
+    "feature",
+    "closest_x",
+    "closest_y",
+    "signed_distance",
+];
+
+/// Writes results in one [`OutputFormat`]. Tabular formats get exactly one
+/// header row, however many triangles are written.
+#[derive(Debug)]
+pub struct SummaryWriter<W: Write> {
+    writer: W,
+    format: OutputFormat,
+    fields: Vec<Field>,
+    header_written: bool,
+}
+
+impl<W: Write> SummaryWriter<W> {
+    /// `fields` selects the columns of tabular formats and is ignored otherwise.
+    pub const fn new(writer: W, format: OutputFormat, fields: Vec<Field>) -> Self {
+        Self {
+            writer,
+            format,
+            fields,
+            header_written: false,
+        }
+    }
+
+    fn ensure_header(&mut self, delimiter: char) -> Result<(), io::Error> {
+        if !self.header_written {
+            write_header(&mut self.writer, &self.fields, delimiter)?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+
+    /// Writes preformatted text such as a plot, untouched.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn write_raw(&mut self, text: &str) -> Result<(), io::Error> {
+        self.writer.write_all(text.as_bytes())
+    }
+
+    /// Writes the result for a single triangle.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn write_summary(
+        &mut self,
+        triangle: &Triangle,
+        summary: &TriangleSummary,
+    ) -> Result<(), io::Error> {
+        self.write_solution(1, triangle, summary)
+    }
+
+    /// Writes one of several triangles solving the same input. Tabular formats
+    /// put `number` in the `line` column.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn write_solution(
+        &mut self,
+        number: usize,
+        triangle: &Triangle,
+        summary: &TriangleSummary,
+    ) -> Result<(), io::Error> {
+        match self.format {
+            OutputFormat::Text => {
+                writeln!(self.writer, "Successfully created triangle: {triangle}")?;
+                writeln!(self.writer, "{summary}")
+            }
+            OutputFormat::Json => writeln!(self.writer, "{}", summary_json(summary).finish()),
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let delimiter = self.format.delimiter().unwrap_or(',');
+                self.ensure_header(delimiter)?;
+                write_row(
+                    &mut self.writer,
+                    &self.fields,
+                    delimiter,
+                    number,
+                    Ok(summary),
+                )
+            }
+        }
+    }
+
+    /// Writes the row for one batch line.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn write_batch_item(&mut self, item: &BatchItem) -> Result<(), io::Error> {
+        match self.format {
+            OutputFormat::Text => write_text_row(&mut self.writer, item),
+            OutputFormat::Json => writeln!(self.writer, "{}", batch_item_json(item).finish()),
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let delimiter = self.format.delimiter().unwrap_or(',');
+                self.ensure_header(delimiter)?;
+                write_row(
+                    &mut self.writer,
+                    &self.fields,
+                    delimiter,
+                    item.line,
+                    item.outcome.as_ref(),
+                )
+            }
+        }
+    }
+
+    /// Writes the answer for the `number`th query point of `triale locate`.
+    /// Tabular formats use their own fixed columns rather than `fields`.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn write_point_query(
+        &mut self,
+        number: usize,
+        point: Point,
+        query: &PointQuery,
+    ) -> Result<(), io::Error> {
+        match self.format {
+            OutputFormat::Text => writeln!(
+                self.writer,
+                "P{number} {point}: {}, closest point {}, signed distance {}",
+                query.containment, query.closest, query.signed_distance
+            ),
+            OutputFormat::Json => {
+                writeln!(self.writer, "{}", point_query_json(point, query).finish())
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let delimiter = self.format.delimiter().unwrap_or(',');
+                if !self.header_written {
+                    write_cells(
+                        &mut self.writer,
+                        delimiter,
+                        POINT_QUERY_COLUMNS.map(String::from),
+                    )?;
+                    self.header_written = true;
+                }
+                let cells = [
+                    number.to_string(),
+                    format!("{:?}", point.x),
+                    format!("{:?}", point.y),
+                    query.containment.name().to_string(),
+                    query.containment.feature().unwrap_or_default(),
+                    format!("{:?}", query.closest.x),
+                    format!("{:?}", query.closest.y),
+                    format!("{:?}", query.signed_distance),
+                ];
+                write_cells(&mut self.writer, delimiter, cells)
+            }
+        }
+    }
+
+    /// Writes the outcome of `triale compare`. For dissimilar triangles the
+    /// least-squares `best_fit` stands in for the exact correspondence.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn write_comparison(
+        &mut self,
+        comparison: &Comparison,
+        best_fit: Option<&BestFit>,
+    ) -> Result<(), io::Error> {
+        let found = comparison
+            .correspondence
+            .or_else(|| best_fit.map(|fit| fit.correspondence));
+        match self.format {
+            OutputFormat::Text => {
+                writeln!(self.writer, "Relation: {}", comparison.relation)?;
+                let Some(found) = found else {
+                    return Ok(());
+                };
+                if let (None, Some(fit)) = (comparison.correspondence, best_fit) {
+                    writeln!(self.writer, "Best fit: RMS residual {}", fit.rms)?;
+                }
+                if found.mirrored {
+                    writeln!(self.writer, "Mirror images: yes")?;
+                }
+                writeln!(self.writer, "Correspondence: {found}")?;
+                writeln!(self.writer, "Scale: {}", found.scale)?;
+                writeln!(self.writer, "Rotation: {}°", found.rotation.to_degrees())?;
+                writeln!(self.writer, "Translation: {}", found.translation)
+            }
+            OutputFormat::Json => {
+                let json = comparison_json(comparison, best_fit).finish();
+                writeln!(self.writer, "{json}")
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let delimiter = self.format.delimiter().unwrap_or(',');
+                write_cells(
+                    &mut self.writer,
+                    delimiter,
+                    COMPARISON_COLUMNS.map(String::from),
+                )?;
+                self.header_written = true;
+                let mut cells = vec![comparison.relation.name().to_string()];
+                if let Some(found) = found {
+                    cells.push(found.mirrored.to_string());
+                    cells.extend(found.labels().map(String::from));
+                    cells.extend(
+                        [
+                            found.scale,
+                            found.rotation.to_degrees(),
+                            found.translation.x,
+                            found.translation.y,
+                        ]
+                        .map(|value| format!("{value:?}")),
+                    );
+                }
+                cells.resize(COMPARISON_COLUMNS.len() - 1, String::new());
+                cells.push(best_fit.map_or_else(String::new, |fit| format!("{:?}", fit.rms)));
+                write_cells(&mut self.writer, delimiter, cells)
+            }
+        }
+    }
+
+    /// Writes the result of `--exact`: the exact quantities, then the `f64`
+    /// summary of the rounded triangle when there is one. Tabular formats
+    /// are rejected on the command line.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn write_exact_summary(
+        &mut self,
+        exact: &ExactSummary,
+        rounded: Option<(&Triangle, &TriangleSummary)>,
+    ) -> Result<(), io::Error> {
+        match self.format {
+            OutputFormat::Json => {
+                let json = exact_json(exact, rounded.map(|(_, summary)| summary)).finish();
+                writeln!(self.writer, "{json}")
+            }
+            OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv => {
+                writeln!(self.writer, "{exact}")?;
+                match rounded {
+                    Some((triangle, summary)) => self.write_summary(triangle, summary),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    /// Writes a header for tables that received no rows, then flushes.
+    ///
+    /// # Errors
+    /// Returns any error raised by the underlying writer.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        if let Some(delimiter) = self.format.delimiter() {
+            self.ensure_header(delimiter)?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt::Write;
+use triale::geometry::TriangleSummary;
+use triale::point::Point;
+
+/// Width used when the terminal size is unknown.
+pub const DEFAULT_COLUMNS: usize = 80;
+const MIN_COLUMNS: usize = 10;
+
+/// Unicode Braille patterns pack a 2x4 grid of dots into one character.
+const BRAILLE_BASE: u32 = 0x2800;
+const BRAILLE_BITS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// Terminal width from `$COLUMNS`, which shells export for interactive sessions.
+pub fn terminal_columns() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|cols| cols.trim().parse::<usize>().ok())
+        .filter(|&cols| cols > 0)
+        .unwrap_or(DEFAULT_COLUMNS)
+}
+
+/// A character grid where every cell is either a Braille pattern or a label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BrailleCanvas {
+    cols: usize,
+    rows: usize,
+    dots: Vec<u8>,
+    labels: Vec<Option<char>>,
+}
+
+impl BrailleCanvas {
+    fn new(cols: usize, rows: usize) -> Self {
+        Self {
+            cols,
+            rows,
+            dots: vec![0; cols * rows],
+            labels: vec![None; cols * rows],
+        }
+    }
+
+    const fn dot_width(&self) -> usize {
+        self.cols * 2
+    }
+
+    const fn dot_height(&self) -> usize {
+        self.rows * 4
+    }
+
+    /// Sets the dot at (`x`, `y`), measured from the top-left corner.
+    fn set(&mut self, x: usize, y: usize) {
+        if x < self.dot_width() && y < self.dot_height() {
+            self.dots[(y / 4) * self.cols + x / 2] |= BRAILLE_BITS[x % 2][y % 4];
+        }
+    }
+
+    /// Rasterizes a segment by sampling it at least once per dot.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn line(&mut self, from: Point, to: Point) {
+        let steps = (to - from).x.abs().max((to - from).y.abs()).ceil().max(1.0);
+        for step in 0..=steps as usize {
+            let point = from + (to - from) * (step as f64 / steps);
+            if point.x >= 0.0 && point.y >= 0.0 {
+                self.set(point.x.round() as usize, point.y.round() as usize);
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn label(&mut self, at: Point, label: char) {
+        if at.x < 0.0 || at.y < 0.0 {
+            return;
+        }
+        let (col, row) = (at.x.round() as usize / 2, at.y.round() as usize / 4);
+        if col < self.cols && row < self.rows {
+            self.labels[row * self.cols + col] = Some(label);
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::with_capacity(self.cols * self.rows * 3);
+        for row in 0..self.rows {
+            let start = row * self.cols;
+            let cells = self.dots[start..start + self.cols]
+                .iter()
+                .zip(&self.labels[start..start + self.cols]);
+            let line: String = cells
+                .map(|(&bits, label)| {
+                    label.unwrap_or_else(|| {
+                        char::from_u32(BRAILLE_BASE + u32::from(bits)).unwrap_or(' ')
+                    })
+                })
+                .collect();
+            out.push_str(line.trim_end_matches('\u{2800}'));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Maps triangle coordinates onto dot coordinates with y pointing down.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct DotMapping {
+    min: Point,
+    max_y: f64,
+    scale: f64,
+}
+
+impl DotMapping {
+    fn map(&self, point: Point) -> Point {
+        Point::new(
+            (point.x - self.min.x) * self.scale,
+            (self.max_y - point.y) * self.scale,
+        )
+    }
+}
+
+/// Draws the triangle edges in Braille and marks the vertices and notable
+/// centres with letters. The plot is `columns` characters wide and keeps the
+/// triangle's proportions; centres outside the triangle's bounding box are
+/// listed in the legend instead of stretching the plot.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn render_plot(summary: &TriangleSummary, columns: usize) -> String {
+    let (a, b, c) = (summary.vertex_a, summary.vertex_b, summary.vertex_c);
+    let min = Point::new(a.x.min(b.x).min(c.x), a.y.min(b.y).min(c.y));
+    let max = Point::new(a.x.max(b.x).max(c.x), a.y.max(b.y).max(c.y));
+    let extent = max - min;
+
+    let cols = columns.max(MIN_COLUMNS);
+    // Braille dots are roughly square, so the aspect ratio carries over as is.
+    let max_rows = cols / 2;
+    let dot_width = (cols * 2 - 1) as f64;
+    let wanted_height = dot_width * extent.y / extent.x;
+    let rows = ((wanted_height / 4.0).ceil() as usize).clamp(1, max_rows) + 1;
+    let dot_height = (rows * 4 - 1) as f64;
+    let scale = (dot_width / extent.x).min(dot_height / extent.y);
+
+    let mapping = DotMapping {
+        min,
+        max_y: max.y,
+        scale,
+    };
+    let mut canvas = BrailleCanvas::new(cols, rows);
+    canvas.line(mapping.map(a), mapping.map(b));
+    canvas.line(mapping.map(b), mapping.map(c));
+    canvas.line(mapping.map(c), mapping.map(a));
+
+    let centres = [
+        ('G', "centroid", summary.centroid),
+        ('I', "incenter", summary.incenter),
+        ('O', "circumcenter", summary.circumcenter),
+        ('H', "orthocenter", summary.orthocenter),
+        ('N', "nine-point center", summary.nine_point_center),
+    ];
+    let in_bounds = |p: Point| (min.x..=max.x).contains(&p.x) && (min.y..=max.y).contains(&p.y);
+
+    let mut legend = String::from("A B C vertices");
+    let mut off_plot = Vec::new();
+    for (label, name, point) in centres {
+        if in_bounds(point) {
+            canvas.label(mapping.map(point), label);
+            let _ = write!(legend, "  {label} {name}");
+        } else {
+            off_plot.push(format!("{label} {name}"));
+        }
+    }
+    canvas.label(mapping.map(a), 'A');
+    canvas.label(mapping.map(b), 'B');
+    canvas.label(mapping.map(c), 'C');
+
+    let mut out = canvas.render();
+    out.push_str(&legend);
+    if !off_plot.is_empty() {
+        let _ = write!(out, "\noff plot: {}", off_plot.join(", "));
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use triale::geometry::compute_summary;
+    use triale::triangle::Triangle;
+
+    fn summary(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> TriangleSummary {
+        compute_summary(&Triangle::new(a.into(), b.into(), c.into()))
+    }
+
+    #[test]
+    fn test_braille_dots() {
+        let mut canvas = BrailleCanvas::new(2, 1);
+        canvas.set(0, 0);
+        canvas.set(1, 3);
+        canvas.set(3, 1);
+        // Out of range dots are ignored.
+        canvas.set(4, 0);
+        canvas.set(0, 4);
+        assert_eq!(canvas.render(), "\u{2881}\u{2810}\n");
+    }
+
+    #[test]
+    fn test_plot_respects_width() {
+        for columns in [20, 80, 200] {
+            let plot = render_plot(&summary((0.0, 0.0), (4.0, 0.0), (0.0, 3.0)), columns);
+            let canvas_lines: Vec<&str> = plot
+                .lines()
+                .take_while(|l| !l.starts_with("A B C"))
+                .collect();
+            assert!(!canvas_lines.is_empty());
+            assert!(
+                canvas_lines
+                    .iter()
+                    .all(|line| line.chars().count() <= columns)
+            );
+            assert!(canvas_lines.len() <= columns / 2 + 1);
+        }
+    }
+
+    #[test]
+    fn test_plot_marks_vertices_and_centres() {
+        let plot = render_plot(&summary((0.0, 0.0), (10.0, 0.0), (3.0, 7.0)), 60);
+        for label in ['A', 'B', 'C', 'G', 'I', 'O', 'H', 'N'] {
+            assert!(plot.contains(label), "missing {label}");
+        }
+        assert!(!plot.contains("off plot"));
+
+        // The orthocenter of a very obtuse triangle lies far outside.
+        let plot = render_plot(&summary((0.0, 0.0), (10.0, 0.0), (5.0, 0.5)), 60);
+        assert!(plot.contains("off plot: O circumcenter, H orthocenter"));
+    }
+
+    #[test]
+    fn test_extreme_magnitudes() {
+        let tiny = render_plot(&summary((0.0, 0.0), (1e-4, 0.0), (0.0, 1e-4)), 40);
+        let huge = render_plot(&summary((0.0, 0.0), (1e12, 0.0), (0.0, 1e12)), 40);
+        assert_eq!(tiny, huge);
+    }
+}
@@ -1,50 +1,85 @@
+use crate::scalar::{Real, Scalar};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
-pub struct Point {
-    pub x: f64,
-    pub y: f64,
+/// A point or vector in the plane, with coordinates of any [`Scalar`] type.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd)]
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Point {
-    pub const fn new(x: f64, y: f64) -> Self {
+impl<T> Point<T> {
+    #[must_use]
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
-    pub const fn splat(number: f64) -> Self {
+}
+
+impl<T: Copy> Point<T> {
+    #[must_use]
+    pub const fn splat(number: T) -> Self {
         Self::new(number, number)
     }
+}
+
+impl Point {
+    #[must_use]
     pub const fn zero() -> Self {
         Self::new(0.0, 0.0)
     }
+    #[must_use]
     pub const fn one() -> Self {
         Self::new(1.0, 1.0)
     }
+}
 
-    /// Euclidean distance to another point
-    pub fn distance_to(self, other: Self) -> f64 {
-        (other.x - self.x).hypot(other.y - self.y)
-    }
-
+impl<T: Scalar> Point<T> {
     /// Dot product treating points as 2D vectors.
-    pub fn dot(self, other: Self) -> f64 {
+    #[must_use]
+    pub fn dot(self, other: Self) -> T {
         self.x.mul_add(other.x, self.y * other.y)
     }
 
     /// 2D cross product (z-component of the 3D cross product).
-    pub fn cross(self, other: Self) -> f64 {
+    #[must_use]
+    pub fn cross(self, other: Self) -> T {
         self.x.mul_add(other.y, -(self.y * other.x))
     }
 
     /// Squared Euclidean length
-    pub fn length_sq(self) -> f64 {
-        self.x.mul_add(self.x, self.y * self.y)
+    #[must_use]
+    pub fn length_sq(self) -> T {
+        self.clone().dot(self)
+    }
+
+    /// `self / divisor`, `None` when `divisor` is zero.
+    #[must_use]
+    pub fn checked_div(self, divisor: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_div(divisor.clone())?,
+            self.y.checked_div(divisor)?,
+        ))
+    }
+
+    /// The nearest point with `f64` coordinates.
+    #[must_use]
+    pub fn to_f64(&self) -> Point {
+        Point::new(self.x.to_f64(), self.y.to_f64())
+    }
+}
+
+impl<T: Real> Point<T> {
+    /// Euclidean distance to another point
+    #[must_use]
+    pub fn distance_to(self, other: Self) -> T {
+        (other.x - self.x).hypot(other.y - self.y)
     }
 }
 
-impl Display for Point {
+impl<T: Display> Display for Point<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
     }
@@ -60,110 +95,105 @@ impl Hash for Point {
     }
 }
 
-impl Add for Point {
+impl<T: Scalar> Add for Point<T> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
         Self::new(self.x + rhs.x, self.y + rhs.y)
     }
 }
 
-impl AddAssign for Point {
+impl<T: Scalar> AddAssign for Point<T> {
     fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
+        *self = self.clone() + rhs;
     }
 }
 
-impl Add<f64> for Point {
+impl<T: Scalar> Add<T> for Point<T> {
     type Output = Self;
-    fn add(self, rhs: f64) -> Self {
-        Self::new(self.x + rhs, self.y + rhs)
+    fn add(self, rhs: T) -> Self {
+        Self::new(self.x + rhs.clone(), self.y + rhs)
     }
 }
 
-impl AddAssign<f64> for Point {
-    fn add_assign(&mut self, rhs: f64) {
-        self.x += rhs;
-        self.y += rhs;
+impl<T: Scalar> AddAssign<T> for Point<T> {
+    fn add_assign(&mut self, rhs: T) {
+        *self = self.clone() + rhs;
     }
 }
 
-impl Sub for Point {
+impl<T: Scalar> Sub for Point<T> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
         Self::new(self.x - rhs.x, self.y - rhs.y)
     }
 }
 
-impl SubAssign for Point {
+impl<T: Scalar> SubAssign for Point<T> {
     fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
+        *self = self.clone() - rhs;
     }
 }
 
-impl Sub<f64> for Point {
+impl<T: Scalar> Sub<T> for Point<T> {
     type Output = Self;
-    fn sub(self, rhs: f64) -> Self {
-        Self::new(self.x - rhs, self.y - rhs)
+    fn sub(self, rhs: T) -> Self {
+        Self::new(self.x - rhs.clone(), self.y - rhs)
     }
 }
 
-impl SubAssign<f64> for Point {
-    fn sub_assign(&mut self, rhs: f64) {
-        self.x -= rhs;
-        self.y -= rhs;
+impl<T: Scalar> SubAssign<T> for Point<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = self.clone() - rhs;
     }
 }
 
-impl Mul<f64> for Point {
+impl<T: Scalar> Mul<T> for Point<T> {
     type Output = Self;
-    fn mul(self, rhs: f64) -> Self {
-        Self::new(self.x * rhs, self.y * rhs)
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs.clone(), self.y * rhs)
     }
 }
 
-impl MulAssign<f64> for Point {
-    fn mul_assign(&mut self, rhs: f64) {
-        self.x *= rhs;
-        self.y *= rhs;
+impl<T: Scalar> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = self.clone() * rhs;
     }
 }
 
-impl Div<f64> for Point {
+impl<T: Scalar + Div<Output = T>> Div<T> for Point<T> {
     type Output = Self;
-    fn div(self, rhs: f64) -> Self {
-        Self::new(self.x / rhs, self.y / rhs)
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.x / rhs.clone(), self.y / rhs)
     }
 }
 
-impl DivAssign<f64> for Point {
-    fn div_assign(&mut self, rhs: f64) {
-        self.x /= rhs;
-        self.y /= rhs;
+impl<T: Scalar + Div<Output = T>> DivAssign<T> for Point<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = self.clone() / rhs;
     }
 }
 
-impl Neg for Point {
+impl<T: Scalar> Neg for Point<T> {
     type Output = Self;
     fn neg(self) -> Self {
         Self::new(-self.x, -self.y)
     }
 }
 
-impl From<(f64, f64)> for Point {
-    fn from((x, y): (f64, f64)) -> Self {
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
         Self::new(x, y)
     }
 }
 
-impl From<[f64; 2]> for Point {
-    fn from([x, y]: [f64; 2]) -> Self {
+impl<T> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
         Self::new(x, y)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum PointCreateError {
     InvalidFormat { got: String, example: String },
     TooManyPoints { got: usize, expected: u8 },
@@ -188,35 +218,13 @@ impl Display for PointCreateError {
 
 impl Error for PointCreateError {}
 
+/// Parses a point written as `x,y`.
+///
+/// # Errors
+/// Returns a [`PointCreateError`] when the input is empty, does not hold
+/// exactly two comma-separated values or a value is not a finite number.
 pub fn to_point(input: &str) -> Result<Point, PointCreateError> {
-    let trimmed = input.trim();
-    if trimmed.is_empty() {
-        return Err(PointCreateError::InvalidFormat {
-            got: String::new(),
-            example: "1.0,2.0".to_string(),
-        });
-    }
-
-    let parts: Vec<&str> = trimmed.split(',').map(str::trim).collect();
-
-    match parts.len().cmp(&2) {
-        std::cmp::Ordering::Less => {
-            return Err(PointCreateError::TooFewPoints {
-                got: parts.len(),
-                expected: 2,
-            });
-        }
-        std::cmp::Ordering::Greater => {
-            return Err(PointCreateError::TooManyPoints {
-                got: parts.len(),
-                expected: 2,
-            });
-        }
-        std::cmp::Ordering::Equal => {}
-    }
-
-    let x_raw = parts[0];
-    let y_raw = parts[1];
+    let [x_raw, y_raw] = split_coordinates(input)?;
 
     let x = x_raw
         .parse::<f64>()
@@ -237,6 +245,31 @@ pub fn to_point(input: &str) -> Result<Point, PointCreateError> {
     Ok(Point::new(x, y))
 }
 
+/// Splits `x,y` into its two trimmed coordinates.
+pub(crate) fn split_coordinates(input: &str) -> Result<[&str; 2], PointCreateError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(PointCreateError::InvalidFormat {
+            got: String::new(),
+            example: "1.0,2.0".to_string(),
+        });
+    }
+
+    let parts: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+
+    match *parts.as_slice() {
+        [x, y] => Ok([x, y]),
+        _ if parts.len() < 2 => Err(PointCreateError::TooFewPoints {
+            got: parts.len(),
+            expected: 2,
+        }),
+        _ => Err(PointCreateError::TooManyPoints {
+            got: parts.len(),
+            expected: 2,
+        }),
+    }
+}
+
 fn validate_finite(value: f64, label: &str, raw: &str) -> Result<f64, PointCreateError> {
     if value.is_nan() {
         return Err(PointCreateError::InvalidFormat {
@@ -254,8 +287,10 @@ fn validate_finite(value: f64, label: &str, raw: &str) -> Result<f64, PointCreat
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::float_cmp, clippy::approx_constant)]
 mod tests {
     use super::*;
+    use crate::triangle::Triangle;
     #[test]
     fn test_point_arithmetic() {
         let p1 = Point::new(10.0, 20.0);
@@ -293,6 +328,15 @@ mod tests {
         assert_eq!(p3.cross(p4), 1.0);
     }
 
+    #[test]
+    fn test_const_constructors() {
+        // The f64 constructors stay usable in constants.
+        const ORIGIN: Point = Point::zero();
+        const CORNER: Triangle = Triangle::splat(Point::splat(1.0));
+        assert_eq!(ORIGIN, Point::new(0.0, 0.0));
+        assert_eq!(CORNER, Triangle::one());
+    }
+
     #[test]
     fn test_to_point_parsing() {
         // Basic parsing
@@ -314,7 +358,7 @@ mod tests {
 
     #[test]
     fn test_extreme_values() {
-        let p1 = Point::new(1e150, 1e150);
+        let p1: Point = Point::new(1e150, 1e150);
         let p2 = Point::new(-1e150, -1e150);
         // distance_to uses hypot, should not overflow to inf easily
         assert!(p1.distance_to(p2).is_finite());
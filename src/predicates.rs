@@ -0,0 +1,223 @@
+//! Exact geometric predicates on `f64` coordinates.
+//!
+//! Each predicate decides the sign of a small polynomial in the coordinates
+//! as if it were evaluated in exact arithmetic. A plain floating-point
+//! evaluation answers most inputs; when its result is too close to zero to be
+//! trusted, the polynomial is recomputed exactly with floating-point
+//! expansions (sums of non-overlapping `f64` components, after Shewchuk's
+//! "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric
+//! Predicates"). Answers are exact as long as no intermediate product
+//! overflows or underflows.
+
+use crate::point::Point;
+use std::cmp::Ordering;
+
+/// Half the distance from 1.0 to the next larger `f64`.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+/// Relative error bound of the plain evaluation of `orient2d` and
+/// `dot_at`, in units of the sum of the magnitudes of the two products.
+const PRODUCT_PAIR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+
+/// Which way the path `a -> b -> c` turns.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    Collinear,
+    CounterClockwise,
+}
+
+/// Twice the signed area of the triangle `a b c`: positive when the points
+/// are in counter-clockwise order. The magnitude is approximate but the sign
+/// is always exact.
+#[must_use]
+pub fn orient2d(a: Point, b: Point, c: Point) -> f64 {
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    if det.abs() > PRODUCT_PAIR_BOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+    let left = product(diff(a.x, c.x), diff(b.y, c.y));
+    let right = product(diff(a.y, c.y), diff(b.x, c.x));
+    most_significant(&sum(&left, &negate(&right)))
+}
+
+/// Exact orientation of `a`, `b` and `c`.
+#[must_use]
+pub fn orientation(a: Point, b: Point, c: Point) -> Orientation {
+    match orient2d(a, b, c).partial_cmp(&0.0) {
+        Some(Ordering::Greater) => Orientation::CounterClockwise,
+        Some(Ordering::Less) => Orientation::Clockwise,
+        _ => Orientation::Collinear,
+    }
+}
+
+/// Exact sign of `(p - vertex) . (q - vertex)`.
+///
+/// `Greater` when the angle at `vertex` is acute, `Equal` when it is right
+/// and `Less` when it is obtuse. `None` when the evaluation produces NaN, e.g.
+/// for infinite coordinates.
+#[must_use]
+pub fn dot_at(vertex: Point, p: Point, q: Point) -> Option<Ordering> {
+    let first = (p.x - vertex.x) * (q.x - vertex.x);
+    let second = (p.y - vertex.y) * (q.y - vertex.y);
+    let dot = first + second;
+    if dot.abs() > PRODUCT_PAIR_BOUND * (first.abs() + second.abs()) {
+        return sign(dot);
+    }
+    let first = product(diff(p.x, vertex.x), diff(q.x, vertex.x));
+    let second = product(diff(p.y, vertex.y), diff(q.y, vertex.y));
+    sign(most_significant(&sum(&first, &second)))
+}
+
+/// Exact comparison of the distances `|a b|` and `|c d|`, `None` as for
+/// [`dot_at`].
+#[must_use]
+pub fn compare_distances(a: Point, b: Point, c: Point, d: Point) -> Option<Ordering> {
+    let first = squared_distance(a, b);
+    let second = squared_distance(c, d);
+    sign(most_significant(&sum(&first, &negate(&second))))
+}
+
+/// `|a b|^2` as an exact expansion.
+fn squared_distance(a: Point, b: Point) -> Vec<f64> {
+    let dx = diff(b.x, a.x);
+    let dy = diff(b.y, a.y);
+    sum(&product(dx, dx), &product(dy, dy))
+}
+
+fn sign(value: f64) -> Option<Ordering> {
+    value.partial_cmp(&0.0)
+}
+
+/// `a + b` as the rounded sum and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// `a * b` as the rounded product and its rounding error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+/// `a - b` as a two-component expansion, smallest component first.
+fn diff(a: f64, b: f64) -> [f64; 2] {
+    let (difference, error) = two_sum(a, -b);
+    [error, difference]
+}
+
+/// Adds `b` to the expansion `e`, dropping zero components.
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut grown = Vec::with_capacity(e.len() + 1);
+    let mut carry = b;
+    for &component in e {
+        let (total, error) = two_sum(carry, component);
+        carry = total;
+        if error != 0.0 {
+            grown.push(error);
+        }
+    }
+    if carry != 0.0 || grown.is_empty() {
+        grown.push(carry);
+    }
+    grown
+}
+
+fn sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter()
+        .fold(e.to_vec(), |total, &component| grow(&total, component))
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|component| -component).collect()
+}
+
+/// Exact product of two two-component expansions.
+fn product(e: [f64; 2], f: [f64; 2]) -> Vec<f64> {
+    let mut total = vec![0.0];
+    for a in e {
+        for b in f {
+            let (product, error) = two_product(a, b);
+            total = grow(&grow(&total, error), product);
+        }
+    }
+    total
+}
+
+/// The largest component, which carries the sign of the whole expansion.
+fn most_significant(e: &[f64]) -> f64 {
+    e.last().copied().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One unit in the last place of 0.5.
+    const ULP: f64 = f64::EPSILON / 2.0;
+
+    #[test]
+    fn test_orientation_near_a_line() {
+        // Every p lies within a few ulps of the line y = x through q and r,
+        // and turns counter-clockwise exactly when p.y > p.x. The plain
+        // cross product gets the sign wrong for most of them.
+        let q = Point::new(12.0, 12.0);
+        let r = Point::new(24.0, 24.0);
+        for i in 0..32 {
+            for j in 0..32 {
+                let p = Point::new(
+                    f64::from(i).mul_add(ULP, 0.5),
+                    f64::from(j).mul_add(ULP, 0.5),
+                );
+                let expected = match p.y.partial_cmp(&p.x) {
+                    Some(Ordering::Greater) => Orientation::CounterClockwise,
+                    Some(Ordering::Less) => Orientation::Clockwise,
+                    _ => Orientation::Collinear,
+                };
+                assert_eq!(orientation(p, q, r), expected, "{p}");
+                assert_eq!(orientation(q, r, p), expected, "{p}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_orientation_far_from_degenerate() {
+        let (a, b, c) = (Point::zero(), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+        assert_eq!(orientation(a, b, c), Orientation::CounterClockwise);
+        assert_eq!(orientation(a, c, b), Orientation::Clockwise);
+        assert!((orient2d(a, b, c) - 12.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_exact_distances_and_angles() {
+        let origin = Point::zero();
+        let unit = Point::new(1.0, 0.0);
+        // 1 + 2^-60 rounds to 1, yet the first segment is longer.
+        let tilted = Point::new(1.0, 2f64.powi(-30));
+        assert_eq!(origin.distance_to(tilted).total_cmp(&1.0), Ordering::Equal);
+        assert_eq!(
+            compare_distances(origin, tilted, origin, unit),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_distances(origin, unit, unit, origin),
+            Some(Ordering::Equal)
+        );
+
+        let up = Point::new(0.0, 1.0);
+        assert_eq!(dot_at(origin, unit, up), Some(Ordering::Equal));
+        assert_eq!(dot_at(origin, unit, Point::new(f64::NAN, 1.0)), None);
+        assert_eq!(
+            dot_at(origin, unit, Point::new(2f64.powi(-60), 1.0)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            dot_at(origin, unit, Point::new(-(2f64.powi(-60)), 1.0)),
+            Some(Ordering::Less)
+        );
+    }
+}
@@ -0,0 +1,601 @@
+//! Lines, segments, rays and circles in the plane.
+//!
+//! Directions are kept as given rather than normalized, so a line through two
+//! points carries their difference. Intersections return every common point:
+//! none, one for touching or crossing once, or two. Tangency is decided with a
+//! [`Tolerance`] relative to the radii, so results computed in floating point
+//! still touch whatever their scale. The plain methods use the default
+//! tolerance, the `_with` variants take one.
+
+use crate::point::Point;
+use crate::tolerance::Tolerance;
+
+/// A vector at a right angle to `dir`, turned counter-clockwise.
+const fn perpendicular(dir: Point) -> Point {
+    Point::new(-dir.y, dir.x)
+}
+
+/// The infinite line through `point` in direction `direction`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Line {
+    pub point: Point,
+    pub direction: Point,
+}
+
+impl Line {
+    #[must_use]
+    pub const fn new(point: Point, direction: Point) -> Self {
+        Self { point, direction }
+    }
+
+    /// The line through `from` and `to`, `None` when they coincide.
+    #[must_use]
+    pub fn through(from: Point, to: Point) -> Option<Self> {
+        (from != to).then(|| Self::new(from, to - from))
+    }
+
+    /// The line through `point` at a right angle to this one.
+    #[must_use]
+    pub const fn perpendicular_through(&self, point: Point) -> Self {
+        Self::new(point, perpendicular(self.direction))
+    }
+
+    /// The point at parameter `t`, where `t = 1` is one direction vector away.
+    #[must_use]
+    pub fn point_at(&self, t: f64) -> Point {
+        self.point + self.direction * t
+    }
+
+    /// The parameter of the orthogonal projection of `point`.
+    #[must_use]
+    pub fn parameter_of(&self, point: Point) -> f64 {
+        (point - self.point).dot(self.direction) / self.direction.length_sq()
+    }
+
+    /// Orthogonal projection of `point` onto the line.
+    #[must_use]
+    pub fn project(&self, point: Point) -> Point {
+        self.point_at(self.parameter_of(point))
+    }
+
+    /// Mirror image of `point` across the line.
+    #[must_use]
+    pub fn reflect(&self, point: Point) -> Point {
+        self.project(point) * 2.0 - point
+    }
+
+    /// Distance to `point`, positive on the left of the direction.
+    #[must_use]
+    pub fn signed_distance(&self, point: Point) -> f64 {
+        self.direction.cross(point - self.point) / self.direction.length_sq().sqrt()
+    }
+
+    #[must_use]
+    pub fn distance_to(&self, point: Point) -> f64 {
+        self.signed_distance(point).abs()
+    }
+
+    #[must_use]
+    pub fn is_parallel(&self, other: &Self) -> bool {
+        self.direction.cross(other.direction) == 0.0
+    }
+
+    /// Parameters along `self` and `other` of their crossing point.
+    fn crossing(&self, other: &Self) -> Option<(f64, f64)> {
+        let denom = self.direction.cross(other.direction);
+        if denom == 0.0 {
+            return None;
+        }
+        let offset = other.point - self.point;
+        Some((
+            offset.cross(other.direction) / denom,
+            offset.cross(self.direction) / denom,
+        ))
+    }
+
+    /// The crossing point, `None` for parallel (or identical) lines.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Point> {
+        self.crossing(other).map(|(t, _)| self.point_at(t))
+    }
+
+    /// Parameters of the points where the line meets `circle`, in increasing
+    /// order.
+    fn circle_parameters(&self, circle: &Circle, tolerance: Tolerance) -> Vec<f64> {
+        let foot = self.parameter_of(circle.center);
+        let distance = self.distance_to(circle.center);
+        if tolerance.approx_eq(distance, circle.radius) {
+            return vec![foot];
+        }
+        if distance > circle.radius {
+            return Vec::new();
+        }
+        let half_chord = distance
+            .mul_add(-distance, circle.radius * circle.radius)
+            .sqrt();
+        let step = half_chord / self.direction.length_sq().sqrt();
+        vec![foot - step, foot + step]
+    }
+
+    /// Where the line meets `circle`: no point, a single touching point or
+    /// two crossing points in the order of the direction.
+    #[must_use]
+    pub fn intersect_circle(&self, circle: &Circle) -> Vec<Point> {
+        self.intersect_circle_with(circle, Tolerance::default())
+    }
+
+    /// [`Self::intersect_circle`], touching when the distance to the centre
+    /// matches the radius within `tolerance`.
+    #[must_use]
+    pub fn intersect_circle_with(&self, circle: &Circle, tolerance: Tolerance) -> Vec<Point> {
+        self.circle_parameters(circle, tolerance)
+            .into_iter()
+            .map(|t| self.point_at(t))
+            .collect()
+    }
+}
+
+/// The straight path from `start` to `end`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Segment {
+    #[must_use]
+    pub const fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    #[must_use]
+    pub fn length(&self) -> f64 {
+        self.start.distance_to(self.end)
+    }
+
+    #[must_use]
+    pub fn midpoint(&self) -> Point {
+        (self.start + self.end) / 2.0
+    }
+
+    /// The line carrying the segment, `None` when it is a single point.
+    #[must_use]
+    pub fn line(&self) -> Option<Line> {
+        Line::through(self.start, self.end)
+    }
+
+    /// Where the projection of `point` falls along the segment: 0 at `start`,
+    /// 1 at `end` and outside `[0, 1]` beyond them.
+    #[must_use]
+    pub fn parameter_of(&self, point: Point) -> f64 {
+        let dir = self.end - self.start;
+        (point - self.start).dot(dir) / dir.length_sq()
+    }
+
+    /// Whether the projection of `point` falls outside the segment.
+    #[must_use]
+    pub fn projects_outside(&self, point: Point) -> bool {
+        !(0.0..=1.0).contains(&self.parameter_of(point))
+    }
+
+    /// Nearest point of the segment to `point`.
+    #[must_use]
+    pub fn closest_point(&self, point: Point) -> Point {
+        if self.start == self.end {
+            return self.start;
+        }
+        let t = self.parameter_of(point).clamp(0.0, 1.0);
+        self.start + (self.end - self.start) * t
+    }
+
+    /// The crossing point with `other`, `None` when they miss each other or
+    /// are parallel.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Point> {
+        let (t, u) = self.line()?.crossing(&other.line()?)?;
+        let on_both = (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u);
+        on_both.then(|| self.start + (self.end - self.start) * t)
+    }
+
+    /// Where the segment meets `line`.
+    #[must_use]
+    pub fn intersect_line(&self, line: &Line) -> Option<Point> {
+        let (t, _) = self.line()?.crossing(line)?;
+        (0.0..=1.0)
+            .contains(&t)
+            .then(|| self.start + (self.end - self.start) * t)
+    }
+
+    /// Where the segment meets `circle`, in order from `start`.
+    #[must_use]
+    pub fn intersect_circle(&self, circle: &Circle) -> Vec<Point> {
+        self.intersect_circle_with(circle, Tolerance::default())
+    }
+
+    /// [`Self::intersect_circle`] with tangency decided within `tolerance`.
+    #[must_use]
+    pub fn intersect_circle_with(&self, circle: &Circle, tolerance: Tolerance) -> Vec<Point> {
+        let Some(line) = self.line() else {
+            return Vec::new();
+        };
+        line.circle_parameters(circle, tolerance)
+            .into_iter()
+            .filter(|t| (0.0..=1.0).contains(t))
+            .map(|t| line.point_at(t))
+            .collect()
+    }
+}
+
+/// The half line starting at `origin` and running in direction `direction`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Point,
+}
+
+impl Ray {
+    #[must_use]
+    pub const fn new(origin: Point, direction: Point) -> Self {
+        Self { origin, direction }
+    }
+
+    /// The ray from `origin` through `toward`, `None` when they coincide.
+    #[must_use]
+    pub fn through(origin: Point, toward: Point) -> Option<Self> {
+        (origin != toward).then(|| Self::new(origin, toward - origin))
+    }
+
+    #[must_use]
+    pub const fn line(&self) -> Line {
+        Line::new(self.origin, self.direction)
+    }
+
+    /// Nearest point of the ray to `point`.
+    #[must_use]
+    pub fn closest_point(&self, point: Point) -> Point {
+        let line = self.line();
+        line.point_at(line.parameter_of(point).max(0.0))
+    }
+
+    /// Where the ray meets `line`.
+    #[must_use]
+    pub fn intersect_line(&self, line: &Line) -> Option<Point> {
+        let (t, _) = self.line().crossing(line)?;
+        (t >= 0.0).then(|| self.line().point_at(t))
+    }
+
+    /// Where the ray meets `other`.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Point> {
+        let (t, u) = self.line().crossing(&other.line())?;
+        (t >= 0.0 && u >= 0.0).then(|| self.line().point_at(t))
+    }
+
+    /// Where the ray meets `circle`, nearest to the origin first.
+    #[must_use]
+    pub fn intersect_circle(&self, circle: &Circle) -> Vec<Point> {
+        self.intersect_circle_with(circle, Tolerance::default())
+    }
+
+    /// [`Self::intersect_circle`] with tangency decided within `tolerance`.
+    #[must_use]
+    pub fn intersect_circle_with(&self, circle: &Circle, tolerance: Tolerance) -> Vec<Point> {
+        let line = self.line();
+        line.circle_parameters(circle, tolerance)
+            .into_iter()
+            .filter(|&t| t >= 0.0)
+            .map(|t| line.point_at(t))
+            .collect()
+    }
+}
+
+/// The circle around `center` with radius `radius`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f64,
+}
+
+impl Circle {
+    #[must_use]
+    pub const fn new(center: Point, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    /// The circle through three points, `None` when they are collinear.
+    #[must_use]
+    pub fn through(a: Point, b: Point, c: Point) -> Option<Self> {
+        let first = Segment::new(a, b);
+        let second = Segment::new(b, c);
+        let bisector = |segment: Segment| {
+            segment
+                .line()
+                .map(|line| line.perpendicular_through(segment.midpoint()))
+        };
+        let center = bisector(first)?.intersect(&bisector(second)?)?;
+        Some(Self::new(center, center.distance_to(a)))
+    }
+
+    /// Whether `point` lies inside or on the circle.
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.contains_with(point, Tolerance::default())
+    }
+
+    /// [`Self::contains`], counting points within `tolerance` of the circle,
+    /// relative to the radius, as on it.
+    #[must_use]
+    pub fn contains_with(&self, point: Point, tolerance: Tolerance) -> bool {
+        let distance = self.center.distance_to(point);
+        distance <= self.radius || tolerance.approx_eq(distance, self.radius)
+    }
+
+    /// Nearest point of the circle to `point`, `None` for the centre, which
+    /// is equally far from all of them.
+    #[must_use]
+    pub fn project(&self, point: Point) -> Option<Point> {
+        let offset = point - self.center;
+        let distance = offset.length_sq().sqrt();
+        (distance > 0.0).then(|| self.center + offset * (self.radius / distance))
+    }
+
+    /// Where the circle meets `line`, see [`Line::intersect_circle`].
+    #[must_use]
+    pub fn intersect_line(&self, line: &Line) -> Vec<Point> {
+        line.intersect_circle(self)
+    }
+
+    /// Where the circle meets `line`, see [`Line::intersect_circle_with`].
+    #[must_use]
+    pub fn intersect_line_with(&self, line: &Line, tolerance: Tolerance) -> Vec<Point> {
+        line.intersect_circle_with(self, tolerance)
+    }
+
+    /// Where two circles meet: no point, a single touching point or two
+    /// crossing points. Concentric circles, including identical ones, have
+    /// no isolated common point and yield none.
+    #[must_use]
+    pub fn intersect_circle(&self, other: &Self) -> Vec<Point> {
+        self.intersect_circle_with(other, Tolerance::default())
+    }
+
+    /// [`Self::intersect_circle`], touching when the distance between the
+    /// centres matches the sum or difference of the radii within `tolerance`,
+    /// relative to the larger radius.
+    #[must_use]
+    pub fn intersect_circle_with(&self, other: &Self, tolerance: Tolerance) -> Vec<Point> {
+        let offset = other.center - self.center;
+        let distance = offset.length_sq().sqrt();
+        if distance == 0.0 {
+            return Vec::new();
+        }
+        // Distance from `self.center` to the chord through both points.
+        let along = other.radius.mul_add(
+            -other.radius,
+            distance.mul_add(distance, self.radius * self.radius),
+        ) / (2.0 * distance);
+        let base = self.center + offset * (along / distance);
+        let scale = self.radius.max(other.radius);
+        let touching = tolerance.is_negligible(distance - (self.radius + other.radius), scale)
+            || tolerance.is_negligible(distance - (self.radius - other.radius).abs(), scale);
+        if touching {
+            return vec![base];
+        }
+        let half_chord_sq = along.mul_add(-along, self.radius * self.radius);
+        if half_chord_sq < 0.0 {
+            return Vec::new();
+        }
+        let across = perpendicular(offset) * (half_chord_sq.sqrt() / distance);
+        vec![base + across, base - across]
+    }
+
+    /// The tangent touching the circle at `point`, which should lie on it.
+    /// `None` for a circle of radius zero.
+    #[must_use]
+    pub fn tangent_at(&self, point: Point) -> Option<Line> {
+        let radial = self.project(point)? - self.center;
+        Some(Line::new(self.center + radial, perpendicular(radial)))
+    }
+
+    /// Tangents to the circle through `point`: none from inside, the single
+    /// tangent at `point` when it lies on the circle and two from outside.
+    /// Each line runs from `point` to its touching point.
+    #[must_use]
+    pub fn tangents_from(&self, point: Point) -> Vec<Line> {
+        self.tangents_from_with(point, Tolerance::default())
+    }
+
+    /// [`Self::tangents_from`], treating `point` as on the circle when its
+    /// distance to the centre matches the radius within `tolerance`.
+    #[must_use]
+    pub fn tangents_from_with(&self, point: Point, tolerance: Tolerance) -> Vec<Line> {
+        let offset = point - self.center;
+        let distance = offset.length_sq().sqrt();
+        if tolerance.approx_eq(distance, self.radius) {
+            return self.tangent_at(point).into_iter().collect();
+        }
+        if distance < self.radius {
+            return Vec::new();
+        }
+        // The touching points lie on the circle with diameter from the
+        // centre to `point`.
+        let thales = Self::new((self.center + point) / 2.0, distance / 2.0);
+        self.intersect_circle_with(&thales, tolerance)
+            .into_iter()
+            .map(|touch| Line::new(point, touch - point))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::{approx_eq, approx_eq_pt};
+
+    #[test]
+    fn test_line_projection_and_reflection() {
+        let line = Line::through(Point::new(0.0, 1.0), Point::new(2.0, 3.0)).unwrap();
+        assert!(approx_eq_pt(
+            line.project(Point::new(2.0, 1.0)),
+            Point::new(1.0, 2.0)
+        ));
+        assert!(approx_eq_pt(
+            line.reflect(Point::new(2.0, 1.0)),
+            Point::new(0.0, 3.0)
+        ));
+        assert!(approx_eq(
+            line.signed_distance(Point::new(0.0, 3.0)),
+            2.0f64.sqrt()
+        ));
+        assert!(approx_eq(
+            line.signed_distance(Point::new(2.0, 1.0)),
+            -(2.0f64.sqrt())
+        ));
+        assert_eq!(Line::through(Point::one(), Point::one()), None);
+    }
+
+    #[test]
+    fn test_line_and_segment_intersections() {
+        let x_axis = Line::new(Point::zero(), Point::new(1.0, 0.0));
+        let diagonal = Line::through(Point::new(1.0, -1.0), Point::new(3.0, 1.0)).unwrap();
+        assert!(approx_eq_pt(
+            x_axis.intersect(&diagonal).unwrap(),
+            Point::new(2.0, 0.0)
+        ));
+        let parallel = Line::new(Point::new(0.0, 5.0), Point::new(-3.0, 0.0));
+        assert!(x_axis.is_parallel(&parallel));
+        assert_eq!(x_axis.intersect(&parallel), None);
+
+        let crossing = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let other = Segment::new(Point::new(0.0, 2.0), Point::new(2.0, 0.0));
+        assert!(approx_eq_pt(
+            crossing.intersect(&other).unwrap(),
+            Point::one()
+        ));
+        let short = Segment::new(Point::new(0.0, 2.0), Point::new(0.5, 1.5));
+        assert_eq!(crossing.intersect(&short), None);
+        assert!(crossing.intersect_line(&x_axis).is_some());
+        assert!(approx_eq_pt(
+            crossing.closest_point(Point::new(3.0, 5.0)),
+            Point::new(2.0, 2.0)
+        ));
+
+        let ray = Ray::through(Point::new(1.0, 1.0), Point::new(2.0, 2.0)).unwrap();
+        assert_eq!(ray.intersect_line(&x_axis), None);
+        assert!(approx_eq_pt(
+            ray.closest_point(Point::new(0.0, -3.0)),
+            ray.origin
+        ));
+        let upward = Ray::new(Point::new(4.0, 0.0), Point::new(0.0, 1.0));
+        assert!(approx_eq_pt(
+            ray.intersect(&upward).unwrap(),
+            Point::new(4.0, 4.0)
+        ));
+    }
+
+    #[test]
+    fn test_circle_intersections() {
+        let unit = Circle::new(Point::zero(), 1.0);
+        let x_axis = Line::new(Point::new(-5.0, 0.0), Point::new(1.0, 0.0));
+        let points = unit.intersect_line(&x_axis);
+        assert_eq!(points.len(), 2);
+        assert!(approx_eq_pt(points[0], Point::new(-1.0, 0.0)));
+        assert!(approx_eq_pt(points[1], Point::new(1.0, 0.0)));
+
+        let touching = Line::new(Point::new(0.0, 1.0), Point::new(1.0, 0.0));
+        assert_eq!(unit.intersect_line(&touching).len(), 1);
+        let missing = Line::new(Point::new(0.0, 2.0), Point::new(1.0, 0.0));
+        assert!(unit.intersect_line(&missing).is_empty());
+
+        let ray = Ray::new(Point::zero(), Point::new(1.0, 0.0));
+        assert_eq!(ray.intersect_circle(&unit).len(), 1);
+        let chord = Segment::new(Point::new(-2.0, 0.0), Point::new(0.5, 0.0));
+        assert_eq!(chord.intersect_circle(&unit).len(), 1);
+
+        let other = Circle::new(Point::new(1.0, 0.0), 1.0);
+        let points = unit.intersect_circle(&other);
+        assert_eq!(points.len(), 2);
+        for point in points {
+            assert!(approx_eq(point.x, 0.5));
+            assert!(approx_eq(point.distance_to(Point::zero()), 1.0));
+        }
+        let outside = Circle::new(Point::new(3.0, 0.0), 2.0);
+        assert!(approx_eq_pt(
+            unit.intersect_circle(&outside)[0],
+            Point::new(1.0, 0.0)
+        ));
+        let inside = Circle::new(Point::new(0.5, 0.0), 0.5);
+        assert_eq!(unit.intersect_circle(&inside).len(), 1);
+        assert!(
+            unit.intersect_circle(&Circle::new(Point::new(5.0, 0.0), 1.0))
+                .is_empty()
+        );
+        assert!(unit.intersect_circle(&unit).is_empty());
+    }
+
+    #[test]
+    fn test_tangency_does_not_depend_on_scale() {
+        for scale in [1e-12, 1.0, 1e12] {
+            let circle = Circle::new(Point::zero(), scale);
+            let touching = Line::new(Point::new(0.0, scale), Point::new(1.0, 0.0));
+            assert_eq!(circle.intersect_line(&touching).len(), 1, "{scale}");
+            let crossing = Line::new(Point::new(0.0, scale * 0.999), Point::new(1.0, 0.0));
+            assert_eq!(circle.intersect_line(&crossing).len(), 2, "{scale}");
+
+            let outside = Circle::new(Point::new(3.0 * scale, 0.0), 2.0 * scale);
+            assert_eq!(circle.intersect_circle(&outside).len(), 1, "{scale}");
+            let apart = Circle::new(Point::new(3.001 * scale, 0.0), 2.0 * scale);
+            assert!(circle.intersect_circle(&apart).is_empty(), "{scale}");
+
+            let beyond = Point::new(0.0, scale * (1.0 + 1e-6));
+            assert!(!circle.contains(beyond));
+            assert!(circle.contains_with(beyond, Tolerance::relative(1e-3)));
+            assert_eq!(circle.tangents_from(beyond).len(), 2);
+            assert_eq!(
+                circle
+                    .tangents_from_with(beyond, Tolerance::relative(1e-3))
+                    .len(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn test_circle_through_points_and_tangents() {
+        let circle = Circle::through(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        )
+        .unwrap();
+        assert!(approx_eq_pt(circle.center, Point::new(2.0, 1.5)));
+        assert!(approx_eq(circle.radius, 2.5));
+        assert_eq!(
+            Circle::through(Point::zero(), Point::one(), Point::splat(2.0)),
+            None
+        );
+
+        let unit = Circle::new(Point::zero(), 1.0);
+        let from = Point::new(2.0, 0.0);
+        let tangents = unit.tangents_from(from);
+        assert_eq!(tangents.len(), 2);
+        for tangent in tangents {
+            assert!(approx_eq(tangent.distance_to(unit.center), 1.0));
+            let touch = tangent.point_at(1.0);
+            assert!(approx_eq(touch.x, 0.5));
+        }
+        assert_eq!(unit.tangents_from(Point::new(0.0, 1.0)).len(), 1);
+        assert!(unit.tangents_from(Point::new(0.2, 0.1)).is_empty());
+
+        let tangent = unit.tangent_at(Point::new(0.0, -1.0)).unwrap();
+        assert!(tangent.is_parallel(&Line::new(Point::zero(), Point::new(1.0, 0.0))));
+        assert!(unit.contains(Point::new(0.6, 0.8)));
+        assert!(!unit.contains(Point::new(0.8, 0.8)));
+        assert!(approx_eq_pt(
+            unit.project(Point::new(0.0, 3.0)).unwrap(),
+            Point::new(0.0, 1.0)
+        ));
+        assert_eq!(unit.project(Point::zero()), None);
+    }
+}
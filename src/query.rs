@@ -0,0 +1,329 @@
+//! Where a point lies relative to a triangle: containment, the nearest point of
+//! the outline and the signed distance to it.
+
+use crate::point::Point;
+use crate::predicates::{Orientation, orientation};
+use crate::primitives::Segment;
+use crate::tolerance::Tolerance;
+use crate::triangle::Triangle;
+use std::fmt::{Display, Formatter};
+
+/// A vertex of the triangle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Vertex {
+    A,
+    B,
+    C,
+}
+
+impl Display for Vertex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::A => write!(f, "A"),
+            Self::B => write!(f, "B"),
+            Self::C => write!(f, "C"),
+        }
+    }
+}
+
+/// An edge of the triangle, named after its end points.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Edge {
+    Ab,
+    Bc,
+    Ca,
+}
+
+impl Display for Edge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ab => write!(f, "AB"),
+            Self::Bc => write!(f, "BC"),
+            Self::Ca => write!(f, "CA"),
+        }
+    }
+}
+
+/// How a point relates to the closed triangle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    OnEdge(Edge),
+    OnVertex(Vertex),
+    Outside,
+}
+
+impl Containment {
+    /// Stable identifier used in machine readable output.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Inside => "inside",
+            Self::OnEdge(_) => "edge",
+            Self::OnVertex(_) => "vertex",
+            Self::Outside => "outside",
+        }
+    }
+
+    /// The edge or vertex the point lies on, if any.
+    #[must_use]
+    pub fn feature(self) -> Option<String> {
+        match self {
+            Self::OnEdge(edge) => Some(edge.to_string()),
+            Self::OnVertex(vertex) => Some(vertex.to_string()),
+            Self::Inside | Self::Outside => None,
+        }
+    }
+
+    /// Inside, on an edge or on a vertex.
+    #[must_use]
+    pub const fn is_contained(self) -> bool {
+        !matches!(self, Self::Outside)
+    }
+}
+
+impl Display for Containment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Inside => write!(f, "inside"),
+            Self::OnEdge(edge) => write!(f, "on edge {edge}"),
+            Self::OnVertex(vertex) => write!(f, "on vertex {vertex}"),
+            Self::Outside => write!(f, "outside"),
+        }
+    }
+}
+
+/// Everything [`query_point`] finds out about one point.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct PointQuery {
+    pub containment: Containment,
+    /// The nearest point of the triangle's outline.
+    pub closest: Point,
+    /// Distance to the outline, negative inside the triangle and zero on it.
+    pub signed_distance: f64,
+}
+
+/// Nearest point to `point` on the segment from `from` to `to`.
+#[must_use]
+pub fn closest_point_on_segment(point: Point, from: Point, to: Point) -> Point {
+    Segment::new(from, to).closest_point(point)
+}
+
+/// Classifies `point` and measures its distance to the outline of `tri`,
+/// using the default [`Tolerance`].
+#[must_use]
+pub fn query_point(point: Point, tri: &Triangle) -> PointQuery {
+    query_point_with(point, tri, Tolerance::default())
+}
+
+/// Classifies `point` and measures its distance to the outline of `tri`.
+///
+/// Points exactly on a vertex or an edge are found with the exact
+/// [`orientation`] predicate, the same one that decides collinearity. Points
+/// within `tolerance` of a vertex or edge, relative to the longest side, are
+/// matched to it as well, so points computed in floating point still land on
+/// the feature they were meant to. A degenerate triangle has no inside.
+#[must_use]
+pub fn query_point_with(point: Point, tri: &Triangle, tolerance: Tolerance) -> PointQuery {
+    let edges = [
+        (Edge::Ab, tri.a, tri.b),
+        (Edge::Bc, tri.b, tri.c),
+        (Edge::Ca, tri.c, tri.a),
+    ];
+    let (edge, closest, distance) = edges
+        .into_iter()
+        .map(|(edge, from, to)| {
+            let closest = closest_point_on_segment(point, from, to);
+            (edge, closest, point.distance_to(closest))
+        })
+        .min_by(|lhs, rhs| lhs.2.total_cmp(&rhs.2))
+        .unwrap_or_else(|| (Edge::Ab, tri.a, point.distance_to(tri.a)));
+
+    let [a, b, c] = tri.side_lengths();
+    let longest = a.max(b).max(c);
+
+    let vertices = [(Vertex::A, tri.a), (Vertex::B, tri.b), (Vertex::C, tri.c)];
+    if let Some((vertex, at)) = vertices
+        .into_iter()
+        .find(|(_, at)| tolerance.is_negligible(point.distance_to(*at), longest))
+    {
+        return PointQuery {
+            containment: Containment::OnVertex(vertex),
+            closest: at,
+            signed_distance: 0.0,
+        };
+    }
+    let exact_edge = edges
+        .into_iter()
+        .find(|(_, from, to)| lies_on_segment(point, *from, *to));
+    if let Some((edge, from, to)) = exact_edge {
+        return PointQuery {
+            containment: Containment::OnEdge(edge),
+            closest: closest_point_on_segment(point, from, to),
+            signed_distance: 0.0,
+        };
+    }
+    if tolerance.is_negligible(distance, longest) {
+        return PointQuery {
+            containment: Containment::OnEdge(edge),
+            closest,
+            signed_distance: 0.0,
+        };
+    }
+
+    let turn = orientation(tri.a, tri.b, tri.c);
+    let inside = turn != Orientation::Collinear
+        && edges
+            .iter()
+            .all(|(_, from, to)| orientation(*from, *to, point) == turn);
+    PointQuery {
+        containment: if inside {
+            Containment::Inside
+        } else {
+            Containment::Outside
+        },
+        closest,
+        signed_distance: if inside { -distance } else { distance },
+    }
+}
+
+/// Whether `point` lies exactly on the closed segment from `from` to `to`.
+fn lies_on_segment(point: Point, from: Point, to: Point) -> bool {
+    let within =
+        |value: f64, end1: f64, end2: f64| end1.min(end2) <= value && value <= end1.max(end2);
+    orientation(from, to, point) == Orientation::Collinear
+        && within(point.x, from.x, to.x)
+        && within(point.y, from.y, to.y)
+}
+
+/// Whether `point` lies inside or on the boundary of `tri`, using the default
+/// [`Tolerance`].
+#[must_use]
+pub fn contains(tri: &Triangle, point: Point) -> bool {
+    query_point(point, tri).containment.is_contained()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{approx_eq, approx_eq_pt, right_triangle};
+    use crate::triangle::check_collinear;
+
+    #[test]
+    fn test_containment() {
+        let tri = right_triangle();
+        let cases = [
+            ((1.0, 1.0), Containment::Inside),
+            ((2.0, 0.0), Containment::OnEdge(Edge::Ab)),
+            ((2.0, 1.5), Containment::OnEdge(Edge::Bc)),
+            ((0.0, 1.0), Containment::OnEdge(Edge::Ca)),
+            ((4.0, 0.0), Containment::OnVertex(Vertex::B)),
+            ((0.0, 3.0), Containment::OnVertex(Vertex::C)),
+            ((5.0, 5.0), Containment::Outside),
+            ((-1.0, -1.0), Containment::Outside),
+        ];
+        for ((x, y), expected) in cases {
+            assert_eq!(
+                query_point(Point::new(x, y), &tri).containment,
+                expected,
+                "({x}, {y})"
+            );
+        }
+
+        // Winding order does not matter.
+        let reversed = Triangle::new(tri.c, tri.b, tri.a);
+        assert!(contains(&reversed, Point::new(1.0, 1.0)));
+        assert!(!contains(&reversed, Point::new(3.0, 3.0)));
+    }
+
+    #[test]
+    fn test_tolerance_is_relative() {
+        let tri = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4e9, 0.0),
+            Point::new(0.0, 3e9),
+        );
+        // A rounding error far below the triangle's scale stays on the edge.
+        let on_edge = Point::new(2e9, 1.5e9 + 0.5);
+        assert_eq!(
+            query_point(on_edge, &tri).containment,
+            Containment::OnEdge(Edge::Bc)
+        );
+
+        let tiny = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4e-9, 0.0),
+            Point::new(0.0, 3e-9),
+        );
+        assert_eq!(
+            query_point(Point::new(1e-9, 1e-9), &tiny).containment,
+            Containment::Inside
+        );
+    }
+
+    #[test]
+    fn test_exact_edges_agree_with_collinearity() {
+        // A long, thin triangle: the midpoint of AB lies exactly on it even
+        // though no tolerance is allowed.
+        let tri = Triangle::new(
+            Point::new(1.0, 3.0),
+            Point::new(1e6 + 1.0, 3e6 + 3.0),
+            Point::new(1.0, 3.001),
+        );
+        let exact = Tolerance::relative(0.0);
+        for (offset, expected) in [
+            (0.0, Containment::OnEdge(Edge::Ab)),
+            (1e-6, Containment::Inside),
+            (-1e-6, Containment::Outside),
+        ] {
+            let point = Point::new(0.5e6 + 1.0, 1.5e6 + 3.0 + offset);
+            assert_eq!(
+                query_point_with(point, &tri, exact).containment,
+                expected,
+                "{offset}"
+            );
+            let collinear = check_collinear(&[tri.a, tri.b, point], exact).is_err();
+            assert_eq!(collinear, expected == Containment::OnEdge(Edge::Ab));
+        }
+    }
+
+    #[test]
+    fn test_closest_point_and_signed_distance() {
+        let tri = right_triangle();
+
+        let inside = query_point(Point::new(1.0, 0.5), &tri);
+        assert!(approx_eq_pt(inside.closest, Point::new(1.0, 0.0)));
+        assert!(approx_eq(inside.signed_distance, -0.5));
+
+        // Beyond a vertex the corner is the nearest point.
+        let corner = query_point(Point::new(5.0, -1.0), &tri);
+        assert!(approx_eq_pt(corner.closest, Point::new(4.0, 0.0)));
+        assert!(approx_eq(corner.signed_distance, 2.0f64.sqrt()));
+
+        // Beyond the hypotenuse the projection onto it.
+        let beyond = query_point(Point::new(4.0, 3.0), &tri);
+        assert!(approx_eq_pt(beyond.closest, Point::new(2.56, 1.08)));
+        assert!(approx_eq(beyond.signed_distance, 2.4));
+
+        let on_vertex = query_point(Point::new(0.0, 0.0), &tri);
+        assert!(approx_eq(on_vertex.signed_distance, 0.0));
+    }
+
+    #[test]
+    fn test_degenerate_triangle_has_no_inside() {
+        let flat = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+        );
+        assert_eq!(
+            query_point(Point::new(1.5, 0.0), &flat).containment,
+            Containment::OnEdge(Edge::Bc)
+        );
+        assert_eq!(
+            query_point(Point::new(1.0, 1.0), &flat).containment,
+            Containment::Outside
+        );
+    }
+}
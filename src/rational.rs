@@ -0,0 +1,384 @@
+//! Exact fractions of arbitrary-precision integers.
+//!
+//! A [`Rational`] is always kept in lowest terms with a positive denominator,
+//! so equal values have equal representations and derived equality is exact.
+
+use crate::bigint::BigInt;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Decimal exponents beyond this are rejected when parsing, which keeps a
+/// typo such as `1e999999` from allocating a huge integer.
+const MAX_DECIMAL_EXPONENT: u32 = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    /// Always positive and coprime with the numerator.
+    denominator: BigInt,
+}
+
+impl Rational {
+    /// `numerator / denominator` in lowest terms, `None` for a zero
+    /// denominator.
+    #[must_use]
+    pub fn new(numerator: &BigInt, denominator: &BigInt) -> Option<Self> {
+        if denominator.is_zero() {
+            return None;
+        }
+        Some(if denominator.is_negative() {
+            Self::reduced(-numerator, -denominator)
+        } else {
+            Self::reduced(numerator.clone(), denominator.clone())
+        })
+    }
+
+    /// `numerator / denominator` in lowest terms for a denominator known to
+    /// be positive, such as the product of two denominators.
+    pub(crate) fn reduced(numerator: BigInt, denominator: BigInt) -> Self {
+        let gcd = numerator.gcd(&denominator);
+        // The gcd of a positive denominator is positive, so both divisions
+        // go through; were it zero the fraction would simply stay unreduced.
+        match (numerator.div_rem(&gcd), denominator.div_rem(&gcd)) {
+            (Some((numerator, _)), Some((denominator, _))) => Self {
+                numerator,
+                denominator,
+            },
+            _ => Self {
+                numerator,
+                denominator,
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn from_integer(value: i64) -> Self {
+        Self {
+            numerator: BigInt::from(value),
+            denominator: BigInt::one(),
+        }
+    }
+
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::from_integer(0)
+    }
+
+    /// The exact value of a finite `f64`, `None` for NaN and infinities.
+    #[must_use]
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        let bits = value.to_bits();
+        let biased = i64::try_from((bits >> 52) & 0x7ff).ok()?;
+        let fraction = bits & ((1 << 52) - 1);
+        // Subnormals have no implicit leading bit and the smallest exponent.
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased - 1075)
+        };
+        let mantissa = BigInt::from(mantissa);
+        let mantissa = if value.is_sign_negative() {
+            -mantissa
+        } else {
+            mantissa
+        };
+        let shift = usize::try_from(exponent.unsigned_abs()).ok()?;
+        if exponent >= 0 {
+            Self::new(&mantissa.shl(shift), &BigInt::one())
+        } else {
+            Self::new(&mantissa, &BigInt::one().shl(shift))
+        }
+    }
+
+    #[must_use]
+    pub const fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    #[must_use]
+    pub const fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// `Less`, `Equal` or `Greater` as the value is negative, zero or positive.
+    #[must_use]
+    pub const fn signum(&self) -> Ordering {
+        self.numerator.signum()
+    }
+
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// `self / divisor`, `None` when `divisor` is zero.
+    #[must_use]
+    pub fn checked_div(&self, divisor: &Self) -> Option<Self> {
+        Self::new(
+            &(&self.numerator * &divisor.denominator),
+            &(&self.denominator * &divisor.numerator),
+        )
+    }
+
+    /// Parses an integer, a decimal such as `-1.25` or `2.5e-3`, or a
+    /// fraction of two of them such as `1/3` or `0.5/7`.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.split_once('/') {
+            Some((numerator, denominator)) => {
+                parse_decimal(numerator.trim())?.checked_div(&parse_decimal(denominator.trim())?)
+            }
+            None => parse_decimal(text.trim()),
+        }
+    }
+
+    /// The nearest `f64` up to a few units in the last place.
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        // Scale so the integer quotient carries at least 64 significant
+        // bits, then undo the scaling on the floating-point result.
+        let shift =
+            (self.denominator.bit_length() + 64).saturating_sub(self.numerator.bit_length());
+        let scaled = self.numerator.shl(shift);
+        let quotient = scaled
+            .div_rem(&self.denominator)
+            .map_or(0.0, |(quotient, _)| quotient.to_f64());
+        scale_by_power_of_two(quotient, shift)
+    }
+}
+
+/// `value / 2^exponent` without overflowing the intermediate power.
+fn scale_by_power_of_two(mut value: f64, mut exponent: usize) -> f64 {
+    const STEP: usize = 512;
+    let step_factor = 0.5f64.powi(512);
+    while exponent >= STEP && value != 0.0 {
+        value *= step_factor;
+        exponent -= STEP;
+    }
+    value * 0.5f64.powi(i32::try_from(exponent).unwrap_or(i32::MAX))
+}
+
+/// Parses `[+-]digits[.digits][e[+-]digits]`.
+fn parse_decimal(text: &str) -> Option<Rational> {
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(idx) => (&text[..idx], text[idx + 1..].parse::<i32>().ok()?),
+        None => (text, 0),
+    };
+    let (sign, unsigned) = match mantissa.as_bytes().first()? {
+        b'-' => ("-", &mantissa[1..]),
+        b'+' => ("", &mantissa[1..]),
+        _ => ("", mantissa),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let all_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if whole.len() + fraction.len() == 0 || !all_digits(whole) || !all_digits(fraction) {
+        return None;
+    }
+    let digits = BigInt::parse(&format!("{sign}{whole}{fraction}"))?;
+    let exponent = i64::from(exponent) - i64::try_from(fraction.len()).ok()?;
+    let power = u32::try_from(exponent.unsigned_abs())
+        .ok()
+        .filter(|power| *power <= MAX_DECIMAL_EXPONENT)?;
+    if exponent >= 0 {
+        Rational::new(&(&digits * &BigInt::pow10(power)), &BigInt::one())
+    } else {
+        Rational::new(&digits, &BigInt::pow10(power))
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl Add for &Rational {
+    type Output = Rational;
+
+    fn add(self, other: Self) -> Rational {
+        let numerator =
+            &(&self.numerator * &other.denominator) + &(&other.numerator * &self.denominator);
+        Rational::reduced(numerator, &self.denominator * &other.denominator)
+    }
+}
+
+impl Sub for &Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Self) -> Rational {
+        self + &-other
+    }
+}
+
+impl Mul for &Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Self) -> Rational {
+        let numerator = &self.numerator * &other.numerator;
+        Rational::reduced(numerator, &self.denominator * &other.denominator)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == BigInt::one() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn q(text: &str) -> Rational {
+        Rational::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_forms() {
+        assert_eq!(q("1/3").to_string(), "1/3");
+        assert_eq!(q("-6/4").to_string(), "-3/2");
+        assert_eq!(q("6/-4").to_string(), "-3/2");
+        assert_eq!(q("0.1").to_string(), "1/10");
+        assert_eq!(q("+2.50").to_string(), "5/2");
+        assert_eq!(q("-1.5e2").to_string(), "-150");
+        assert_eq!(q("25e-3").to_string(), "1/40");
+        assert_eq!(q(".5").to_string(), "1/2");
+        assert_eq!(q("0.5/7").to_string(), "1/14");
+        assert_eq!(q("0/5"), Rational::zero());
+        for bad in [
+            "", "1/0", "abc", "1/", "/2", "1.2.3", "1e", "inf", "NaN", "1e9999", "-",
+        ] {
+            assert_eq!(Rational::parse(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_exponent_cap_bounds_size() {
+        // The largest accepted exponents stay a few thousand bits wide, and
+        // arithmetic on them stays exact.
+        let huge = q("1e1000");
+        let tiny = q("-1e-1000");
+        assert_eq!(huge.numerator().bit_length(), 3322);
+        assert_eq!(tiny.denominator().bit_length(), 3322);
+        assert_eq!(&huge * &tiny, Rational::from_integer(-1));
+        let sum = &(&huge + &tiny) + &q("1/7");
+        assert_eq!(&(&sum - &huge) - &tiny, q("1/7"));
+        assert_eq!(Rational::parse("1e1001"), None);
+        assert_eq!(Rational::parse("1e-1001"), None);
+    }
+
+    #[test]
+    fn test_arithmetic_is_exact() {
+        let third = q("1/3");
+        let sum = &(&third + &third) + &third;
+        assert_eq!(sum, Rational::from(1));
+        // 0.1 + 0.2 is exactly 0.3 here.
+        assert_eq!(&q("0.1") + &q("0.2"), q("0.3"));
+        assert_eq!(&q("2/7") * &q("-7/4"), q("-1/2"));
+        assert_eq!(&q("1/2") - &q("3/4"), q("-1/4"));
+        assert_eq!(q("3/4").checked_div(&q("-3/8")), Some(Rational::from(-2)));
+        assert_eq!(q("1").checked_div(&Rational::zero()), None);
+        assert!(q("1/3") < q("0.3334") && q("-1/3") < q("-0.3333"));
+    }
+
+    #[test]
+    fn test_f64_conversion() {
+        for value in [
+            0.0,
+            1.0,
+            -0.1,
+            1.0 / 3.0,
+            1e300,
+            -2.5e-310,
+            f64::MIN_POSITIVE,
+        ] {
+            let exact = Rational::from_f64(value).unwrap();
+            assert_eq!(exact.to_f64().total_cmp(&value), Ordering::Equal, "{value}");
+        }
+        assert_eq!(Rational::from_f64(0.75), Some(q("3/4")));
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+        assert!((q("1/3").to_f64() - 1.0 / 3.0).abs() < 1e-16);
+        assert!((q("22/7e400").to_f64()).abs() < 1e-300);
+    }
+}
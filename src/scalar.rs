@@ -0,0 +1,509 @@
+//! Number types that points, triangles and the geometry functions work with.
+//!
+//! [`Scalar`] is what the rational constructions need: the field operations,
+//! comparison and a few exact sign tests. `f64`, `f32` and [`Rational`]
+//! implement it, so the centroid, circumcenter or the classifications of a
+//! `Triangle<f32>` or a `Triangle<Rational>` run through the same code as those
+//! of the default `Triangle<f64>`. [`Real`] adds the square roots and
+//! trigonometry behind side lengths, angles and the full
+//! [`TriangleSummary`](crate::geometry::TriangleSummary), and is implemented by
+//! the two floating-point types.
+
+use crate::bigint::BigInt;
+use crate::point::Point;
+use crate::predicates;
+use crate::rational::Rational;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The field operations, comparison and exact sign tests of a coordinate type.
+///
+/// Division goes through [`Self::checked_div`], so generic code dividing by a
+/// quantity that can vanish has to say what a missing quotient means. [`Real`]
+/// adds the plain `/` operator of the floating-point types.
+pub trait Scalar:
+    Clone
+    + Debug
+    + Display
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    #[must_use]
+    fn zero() -> Self;
+
+    #[must_use]
+    fn from_i32(value: i32) -> Self;
+
+    /// The nearest `f64`, used for tolerances and output.
+    #[must_use]
+    fn to_f64(&self) -> f64;
+
+    /// Whether the value is a number other than an infinity. Exact types
+    /// always are.
+    #[must_use]
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// `self * a + b`, with a single rounding where the type supports it.
+    #[must_use]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    /// `self / divisor`, `None` when `divisor` is zero.
+    #[must_use]
+    fn checked_div(self, divisor: Self) -> Option<Self>;
+
+    /// `self / 2`, which needs no check.
+    #[must_use]
+    fn half(self) -> Self;
+
+    /// `self / 3`, which needs no check.
+    #[must_use]
+    fn third(self) -> Self;
+
+    /// Sign of twice the signed area of `a b c`: `Greater` when the points
+    /// run counter-clockwise, `Equal` when they are collinear. `None` when the
+    /// sign cannot be decided, as for coordinates that are not finite.
+    ///
+    /// The default evaluates the cross product directly, which is exact for
+    /// exact types. Floating-point types override it with exact predicates.
+    #[must_use]
+    fn area_sign(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> Option<Ordering> {
+        let area = (b.clone() - a.clone()).cross(c.clone() - a.clone());
+        area.partial_cmp(&Self::zero())
+    }
+
+    /// Sign of `(p - vertex) . (q - vertex)`: `Greater` for an acute angle at
+    /// `vertex`, `Equal` for a right one and `Less` for an obtuse one, decided
+    /// in the same sense as [`Self::area_sign`].
+    #[must_use]
+    fn dot_sign(vertex: &Point<Self>, p: &Point<Self>, q: &Point<Self>) -> Option<Ordering> {
+        let dot = (p.clone() - vertex.clone()).dot(q.clone() - vertex.clone());
+        dot.partial_cmp(&Self::zero())
+    }
+
+    /// Compares the distances `|a b|` and `|c d|`, decided in the same sense
+    /// as [`Self::area_sign`].
+    #[must_use]
+    fn compare_distances(
+        a: &Point<Self>,
+        b: &Point<Self>,
+        c: &Point<Self>,
+        d: &Point<Self>,
+    ) -> Option<Ordering> {
+        let first = (b.clone() - a.clone()).length_sq();
+        let second = (d.clone() - c.clone()).length_sq();
+        first.partial_cmp(&second)
+    }
+}
+
+/// Scalars with square roots and trigonometry.
+pub trait Real: Scalar + Copy + Div<Output = Self> {
+    #[must_use]
+    fn from_f64(value: f64) -> Self;
+
+    #[must_use]
+    fn abs(self) -> Self;
+
+    #[must_use]
+    fn sqrt(self) -> Self;
+
+    /// `sqrt(self^2 + other^2)` without undue overflow or underflow.
+    #[must_use]
+    fn hypot(self, other: Self) -> Self;
+
+    #[must_use]
+    fn cos(self) -> Self;
+
+    #[must_use]
+    fn acos(self) -> Self;
+
+    #[must_use]
+    fn max(self, other: Self) -> Self;
+
+    #[must_use]
+    fn clamp(self, min: Self, max: Self) -> Self;
+}
+
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn from_i32(value: i32) -> Self {
+        Self::from(value)
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+
+    fn is_finite(&self) -> bool {
+        Self::is_finite(*self)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+
+    fn checked_div(self, divisor: Self) -> Option<Self> {
+        (divisor != 0.0).then(|| self / divisor)
+    }
+
+    fn half(self) -> Self {
+        self / 2.0
+    }
+
+    fn third(self) -> Self {
+        self / 3.0
+    }
+
+    fn area_sign(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> Option<Ordering> {
+        if !all_finite(&[a, b, c]) {
+            return None;
+        }
+        predicates::orient2d(*a, *b, *c).partial_cmp(&0.0)
+    }
+
+    fn dot_sign(vertex: &Point<Self>, p: &Point<Self>, q: &Point<Self>) -> Option<Ordering> {
+        if !all_finite(&[vertex, p, q]) {
+            return None;
+        }
+        predicates::dot_at(*vertex, *p, *q)
+    }
+
+    fn compare_distances(
+        a: &Point<Self>,
+        b: &Point<Self>,
+        c: &Point<Self>,
+        d: &Point<Self>,
+    ) -> Option<Ordering> {
+        if !all_finite(&[a, b, c, d]) {
+            return None;
+        }
+        predicates::compare_distances(*a, *b, *c, *d)
+    }
+}
+
+impl Real for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn abs(self) -> Self {
+        Self::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        Self::sqrt(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        Self::hypot(self, other)
+    }
+
+    fn cos(self) -> Self {
+        Self::cos(self)
+    }
+
+    fn acos(self) -> Self {
+        Self::acos(self)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::max(self, other)
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Self::clamp(self, min, max)
+    }
+}
+
+/// The exact predicates only hold for finite coordinates.
+fn all_finite(points: &[&Point]) -> bool {
+    points
+        .iter()
+        .all(|point| point.x.is_finite() && point.y.is_finite())
+}
+
+// Every `f32` widens to `f64` without rounding, so the `f64` predicates are
+// exact for `f32` input too.
+impl Scalar for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn from_i32(value: i32) -> Self {
+        value as Self
+    }
+
+    fn to_f64(&self) -> f64 {
+        f64::from(*self)
+    }
+
+    fn is_finite(&self) -> bool {
+        Self::is_finite(*self)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+
+    fn checked_div(self, divisor: Self) -> Option<Self> {
+        (divisor != 0.0).then(|| self / divisor)
+    }
+
+    fn half(self) -> Self {
+        self / 2.0
+    }
+
+    fn third(self) -> Self {
+        self / 3.0
+    }
+
+    fn area_sign(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> Option<Ordering> {
+        f64::area_sign(&a.to_f64(), &b.to_f64(), &c.to_f64())
+    }
+
+    fn dot_sign(vertex: &Point<Self>, p: &Point<Self>, q: &Point<Self>) -> Option<Ordering> {
+        f64::dot_sign(&vertex.to_f64(), &p.to_f64(), &q.to_f64())
+    }
+
+    fn compare_distances(
+        a: &Point<Self>,
+        b: &Point<Self>,
+        c: &Point<Self>,
+        d: &Point<Self>,
+    ) -> Option<Ordering> {
+        f64::compare_distances(&a.to_f64(), &b.to_f64(), &c.to_f64(), &d.to_f64())
+    }
+}
+
+impl Real for f32 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_f64(value: f64) -> Self {
+        value as Self
+    }
+
+    fn abs(self) -> Self {
+        Self::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        Self::sqrt(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        Self::hypot(self, other)
+    }
+
+    fn cos(self) -> Self {
+        Self::cos(self)
+    }
+
+    fn acos(self) -> Self {
+        Self::acos(self)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::max(self, other)
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Self::clamp(self, min, max)
+    }
+}
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    fn from_i32(value: i32) -> Self {
+        Self::from_integer(i64::from(value))
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.to_f64()
+    }
+
+    fn checked_div(self, divisor: Self) -> Option<Self> {
+        Self::checked_div(&self, &divisor)
+    }
+
+    fn half(self) -> Self {
+        Self::reduced(
+            self.numerator().clone(),
+            self.denominator() * &BigInt::from(2u32),
+        )
+    }
+
+    fn third(self) -> Self {
+        Self::reduced(
+            self.numerator().clone(),
+            self.denominator() * &BigInt::from(3u32),
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::errors::Error;
+    use crate::geometry::{
+        AngleClassification, SideClassification, centroid, circumcenter, classify_angles_exact,
+        classify_sides_exact, compute_summary, excenter, foot_on_line,
+    };
+    use crate::tolerance::Tolerance;
+    use crate::triangle::{
+        DegenerateTriangleError, Triangle, TriangleCreateError, build_triangle_with,
+    };
+
+    #[test]
+    fn test_f32_summary_matches_f64() {
+        let single: Triangle<f32> = Triangle::from([0.0, 0.0, 4.0, 0.0, 0.0, 3.0]);
+        let summary = compute_summary(&single);
+        assert!((summary.area - 6.0).abs() < 1e-6);
+        assert!((summary.circumradius - 2.5).abs() < 1e-6);
+        assert_eq!(summary.angle_class, AngleClassification::Right);
+        assert_eq!(summary.side_class, SideClassification::Scalene);
+
+        let double = compute_summary(&single.to_f64());
+        let widened = summary.to_f64();
+        assert!((widened.angle_a_rad - double.angle_a_rad).abs() < 1e-6);
+        assert!((widened.circumcenter.x - double.circumcenter.x).abs() < 1e-6);
+        let text = summary.to_string();
+        assert_eq!(text, widened.to_string());
+        assert!(text.contains("TRIANGLE SUMMARY") && text.contains("Right"));
+    }
+
+    #[test]
+    fn test_non_finite_signs_are_undecided() {
+        let tri: Triangle<f32> = Triangle::from([0.0, 0.0, 1.0, 0.0, 0.0, f32::NAN]);
+        assert_eq!(classify_angles_exact(&tri), None);
+        assert_eq!(classify_sides_exact(&tri), None);
+        let [a, b, c] = [tri.a, tri.b, tri.c];
+        assert_eq!(f32::area_sign(&a, &b, &c), None);
+        let far = Point::new(f64::INFINITY, 0.0);
+        assert_eq!(f64::dot_sign(&Point::zero(), &far, &Point::one()), None);
+    }
+
+    #[test]
+    fn test_validation_for_every_scalar() {
+        let tolerance = Tolerance::default();
+        let single = [
+            Point::new(0f32, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        ];
+        assert_eq!(
+            build_triangle_with(single, tolerance).unwrap(),
+            Triangle::from(single)
+        );
+        let flat = [
+            Point::new(0f32, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ];
+        assert!(matches!(
+            build_triangle_with(flat, tolerance),
+            Err(Error::DegenerateTriangle(
+                DegenerateTriangleError::Collinear { .. }
+            ))
+        ));
+        let broken = [
+            Point::new(0f32, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, f32::NAN),
+        ];
+        assert!(matches!(
+            build_triangle_with(broken, tolerance),
+            Err(Error::TriangleCreate(
+                TriangleCreateError::InvalidFormat { .. }
+            ))
+        ));
+
+        // Distinct rational points that round to the same f64 point are
+        // nearly, not exactly, duplicate.
+        let q = |text: &str| Rational::parse(text).unwrap();
+        let point = |x, y| Point::new(q(x), q(y));
+        let close = [
+            point("0", "0"),
+            point("1", "1"),
+            point("1.000000000000000000001", "1"),
+        ];
+        assert!(matches!(
+            build_triangle_with(close, tolerance),
+            Err(Error::TriangleCreate(
+                TriangleCreateError::NearlyDuplicatePoint { .. }
+            ))
+        ));
+        let exact = [point("0", "0"), point("1/3", "0"), point("0", "1/7")];
+        assert!(build_triangle_with(exact, tolerance).is_ok());
+    }
+
+    #[test]
+    fn test_f32_classification_is_exact() {
+        // The legs from A have a dot product of 2^-24, which f32 arithmetic
+        // rounds away, yet the angle is still seen as acute.
+        let t = 2f32.powi(-12);
+        let tri = Triangle::from([0.0, 0.0, 1.0, 1.0 + t, -(1.0 + t + t), 1.0 + t]);
+        assert_eq!((tri.b - tri.a).dot(tri.c - tri.a), 0.0);
+        assert_eq!(
+            classify_angles_exact(&tri),
+            Some(AngleClassification::Acute)
+        );
+    }
+
+    #[test]
+    fn test_rational_shares_the_geometry() {
+        let q = |text: &str| Rational::parse(text).unwrap();
+        let tri = Triangle::new(
+            Point::new(q("0"), q("0")),
+            Point::new(q("1/2"), q("0")),
+            Point::new(q("0"), q("1/3")),
+        );
+        assert_eq!(
+            circumcenter(tri.a.clone(), tri.b.clone(), tri.c.clone()),
+            Some(Point::new(q("1/4"), q("1/6")))
+        );
+        assert_eq!(
+            centroid(tri.a.clone(), tri.b.clone(), tri.c.clone()),
+            Point::new(q("1/6"), q("1/9"))
+        );
+        assert_eq!(
+            classify_angles_exact(&tri),
+            Some(AngleClassification::Right)
+        );
+        assert_eq!(
+            classify_sides_exact(&tri),
+            Some(SideClassification::Scalene)
+        );
+        assert_eq!(tri.twice_signed_area(), q("1/6"));
+    }
+
+    #[test]
+    fn test_degenerate_rational_input_has_no_quotient() {
+        let q = |text: &str| Rational::parse(text).unwrap();
+        let [a, b, c] = ["0", "1", "2"].map(|value| Point::new(q(value), q(value)));
+        assert_eq!(circumcenter(a.clone(), b.clone(), c.clone()), None);
+        assert_eq!(foot_on_line(c.clone(), a.clone(), a.clone()), None);
+        assert_eq!(
+            excenter(a.clone(), b.clone(), c, q("2"), q("1"), q("1")),
+            None
+        );
+        assert_eq!(q("1").checked_div(Rational::zero()), None);
+        assert_eq!(1.0.checked_div(0.0), None);
+        assert_eq!(q("1/2").third(), q("1/6"));
+        assert_eq!(centroid(a, b.clone(), b).to_string(), "(2/3, 2/3)");
+    }
+}
@@ -0,0 +1,675 @@
+use crate::point::Point;
+use crate::tolerance::Tolerance;
+use crate::triangle::{DegenerateTriangleError, Triangle, check_collinear, is_valid_triangle};
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter};
+
+/// A triangle described by measurements instead of coordinates.
+///
+/// Sides follow the usual convention: `a` is opposite vertex A (side BC),
+/// `b` is opposite B (side AC) and `c` is opposite C (side AB). Angles are
+/// in radians and named after their vertex.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SolveInput {
+    Sss { a: f64, b: f64, c: f64 },
+    Sas { b: f64, angle_a: f64, c: f64 },
+    Asa { angle_a: f64, c: f64, angle_b: f64 },
+    Aas { angle_a: f64, angle_b: f64, a: f64 },
+}
+
+impl SolveInput {
+    /// Constructs the triangle in canonical placement: A at the origin, B on
+    /// the positive x-axis and C above it.
+    ///
+    /// # Errors
+    /// Returns a [`SolveError`] describing why the measurements cannot form a triangle.
+    pub fn solve(self) -> Result<Triangle, SolveError> {
+        self.solve_with(Tolerance::default())
+    }
+
+    /// [`Self::solve`], rejecting results that are collinear within
+    /// `tolerance`.
+    ///
+    /// # Errors
+    /// Returns a [`SolveError`] describing why the measurements cannot form a triangle.
+    pub fn solve_with(self, tolerance: Tolerance) -> Result<Triangle, SolveError> {
+        match self {
+            Self::Sss { a, b, c } => Ok(place_sss_with(a, b, c, tolerance)?),
+            Self::Sas { b, angle_a, c } => place_sas_with(b, angle_a, c, tolerance),
+            Self::Asa {
+                angle_a,
+                c,
+                angle_b,
+            } => place_asa_with(angle_a, c, angle_b, tolerance),
+            Self::Aas {
+                angle_a,
+                angle_b,
+                a,
+            } => place_aas_with(angle_a, angle_b, a, tolerance),
+        }
+    }
+}
+
+/// Reasons why measurements do not describe a triangle.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum SolveError {
+    NonPositiveSide { name: &'static str, value: f64 },
+    AngleOutOfRange { name: &'static str, value: f64 },
+    AngleSumTooLarge { sum: f64 },
+    Degenerate(DegenerateTriangleError),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonPositiveSide { name, value } => {
+                write!(f, "Side {name} must be a positive length, got {value}")
+            }
+            Self::AngleOutOfRange { name, value } => write!(
+                f,
+                "Angle {name} must lie strictly between 0° and 180°, got {:.6}°",
+                value.to_degrees()
+            ),
+            Self::AngleSumTooLarge { sum } => write!(
+                f,
+                "The given angles add up to {:.6}°, leaving nothing for the third angle",
+                sum.to_degrees()
+            ),
+            Self::Degenerate(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Degenerate(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<DegenerateTriangleError> for SolveError {
+    fn from(err: DegenerateTriangleError) -> Self {
+        Self::Degenerate(err)
+    }
+}
+
+fn check_side(name: &'static str, value: f64) -> Result<(), SolveError> {
+    if value.is_finite() && value > 0.0 {
+        return Ok(());
+    }
+    Err(SolveError::NonPositiveSide { name, value })
+}
+
+fn check_angle(name: &'static str, value: f64) -> Result<(), SolveError> {
+    if value.is_finite() && value > 0.0 && value < PI {
+        return Ok(());
+    }
+    Err(SolveError::AngleOutOfRange { name, value })
+}
+
+/// Returns the third angle of a triangle with angles `first` and `second`.
+fn third_angle(first: f64, second: f64) -> Result<f64, SolveError> {
+    let sum = first + second;
+    if sum >= PI {
+        return Err(SolveError::AngleSumTooLarge { sum });
+    }
+    Ok(PI - sum)
+}
+
+/// Builds the canonical triangle from the two sides at A and the angle between them.
+fn place_at_origin(
+    b: f64,
+    angle_a: f64,
+    c: f64,
+    tolerance: Tolerance,
+) -> Result<Triangle, SolveError> {
+    let (sin_a, cos_a) = angle_a.sin_cos();
+    let points = [
+        Point::zero(),
+        Point::new(c, 0.0),
+        Point::new(b * cos_a, b * sin_a),
+    ];
+    check_collinear(&points, tolerance)?;
+    Ok(Triangle::from(points))
+}
+
+/// Area from three sides using Kahan's rearrangement of Heron's formula,
+/// which stays accurate for needle-like triangles.
+fn heron_area(a: f64, b: f64, c: f64) -> f64 {
+    let mut sides = [a, b, c];
+    sides.sort_by(|lhs, rhs| rhs.total_cmp(lhs));
+    let [a, b, c] = sides;
+    let product = (a + (b + c)) * (c - (a - b)) * (c + (a - b)) * (a + (b - c));
+    0.25 * product.max(0.0).sqrt()
+}
+
+/// Places a triangle with the given side lengths canonically: A at the
+/// origin, B on the positive x-axis and C above it.
+///
+/// # Errors
+/// Returns [`DegenerateTriangleError::InequalityViolation`] when the sides are
+/// not positive finite lengths satisfying the triangle inequality, and
+/// [`DegenerateTriangleError::Collinear`] when they are too close to it to
+/// produce three distinct non-collinear points.
+pub fn place_sss(a: f64, b: f64, c: f64) -> Result<Triangle, DegenerateTriangleError> {
+    place_sss_with(a, b, c, Tolerance::default())
+}
+
+/// [`place_sss`] with the collinearity check done within `tolerance`.
+///
+/// # Errors
+/// As for [`place_sss`].
+pub fn place_sss_with(
+    a: f64,
+    b: f64,
+    c: f64,
+    tolerance: Tolerance,
+) -> Result<Triangle, DegenerateTriangleError> {
+    is_valid_triangle(a, b, c)?;
+
+    let cx = c.mul_add(c, b.mul_add(b, -(a * a))) / (2.0 * c);
+    let cy = 2.0 * heron_area(a, b, c) / c;
+
+    let points = [Point::zero(), Point::new(c, 0.0), Point::new(cx, cy)];
+    check_collinear(&points, tolerance)?;
+    Ok(Triangle::from(points))
+}
+
+/// Places a triangle from sides `b` (AC) and `c` (AB) and the included angle A.
+///
+/// # Errors
+/// Returns a [`SolveError`] when a side is not positive or the angle is not
+/// strictly between 0 and π.
+pub fn place_sas(b: f64, angle_a: f64, c: f64) -> Result<Triangle, SolveError> {
+    place_sas_with(b, angle_a, c, Tolerance::default())
+}
+
+/// [`place_sas`] with the collinearity check done within `tolerance`.
+///
+/// # Errors
+/// As for [`place_sas`].
+pub fn place_sas_with(
+    b: f64,
+    angle_a: f64,
+    c: f64,
+    tolerance: Tolerance,
+) -> Result<Triangle, SolveError> {
+    check_side("b", b)?;
+    check_side("c", c)?;
+    check_angle("A", angle_a)?;
+    place_at_origin(b, angle_a, c, tolerance)
+}
+
+/// Places a triangle from angles A and B and the side `c` (AB) between them.
+///
+/// # Errors
+/// Returns a [`SolveError`] when the side is not positive, an angle is out of
+/// range or the two angles leave no room for the third.
+pub fn place_asa(angle_a: f64, c: f64, angle_b: f64) -> Result<Triangle, SolveError> {
+    place_asa_with(angle_a, c, angle_b, Tolerance::default())
+}
+
+/// [`place_asa`] with the collinearity check done within `tolerance`.
+///
+/// # Errors
+/// As for [`place_asa`].
+pub fn place_asa_with(
+    angle_a: f64,
+    c: f64,
+    angle_b: f64,
+    tolerance: Tolerance,
+) -> Result<Triangle, SolveError> {
+    check_angle("A", angle_a)?;
+    check_angle("B", angle_b)?;
+    check_side("c", c)?;
+    let angle_c = third_angle(angle_a, angle_b)?;
+    // Law of sines: b / sin B = c / sin C
+    let b = c * angle_b.sin() / angle_c.sin();
+    place_at_origin(b, angle_a, c, tolerance)
+}
+
+/// Places a triangle from angles A and B and the side `a` (BC) opposite A.
+///
+/// # Errors
+/// Returns a [`SolveError`] when the side is not positive, an angle is out of
+/// range or the two angles leave no room for the third.
+pub fn place_aas(angle_a: f64, angle_b: f64, a: f64) -> Result<Triangle, SolveError> {
+    place_aas_with(angle_a, angle_b, a, Tolerance::default())
+}
+
+/// [`place_aas`] with the collinearity check done within `tolerance`.
+///
+/// # Errors
+/// As for [`place_aas`].
+pub fn place_aas_with(
+    angle_a: f64,
+    angle_b: f64,
+    a: f64,
+    tolerance: Tolerance,
+) -> Result<Triangle, SolveError> {
+    check_angle("A", angle_a)?;
+    check_angle("B", angle_b)?;
+    check_side("a", a)?;
+    let angle_c = third_angle(angle_a, angle_b)?;
+    // Law of sines: a / sin A = b / sin B = c / sin C
+    let ratio = a / angle_a.sin();
+    place_at_origin(
+        ratio * angle_b.sin(),
+        angle_a,
+        ratio * angle_c.sin(),
+        tolerance,
+    )
+}
+
+/// Which of the possible outcomes of the ambiguous SSA case occurred.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SsaCase {
+    /// Side `a` is too short to reach the ray from A.
+    NoSolution,
+    /// Side `a` exactly equals the height from C, giving one right triangle.
+    RightTriangle,
+    /// Side `a` is long enough that only one triangle fits.
+    OneSolution,
+    /// Side `a` swings to two positions, giving an acute and an obtuse B.
+    TwoSolutions,
+}
+
+impl Display for SsaCase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSolution => write!(f, "no triangle satisfies the input"),
+            Self::RightTriangle => write!(f, "exactly one triangle, right-angled at B"),
+            Self::OneSolution => write!(f, "exactly one triangle"),
+            Self::TwoSolutions => write!(f, "two triangles satisfy the input"),
+        }
+    }
+}
+
+impl SsaCase {
+    /// Stable identifier used in machine readable output.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::NoSolution => "no_solution",
+            Self::RightTriangle => "right_triangle",
+            Self::OneSolution => "one_solution",
+            Self::TwoSolutions => "two_solutions",
+        }
+    }
+}
+
+/// Two sides and a non-included angle: angle A, the side `a` opposite it and
+/// the side `b` adjacent to it. Angles are in radians.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SsaInput {
+    pub angle_a: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl SsaInput {
+    /// See [`solve_ssa`].
+    ///
+    /// # Errors
+    /// Returns a [`SolveError`] when the input is out of range.
+    pub fn solve(self) -> Result<SsaSolution, SolveError> {
+        solve_ssa(self.angle_a, self.a, self.b)
+    }
+
+    /// See [`solve_ssa_with`].
+    ///
+    /// # Errors
+    /// Returns a [`SolveError`] when the input is out of range.
+    pub fn solve_with(self, tolerance: Tolerance) -> Result<SsaSolution, SolveError> {
+        solve_ssa_with(self.angle_a, self.a, self.b, tolerance)
+    }
+}
+
+/// Every triangle matching an SSA input, in canonical placement.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SsaSolution {
+    pub case: SsaCase,
+    pub triangles: Vec<Triangle>,
+}
+
+/// Solves the ambiguous case: angle A, the side `a` opposite it and the side
+/// `b` adjacent to it.
+///
+/// Depending on how `a` compares with `b` and with the
+/// height `b sin A`, zero, one or two triangles exist; all of them are
+/// returned, with the acute-B triangle first.
+///
+/// # Errors
+/// Returns a [`SolveError`] when a side is not positive or the angle is not
+/// strictly between 0 and π. An input that simply has no solution is not an
+/// error and is reported as [`SsaCase::NoSolution`].
+pub fn solve_ssa(angle_a: f64, a: f64, b: f64) -> Result<SsaSolution, SolveError> {
+    solve_ssa_with(angle_a, a, b, Tolerance::default())
+}
+
+/// [`solve_ssa`] with the borderline cases decided within `tolerance`,
+/// relative to the longer of the two sides.
+///
+/// # Errors
+/// As for [`solve_ssa`].
+pub fn solve_ssa_with(
+    angle_a: f64,
+    a: f64,
+    b: f64,
+    tolerance: Tolerance,
+) -> Result<SsaSolution, SolveError> {
+    check_angle("A", angle_a)?;
+    check_side("a", a)?;
+    check_side("b", b)?;
+
+    let height = b * angle_a.sin();
+    let sin_b = height / a;
+    let acute_b = sin_b.min(1.0).asin();
+    // Both comparisons are relative to the sides, so scaled copies of an
+    // input fall into the same case.
+    let scale = a.max(b);
+    let equal_sides = tolerance.is_negligible(a - b, scale);
+
+    let mut angles_b = Vec::with_capacity(2);
+    let case = if a > b && !equal_sides {
+        angles_b.push(acute_b);
+        SsaCase::OneSolution
+    } else if angle_a >= PI / 2.0 || equal_sides {
+        // With a ≈ b, B ≈ A and the second candidate would collapse to a segment.
+        if angle_a < PI / 2.0 {
+            angles_b.push(acute_b);
+            SsaCase::OneSolution
+        } else {
+            SsaCase::NoSolution
+        }
+    } else if tolerance.is_negligible(a - height, scale) {
+        angles_b.push(PI / 2.0);
+        SsaCase::RightTriangle
+    } else if a < height {
+        SsaCase::NoSolution
+    } else {
+        angles_b.push(acute_b);
+        angles_b.push(PI - acute_b);
+        SsaCase::TwoSolutions
+    };
+
+    let triangles = angles_b
+        .into_iter()
+        .map(|angle_b| {
+            let angle_c = third_angle(angle_a, angle_b)?;
+            // Law of sines: c / sin C = a / sin A
+            let c = a * angle_c.sin() / angle_a.sin();
+            place_at_origin(b, angle_a, c, tolerance)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SsaSolution { case, triangles })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::{AngleClassification, SideClassification, compute_summary};
+    use crate::test_support::approx_eq;
+
+    fn sides_of(tri: &Triangle) -> (f64, f64, f64) {
+        (
+            tri.b.distance_to(tri.c),
+            tri.a.distance_to(tri.c),
+            tri.a.distance_to(tri.b),
+        )
+    }
+
+    #[test]
+    fn test_sss_canonical_placement() {
+        let tri = place_sss(5.0, 3.0, 4.0).unwrap();
+
+        assert_eq!(tri.a, Point::zero());
+        assert_eq!(tri.b, Point::new(4.0, 0.0));
+        assert!(approx_eq(tri.c.x, 0.0) && approx_eq(tri.c.y, 3.0));
+
+        let s = compute_summary(&tri);
+        assert_eq!(s.angle_class, AngleClassification::Right);
+        assert!(approx_eq(s.area, 6.0));
+    }
+
+    #[test]
+    fn test_sss_preserves_side_lengths() {
+        for (a, b, c) in [
+            (7.0, 8.0, 9.0),
+            (10.0, 6.0, 5.0),
+            (1e-3, 1e-3, 1e-3),
+            (2.0, 3.0, 4.0),
+        ] {
+            let tri = SolveInput::Sss { a, b, c }.solve().unwrap();
+            let (ra, rb, rc) = sides_of(&tri);
+            assert!(approx_eq(ra, a) && approx_eq(rb, b) && approx_eq(rc, c));
+            assert!(tri.c.y > 0.0, "C must lie above AB");
+        }
+    }
+
+    #[test]
+    fn test_sss_equilateral() {
+        let tri = place_sss(2.0, 2.0, 2.0).unwrap();
+        assert_eq!(
+            compute_summary(&tri).side_class,
+            SideClassification::Equilateral
+        );
+    }
+
+    fn assert_triangle(tri: &Triangle, sides: (f64, f64, f64), angles_deg: (f64, f64, f64)) {
+        let s = compute_summary(tri);
+        assert!(approx_eq(s.side_a, sides.0), "a = {}", s.side_a);
+        assert!(approx_eq(s.side_b, sides.1), "b = {}", s.side_b);
+        assert!(approx_eq(s.side_c, sides.2), "c = {}", s.side_c);
+        assert!(approx_eq(s.angle_a_rad.to_degrees(), angles_deg.0));
+        assert!(approx_eq(s.angle_b_rad.to_degrees(), angles_deg.1));
+        assert!(approx_eq(s.angle_c_rad.to_degrees(), angles_deg.2));
+        assert_eq!(tri.a, Point::zero());
+        assert!(tri.b.y == 0.0 && tri.b.x > 0.0 && tri.c.y > 0.0);
+    }
+
+    #[test]
+    fn test_sas_asa_aas_agree_on_3_4_5() {
+        let angle_b = 3.0f64.atan2(4.0);
+        let angle_c = 4.0f64.atan2(3.0);
+        let sides = (5.0, 3.0, 4.0);
+        let angles = (90.0, angle_b.to_degrees(), angle_c.to_degrees());
+        let right = PI / 2.0;
+
+        assert_triangle(&place_sas(3.0, right, 4.0).unwrap(), sides, angles);
+        assert_triangle(&place_asa(right, 4.0, angle_b).unwrap(), sides, angles);
+        assert_triangle(&place_aas(right, angle_b, 5.0).unwrap(), sides, angles);
+    }
+
+    #[test]
+    fn test_solvers_match_sss() {
+        let sss = compute_summary(&place_sss(7.0, 8.0, 9.0).unwrap());
+        let inputs = [
+            SolveInput::Sas {
+                b: sss.side_b,
+                angle_a: sss.angle_a_rad,
+                c: sss.side_c,
+            },
+            SolveInput::Asa {
+                angle_a: sss.angle_a_rad,
+                c: sss.side_c,
+                angle_b: sss.angle_b_rad,
+            },
+            SolveInput::Aas {
+                angle_a: sss.angle_a_rad,
+                angle_b: sss.angle_b_rad,
+                a: sss.side_a,
+            },
+        ];
+        for input in inputs {
+            let tri = input.solve().unwrap();
+            assert!(approx_eq(tri.c.x, sss.vertex_c.x), "{input:?}");
+            assert!(approx_eq(tri.c.y, sss.vertex_c.y), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_obtuse_sas() {
+        let tri = place_sas(1.0, 120f64.to_radians(), 1.0).unwrap();
+        assert!(tri.c.x < 0.0);
+        assert!(approx_eq(tri.b.distance_to(tri.c), 3.0f64.sqrt()));
+    }
+
+    #[test]
+    fn test_impossible_inputs() {
+        assert_eq!(
+            place_sas(0.0, 1.0, 1.0),
+            Err(SolveError::NonPositiveSide {
+                name: "b",
+                value: 0.0
+            })
+        );
+        assert_eq!(
+            place_sas(1.0, PI, 1.0),
+            Err(SolveError::AngleOutOfRange {
+                name: "A",
+                value: PI
+            })
+        );
+        assert!(matches!(
+            place_asa(-0.1, 1.0, 1.0),
+            Err(SolveError::AngleOutOfRange { name: "A", .. })
+        ));
+        assert!(matches!(
+            place_asa(1.0, f64::NAN, 1.0),
+            Err(SolveError::NonPositiveSide { name: "c", .. })
+        ));
+        assert!(matches!(
+            place_asa(2.0, 1.0, 1.5),
+            Err(SolveError::AngleSumTooLarge { .. })
+        ));
+        assert!(matches!(
+            place_aas(PI / 2.0, PI / 2.0, 1.0),
+            Err(SolveError::AngleSumTooLarge { .. })
+        ));
+        assert!(matches!(
+            place_aas(1.0, 1.0, -2.0),
+            Err(SolveError::NonPositiveSide { name: "a", .. })
+        ));
+        assert!(matches!(
+            SolveInput::Sss {
+                a: 1.0,
+                b: 1.0,
+                c: 3.0
+            }
+            .solve(),
+            Err(SolveError::Degenerate(_))
+        ));
+    }
+
+    fn ssa(angle_a_deg: f64, a: f64, b: f64) -> SsaSolution {
+        solve_ssa(angle_a_deg.to_radians(), a, b).unwrap()
+    }
+
+    #[test]
+    fn test_ssa_two_solutions() {
+        let solution = ssa(30.0, 3.0, 5.0);
+        assert_eq!(solution.case, SsaCase::TwoSolutions);
+        assert_eq!(solution.triangles.len(), 2);
+
+        let angles_b: Vec<f64> = solution
+            .triangles
+            .iter()
+            .map(|tri| compute_summary(tri).angle_b_rad.to_degrees())
+            .collect();
+        let expected = (5.0 * 0.5 / 3.0f64).asin().to_degrees();
+        assert!(approx_eq(angles_b[0], expected));
+        assert!(approx_eq(angles_b[1], 180.0 - expected));
+
+        for tri in &solution.triangles {
+            let s = compute_summary(tri);
+            assert!(approx_eq(s.side_a, 3.0) && approx_eq(s.side_b, 5.0));
+            assert!(approx_eq(s.angle_a_rad.to_degrees(), 30.0));
+        }
+    }
+
+    #[test]
+    fn test_ssa_single_solutions() {
+        // a ≥ b leaves room for one triangle only.
+        let solution = ssa(30.0, 6.0, 5.0);
+        assert_eq!(solution.case, SsaCase::OneSolution);
+        assert_eq!(solution.triangles.len(), 1);
+
+        let solution = ssa(40.0, 5.0, 5.0);
+        assert_eq!(solution.case, SsaCase::OneSolution);
+        assert_eq!(
+            compute_summary(&solution.triangles[0]).side_class,
+            SideClassification::Isosceles
+        );
+
+        // a equal to the height b sin A touches the ray once.
+        let solution = ssa(30.0, 2.5, 5.0);
+        assert_eq!(solution.case, SsaCase::RightTriangle);
+        assert_eq!(
+            compute_summary(&solution.triangles[0]).angle_class,
+            AngleClassification::Right
+        );
+
+        // Obtuse A needs a longer than b.
+        let solution = ssa(120.0, 7.0, 3.0);
+        assert_eq!(solution.case, SsaCase::OneSolution);
+        assert!(approx_eq(
+            compute_summary(&solution.triangles[0]).side_a,
+            7.0
+        ));
+    }
+
+    #[test]
+    fn test_ssa_no_solution() {
+        for (angle, a, b) in [(30.0, 2.0, 5.0), (120.0, 3.0, 7.0), (90.0, 5.0, 5.0)] {
+            let solution = ssa(angle, a, b);
+            assert_eq!(solution.case, SsaCase::NoSolution, "{angle} {a} {b}");
+            assert!(solution.triangles.is_empty());
+        }
+        assert!(matches!(
+            solve_ssa(PI, 1.0, 1.0),
+            Err(SolveError::AngleOutOfRange { .. })
+        ));
+        assert!(matches!(
+            solve_ssa(1.0, 0.0, 1.0),
+            Err(SolveError::NonPositiveSide { name: "a", .. })
+        ));
+    }
+
+    #[test]
+    fn test_ssa_cases_do_not_depend_on_scale() {
+        for (angle, a, b, case) in [
+            (30.0, 1.0, 1.5, SsaCase::TwoSolutions),
+            (30.0, 6.0, 5.0, SsaCase::OneSolution),
+            (40.0, 5.0, 5.0, SsaCase::OneSolution),
+            (30.0, 2.5, 5.0, SsaCase::RightTriangle),
+            (30.0, 2.0, 5.0, SsaCase::NoSolution),
+        ] {
+            for scale in [1e-10, 1.0, 1e10] {
+                let solution = ssa(angle, a * scale, b * scale);
+                assert_eq!(solution.case, case, "{angle} {a} {b} at {scale}");
+                for tri in &solution.triangles {
+                    let side_a = tri.b.distance_to(tri.c);
+                    assert!((side_a / scale - a).abs() < 1e-9, "{side_a} at {scale}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sss_rejects_impossible_sides() {
+        assert!(place_sss(1.0, 1.0, 2.0).is_err());
+        assert!(place_sss(1.0, 1.0, 3.0).is_err());
+        assert!(place_sss(0.0, 1.0, 1.0).is_err());
+        assert!(place_sss(-3.0, 4.0, 5.0).is_err());
+        assert!(place_sss(f64::NAN, 4.0, 5.0).is_err());
+        assert!(place_sss(f64::INFINITY, 4.0, 5.0).is_err());
+    }
+}
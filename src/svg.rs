@@ -0,0 +1,334 @@
+use std::fmt::Write;
+use triale::geometry::TriangleSummary;
+use triale::point::Point;
+use triale::primitives::{Circle, Segment};
+
+/// Width and height of the generated image in SVG user units.
+const CANVAS: f64 = 800.0;
+const MARGIN: f64 = 48.0;
+
+/// Optional cevians drawn on top of the triangle.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct SvgLayers {
+    pub medians: bool,
+    pub altitudes: bool,
+    pub bisectors: bool,
+}
+
+impl SvgLayers {
+    pub const NAMES: &'static str = "medians, altitudes, bisectors";
+
+    /// Parses a comma separated list such as `medians,altitudes`.
+    ///
+    /// # Errors
+    /// Returns the first name that is not a known layer.
+    pub fn from_list(list: &str) -> Result<Self, String> {
+        let mut layers = Self::default();
+        for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            match name.to_ascii_lowercase().as_str() {
+                "medians" => layers.medians = true,
+                "altitudes" => layers.altitudes = true,
+                "bisectors" => layers.bisectors = true,
+                _ => return Err(name.to_string()),
+            }
+        }
+        Ok(layers)
+    }
+}
+
+/// Maps triangle coordinates onto the canvas, keeping the aspect ratio and
+/// flipping the y axis so that y grows upwards like in the input.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Viewport {
+    min: Point,
+    scale: f64,
+    offset: Point,
+}
+
+impl Viewport {
+    fn fit(points: &[Point]) -> Self {
+        let mut min = Point::splat(f64::INFINITY);
+        let mut max = Point::splat(f64::NEG_INFINITY);
+        for point in points.iter().filter(|p| p.x.is_finite() && p.y.is_finite()) {
+            min = Point::new(min.x.min(point.x), min.y.min(point.y));
+            max = Point::new(max.x.max(point.x), max.y.max(point.y));
+        }
+
+        let extent = (max - min).x.max((max - min).y);
+        let usable = 2.0f64.mul_add(-MARGIN, CANVAS);
+        // A zero extent would only come from a single point, keep it centred.
+        let scale = if extent > 0.0 && extent.is_finite() {
+            usable / extent
+        } else {
+            1.0
+        };
+        let drawn = (max - min) * scale;
+        let offset = Point::new(
+            MARGIN + (usable - drawn.x) / 2.0,
+            MARGIN + (usable - drawn.y) / 2.0,
+        );
+        Self { min, scale, offset }
+    }
+
+    fn map(&self, point: Point) -> Point {
+        let local = (point - self.min) * self.scale + self.offset;
+        Point::new(local.x, CANVAS - local.y)
+    }
+
+    fn length(&self, length: f64) -> f64 {
+        length * self.scale
+    }
+}
+
+struct SvgBuilder {
+    out: String,
+    view: Viewport,
+}
+
+impl SvgBuilder {
+    fn line(&mut self, from: Point, to: Point, class: &str) {
+        let (from, to) = (self.view.map(from), self.view.map(to));
+        let _ = writeln!(
+            self.out,
+            r#"  <line class="{class}" x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}"/>"#,
+            from.x, from.y, to.x, to.y
+        );
+    }
+
+    fn circle(&mut self, circle: Circle, class: &str) {
+        let center = self.view.map(circle.center);
+        let _ = writeln!(
+            self.out,
+            r#"  <circle class="{class}" cx="{:.2}" cy="{:.2}" r="{:.2}"/>"#,
+            center.x,
+            center.y,
+            self.view.length(circle.radius)
+        );
+    }
+
+    fn dot(&mut self, at: Point, label: &str, class: &str) {
+        let at = self.view.map(at);
+        let _ = writeln!(
+            self.out,
+            r#"  <circle class="dot {class}" cx="{:.2}" cy="{:.2}" r="3.5"/>"#,
+            at.x, at.y
+        );
+        let _ = writeln!(
+            self.out,
+            r#"  <text class="{class}" x="{:.2}" y="{:.2}">{label}</text>"#,
+            at.x + 6.0,
+            at.y - 6.0
+        );
+    }
+
+    fn vertex_label(&mut self, at: Point, away_from: Point, label: &str) {
+        let (at, away_from) = (self.view.map(at), self.view.map(away_from));
+        let dir = at - away_from;
+        let len = dir.length_sq().sqrt();
+        let offset = if len > 0.0 {
+            dir / len * 18.0
+        } else {
+            Point::zero()
+        };
+        let pos = at + offset;
+        let _ = writeln!(
+            self.out,
+            r#"  <text class="vertex" x="{:.2}" y="{:.2}">{label}</text>"#,
+            pos.x, pos.y
+        );
+    }
+
+    /// Draws `cevian` from its vertex to its foot, plus a dotted extension of
+    /// `side` when the foot falls outside it (altitudes of obtuse triangles).
+    fn cevian(&mut self, cevian: Segment, side: Segment, class: &str) {
+        self.line(cevian.start, cevian.end, class);
+        let t = side.parameter_of(cevian.end);
+        if t < 0.0 {
+            self.line(side.start, cevian.end, "extension");
+        } else if t > 1.0 {
+            self.line(side.end, cevian.end, "extension");
+        }
+    }
+}
+
+const STYLE: &str = "\
+    .triangle { fill: #e8f0fe; stroke: #1a3d7c; stroke-width: 2; }
+    .circumcircle, .incircle, .nine-point { fill: none; stroke-width: 1.5; }
+    .circumcircle { stroke: #c0392b; }
+    .incircle { stroke: #27ae60; }
+    .nine-point { stroke: #8e44ad; stroke-dasharray: 6 4; }
+    .euler { stroke: #555; stroke-width: 1.2; stroke-dasharray: 10 5; }
+    .median { stroke: #e67e22; stroke-width: 1.2; }
+    .altitude { stroke: #16a085; stroke-width: 1.2; }
+    .bisector { stroke: #2980b9; stroke-width: 1.2; }
+    .extension { stroke: #999; stroke-width: 1; stroke-dasharray: 2 3; }
+    .dot { stroke: none; }
+    circle.centroid, text.centroid { fill: #e67e22; }
+    circle.incenter, text.incenter { fill: #27ae60; }
+    circle.circumcenter, text.circumcenter { fill: #c0392b; }
+    circle.orthocenter, text.orthocenter { fill: #16a085; }
+    circle.nine-point-center, text.nine-point-center { fill: #8e44ad; }
+    text { font: 14px sans-serif; }
+    text.vertex { font-weight: bold; text-anchor: middle; dominant-baseline: middle; }
+";
+
+/// Renders the triangle, its incircle, circumcircle and nine-point circle,
+/// the Euler line and the notable centres as a standalone SVG document.
+/// The drawing is scaled to fit the canvas whatever the input magnitude.
+pub fn render_svg(summary: &TriangleSummary, layers: SvgLayers) -> String {
+    let (a, b, c) = (summary.vertex_a, summary.vertex_b, summary.vertex_c);
+    let circumcircle = [
+        summary.circumcenter - summary.circumradius,
+        summary.circumcenter + summary.circumradius,
+    ];
+
+    let mut bounds = vec![a, b, c, summary.orthocenter];
+    bounds.extend(circumcircle);
+    if layers.altitudes {
+        bounds.extend(summary.altitude_segments().map(|segment| segment.end));
+    }
+    let view = Viewport::fit(&bounds);
+    let mut svg = SvgBuilder {
+        out: String::new(),
+        view,
+    };
+
+    let _ = writeln!(
+        svg.out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{CANVAS}" height="{CANVAS}" viewBox="0 0 {CANVAS} {CANVAS}">"#
+    );
+    let _ = writeln!(svg.out, "  <style>\n{STYLE}  </style>");
+    let _ = writeln!(
+        svg.out,
+        r##"  <rect width="100%" height="100%" fill="#ffffff"/>"##
+    );
+
+    let (pa, pb, pc) = (view.map(a), view.map(b), view.map(c));
+    let _ = writeln!(
+        svg.out,
+        r#"  <polygon class="triangle" points="{:.2},{:.2} {:.2},{:.2} {:.2},{:.2}"/>"#,
+        pa.x, pa.y, pb.x, pb.y, pc.x, pc.y
+    );
+
+    svg.circle(summary.circumcircle(), "circumcircle");
+    svg.circle(summary.incircle(), "incircle");
+    svg.circle(summary.nine_point_circle(), "nine-point");
+
+    // The Euler line is undefined for equilateral triangles where O = H, and
+    // its direction is noise when they only differ by rounding.
+    let euler = summary
+        .euler_line()
+        .filter(|line| view.length(line.direction.length_sq().sqrt()) > 1e-6);
+    if let Some(euler) = euler {
+        let reach = CANVAS * 2.0 / view.length(euler.direction.length_sq().sqrt());
+        svg.line(euler.point_at(-reach), euler.point_at(reach), "euler");
+    }
+
+    let layered = [
+        (layers.medians, summary.median_segments(), "median"),
+        (layers.altitudes, summary.altitude_segments(), "altitude"),
+        (layers.bisectors, summary.bisector_segments(), "bisector"),
+    ];
+    for (_, segments, class) in layered.into_iter().filter(|(shown, ..)| *shown) {
+        for (segment, side) in segments.into_iter().zip(summary.sides()) {
+            svg.cevian(segment, side, class);
+        }
+    }
+
+    svg.dot(summary.centroid, "G", "centroid");
+    svg.dot(summary.incenter, "I", "incenter");
+    svg.dot(summary.circumcenter, "O", "circumcenter");
+    svg.dot(summary.orthocenter, "H", "orthocenter");
+    svg.dot(summary.nine_point_center, "N", "nine-point-center");
+
+    svg.vertex_label(a, summary.centroid, "A");
+    svg.vertex_label(b, summary.centroid, "B");
+    svg.vertex_label(c, summary.centroid, "C");
+
+    svg.out.push_str("</svg>\n");
+    svg.out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use triale::geometry::compute_summary;
+    use triale::tolerance::Tolerance;
+    use triale::triangle::Triangle;
+
+    fn inside_canvas(point: Point) -> bool {
+        (MARGIN - 1e-6..=CANVAS - MARGIN + 1e-6).contains(&point.x)
+            && (MARGIN - 1e-6..=CANVAS - MARGIN + 1e-6).contains(&point.y)
+    }
+
+    #[test]
+    fn test_viewport_fits_any_magnitude() {
+        for scale in [1e-9, 1.0, 1e12] {
+            let points = [
+                Point::new(0.0, 0.0) * scale,
+                Point::new(4.0, 0.0) * scale,
+                Point::new(0.0, 3.0) * scale,
+            ];
+            let view = Viewport::fit(&points);
+            for point in points {
+                assert!(inside_canvas(view.map(point)), "scale {scale}");
+            }
+            // The longer extent fills the usable width exactly.
+            let width = view.map(points[1]).x - view.map(points[0]).x;
+            assert!(Tolerance::default().approx_eq(width, 2.0f64.mul_add(-MARGIN, CANVAS)));
+        }
+    }
+
+    #[test]
+    fn test_viewport_flips_y_axis() {
+        let view = Viewport::fit(&[Point::new(0.0, 0.0), Point::new(1.0, 1.0)]);
+        assert!(view.map(Point::new(0.0, 1.0)).y < view.map(Point::new(0.0, 0.0)).y);
+    }
+
+    #[test]
+    fn test_layers_and_document() {
+        assert_eq!(
+            SvgLayers::from_list("medians, Bisectors"),
+            Ok(SvgLayers {
+                medians: true,
+                altitudes: false,
+                bisectors: true,
+            })
+        );
+        assert_eq!(
+            SvgLayers::from_list("medians,arcs"),
+            Err("arcs".to_string())
+        );
+
+        let summary = compute_summary(&Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(3.0, 7.0),
+        ));
+        let svg = render_svg(
+            &summary,
+            SvgLayers {
+                medians: true,
+                altitudes: true,
+                bisectors: true,
+            },
+        );
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        for class in [
+            "circumcircle",
+            "incircle",
+            "nine-point",
+            "euler",
+            "median",
+            "altitude",
+            "bisector",
+        ] {
+            assert!(
+                svg.contains(&format!("class=\"{class}\"")),
+                "missing {class}"
+            );
+        }
+        assert!(!svg.contains("NaN") && !svg.contains("inf"));
+    }
+}
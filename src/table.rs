@@ -0,0 +1,463 @@
+use crate::app_error::AppError;
+use std::io::{self, Write};
+use triale::geometry::TriangleSummary;
+use triale::point::Point;
+
+/// A selectable column of CSV/TSV output.
+///
+/// Most columns map onto one [`TriangleSummary`] field, with points split into
+/// `_x`/`_y` and angles offered in both radians and degrees. `line` and `error`
+/// describe the input row itself.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Field {
+    Line,
+    VertexAX,
+    VertexAY,
+    VertexBX,
+    VertexBY,
+    VertexCX,
+    VertexCY,
+    SideA,
+    SideB,
+    SideC,
+    AngleARad,
+    AngleBRad,
+    AngleCRad,
+    AngleADeg,
+    AngleBDeg,
+    AngleCDeg,
+    Perimeter,
+    SemiPerimeter,
+    Area,
+    SideClass,
+    AngleClass,
+    CentroidX,
+    CentroidY,
+    IncenterX,
+    IncenterY,
+    CircumcenterX,
+    CircumcenterY,
+    OrthocenterX,
+    OrthocenterY,
+    NinePointCenterX,
+    NinePointCenterY,
+    Inradius,
+    Circumradius,
+    NinePointRadius,
+    ExcenterAX,
+    ExcenterAY,
+    ExcenterBX,
+    ExcenterBY,
+    ExcenterCX,
+    ExcenterCY,
+    ExradiusA,
+    ExradiusB,
+    ExradiusC,
+    MedianA,
+    MedianB,
+    MedianC,
+    AltitudeA,
+    AltitudeB,
+    AltitudeC,
+    BisectorA,
+    BisectorB,
+    BisectorC,
+    EulerLineResidual,
+    Error,
+}
+
+impl Field {
+    /// Every column in default output order.
+    pub const ALL: [Self; 54] = [
+        Self::Line,
+        Self::VertexAX,
+        Self::VertexAY,
+        Self::VertexBX,
+        Self::VertexBY,
+        Self::VertexCX,
+        Self::VertexCY,
+        Self::SideA,
+        Self::SideB,
+        Self::SideC,
+        Self::AngleARad,
+        Self::AngleBRad,
+        Self::AngleCRad,
+        Self::AngleADeg,
+        Self::AngleBDeg,
+        Self::AngleCDeg,
+        Self::Perimeter,
+        Self::SemiPerimeter,
+        Self::Area,
+        Self::SideClass,
+        Self::AngleClass,
+        Self::CentroidX,
+        Self::CentroidY,
+        Self::IncenterX,
+        Self::IncenterY,
+        Self::CircumcenterX,
+        Self::CircumcenterY,
+        Self::OrthocenterX,
+        Self::OrthocenterY,
+        Self::NinePointCenterX,
+        Self::NinePointCenterY,
+        Self::Inradius,
+        Self::Circumradius,
+        Self::NinePointRadius,
+        Self::ExcenterAX,
+        Self::ExcenterAY,
+        Self::ExcenterBX,
+        Self::ExcenterBY,
+        Self::ExcenterCX,
+        Self::ExcenterCY,
+        Self::ExradiusA,
+        Self::ExradiusB,
+        Self::ExradiusC,
+        Self::MedianA,
+        Self::MedianB,
+        Self::MedianC,
+        Self::AltitudeA,
+        Self::AltitudeB,
+        Self::AltitudeC,
+        Self::BisectorA,
+        Self::BisectorB,
+        Self::BisectorC,
+        Self::EulerLineResidual,
+        Self::Error,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::VertexAX => "vertex_a_x",
+            Self::VertexAY => "vertex_a_y",
+            Self::VertexBX => "vertex_b_x",
+            Self::VertexBY => "vertex_b_y",
+            Self::VertexCX => "vertex_c_x",
+            Self::VertexCY => "vertex_c_y",
+            Self::SideA => "side_a",
+            Self::SideB => "side_b",
+            Self::SideC => "side_c",
+            Self::AngleARad => "angle_a_rad",
+            Self::AngleBRad => "angle_b_rad",
+            Self::AngleCRad => "angle_c_rad",
+            Self::AngleADeg => "angle_a_deg",
+            Self::AngleBDeg => "angle_b_deg",
+            Self::AngleCDeg => "angle_c_deg",
+            Self::Perimeter => "perimeter",
+            Self::SemiPerimeter => "semi_perimeter",
+            Self::Area => "area",
+            Self::SideClass => "side_class",
+            Self::AngleClass => "angle_class",
+            Self::CentroidX => "centroid_x",
+            Self::CentroidY => "centroid_y",
+            Self::IncenterX => "incenter_x",
+            Self::IncenterY => "incenter_y",
+            Self::CircumcenterX => "circumcenter_x",
+            Self::CircumcenterY => "circumcenter_y",
+            Self::OrthocenterX => "orthocenter_x",
+            Self::OrthocenterY => "orthocenter_y",
+            Self::NinePointCenterX => "nine_point_center_x",
+            Self::NinePointCenterY => "nine_point_center_y",
+            Self::Inradius => "inradius",
+            Self::Circumradius => "circumradius",
+            Self::NinePointRadius => "nine_point_radius",
+            Self::ExcenterAX => "excenter_a_x",
+            Self::ExcenterAY => "excenter_a_y",
+            Self::ExcenterBX => "excenter_b_x",
+            Self::ExcenterBY => "excenter_b_y",
+            Self::ExcenterCX => "excenter_c_x",
+            Self::ExcenterCY => "excenter_c_y",
+            Self::ExradiusA => "exradius_a",
+            Self::ExradiusB => "exradius_b",
+            Self::ExradiusC => "exradius_c",
+            Self::MedianA => "median_a",
+            Self::MedianB => "median_b",
+            Self::MedianC => "median_c",
+            Self::AltitudeA => "altitude_a",
+            Self::AltitudeB => "altitude_b",
+            Self::AltitudeC => "altitude_c",
+            Self::BisectorA => "bisector_a",
+            Self::BisectorB => "bisector_b",
+            Self::BisectorC => "bisector_c",
+            Self::EulerLineResidual => "euler_line_residual",
+            Self::Error => "error",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.name().eq_ignore_ascii_case(name))
+    }
+
+    fn summary_value(self, summary: &TriangleSummary) -> Option<f64> {
+        let coord = |point: Point, y: bool| if y { point.y } else { point.x };
+        let value = match self {
+            Self::VertexAX | Self::VertexAY => coord(summary.vertex_a, self == Self::VertexAY),
+            Self::VertexBX | Self::VertexBY => coord(summary.vertex_b, self == Self::VertexBY),
+            Self::VertexCX | Self::VertexCY => coord(summary.vertex_c, self == Self::VertexCY),
+            Self::SideA => summary.side_a,
+            Self::SideB => summary.side_b,
+            Self::SideC => summary.side_c,
+            Self::AngleARad => summary.angle_a_rad,
+            Self::AngleBRad => summary.angle_b_rad,
+            Self::AngleCRad => summary.angle_c_rad,
+            Self::AngleADeg => summary.angle_a_rad.to_degrees(),
+            Self::AngleBDeg => summary.angle_b_rad.to_degrees(),
+            Self::AngleCDeg => summary.angle_c_rad.to_degrees(),
+            Self::Perimeter => summary.perimeter,
+            Self::SemiPerimeter => summary.semi_perimeter,
+            Self::Area => summary.area,
+            Self::CentroidX | Self::CentroidY => coord(summary.centroid, self == Self::CentroidY),
+            Self::IncenterX | Self::IncenterY => coord(summary.incenter, self == Self::IncenterY),
+            Self::CircumcenterX | Self::CircumcenterY => {
+                coord(summary.circumcenter, self == Self::CircumcenterY)
+            }
+            Self::OrthocenterX | Self::OrthocenterY => {
+                coord(summary.orthocenter, self == Self::OrthocenterY)
+            }
+            Self::NinePointCenterX | Self::NinePointCenterY => {
+                coord(summary.nine_point_center, self == Self::NinePointCenterY)
+            }
+            Self::Inradius => summary.inradius,
+            Self::Circumradius => summary.circumradius,
+            Self::NinePointRadius => summary.nine_point_radius,
+            Self::ExcenterAX | Self::ExcenterAY => {
+                coord(summary.excenter_a, self == Self::ExcenterAY)
+            }
+            Self::ExcenterBX | Self::ExcenterBY => {
+                coord(summary.excenter_b, self == Self::ExcenterBY)
+            }
+            Self::ExcenterCX | Self::ExcenterCY => {
+                coord(summary.excenter_c, self == Self::ExcenterCY)
+            }
+            Self::ExradiusA => summary.exradius_a,
+            Self::ExradiusB => summary.exradius_b,
+            Self::ExradiusC => summary.exradius_c,
+            Self::MedianA => summary.median_a,
+            Self::MedianB => summary.median_b,
+            Self::MedianC => summary.median_c,
+            Self::AltitudeA => summary.altitude_a,
+            Self::AltitudeB => summary.altitude_b,
+            Self::AltitudeC => summary.altitude_c,
+            Self::BisectorA => summary.bisector_a,
+            Self::BisectorB => summary.bisector_b,
+            Self::BisectorC => summary.bisector_c,
+            Self::EulerLineResidual => summary.euler_line_residual(),
+            Self::Line | Self::SideClass | Self::AngleClass | Self::Error => return None,
+        };
+        Some(value)
+    }
+
+    /// Text of this column for one input row. Summary columns are empty when
+    /// the row failed, and `error` is empty when it succeeded.
+    fn cell(self, line: usize, outcome: Result<&TriangleSummary, &AppError>) -> String {
+        match (self, outcome) {
+            (Self::Line, _) => line.to_string(),
+            (Self::Error, Err(err)) => err.to_string(),
+            (Self::Error, Ok(_)) | (_, Err(_)) => String::new(),
+            (Self::SideClass, Ok(summary)) => summary.side_class.to_string(),
+            (Self::AngleClass, Ok(summary)) => summary.angle_class.to_string(),
+            (_, Ok(summary)) => self
+                .summary_value(summary)
+                .map_or_else(String::new, |value| format!("{value:?}")),
+        }
+    }
+}
+
+/// Parses a comma separated list of field names such as `area,perimeter`.
+///
+/// # Errors
+/// Returns the first name that is not a known [`Field`].
+pub fn parse_fields(list: &str) -> Result<Vec<Field>, String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| Field::from_name(name).ok_or_else(|| name.to_string()))
+        .collect()
+}
+
+/// Columns used when `--fields` is not given. Single triangles have no use
+/// for the `line` and `error` columns.
+pub fn default_fields(batch: bool) -> Vec<Field> {
+    Field::ALL
+        .into_iter()
+        .filter(|field| batch || !matches!(field, Field::Line | Field::Error))
+        .collect()
+}
+
+/// Columns of batch output for a `--fields` selection. `line` and `error` are
+/// added when left out, so a failed row still says which line failed and why.
+pub fn batch_fields(mut fields: Vec<Field>) -> Vec<Field> {
+    if !fields.contains(&Field::Line) {
+        fields.insert(0, Field::Line);
+    }
+    if !fields.contains(&Field::Error) {
+        fields.push(Field::Error);
+    }
+    fields
+}
+
+/// Quotes a CSV cell when needed. TSV has no quoting, so tabs and line breaks
+/// inside a TSV cell are replaced with spaces instead.
+fn escape_cell(cell: &str, delimiter: char) -> String {
+    if delimiter == '\t' {
+        return cell.replace(['\t', '\n', '\r'], " ");
+    }
+    if cell.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// Writes one row of already formatted cells, escaping them as needed.
+///
+/// # Errors
+/// Returns any error raised by `writer`.
+pub fn write_cells<W, I>(writer: &mut W, delimiter: char, cells: I) -> Result<(), io::Error>
+where
+    W: Write,
+    I: IntoIterator<Item = String>,
+{
+    let row: Vec<String> = cells
+        .into_iter()
+        .map(|cell| escape_cell(&cell, delimiter))
+        .collect();
+    writeln!(writer, "{}", row.join(&delimiter.to_string()))
+}
+
+/// # Errors
+/// Returns any error raised by `writer`.
+pub fn write_header<W: Write>(
+    writer: &mut W,
+    fields: &[Field],
+    delimiter: char,
+) -> Result<(), io::Error> {
+    write_cells(
+        writer,
+        delimiter,
+        fields.iter().map(|field| field.name().to_string()),
+    )
+}
+
+/// # Errors
+/// Returns any error raised by `writer`.
+pub fn write_row<W: Write>(
+    writer: &mut W,
+    fields: &[Field],
+    delimiter: char,
+    line: usize,
+    outcome: Result<&TriangleSummary, &AppError>,
+) -> Result<(), io::Error> {
+    write_cells(
+        writer,
+        delimiter,
+        fields.iter().map(|field| field.cell(line, outcome)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use triale::geometry::compute_summary;
+    use triale::triangle::{DegenerateTriangleError, Triangle};
+
+    fn right_summary() -> TriangleSummary {
+        compute_summary(&Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        ))
+    }
+
+    fn render(
+        fields: &[Field],
+        delimiter: char,
+        rows: &[Result<TriangleSummary, AppError>],
+    ) -> String {
+        let mut out = Vec::new();
+        let mut result = write_header(&mut out, fields, delimiter);
+        for (idx, row) in rows.iter().enumerate() {
+            result =
+                result.and_then(|()| write_row(&mut out, fields, delimiter, idx + 1, row.as_ref()));
+        }
+        assert!(result.is_ok());
+        String::from_utf8_lossy(&out).into_owned()
+    }
+
+    #[test]
+    fn test_field_names_round_trip() {
+        for field in Field::ALL {
+            assert_eq!(Field::from_name(field.name()), Some(field));
+        }
+        assert_eq!(
+            parse_fields("area, Perimeter,circumradius"),
+            Ok(vec![Field::Area, Field::Perimeter, Field::Circumradius])
+        );
+        assert_eq!(parse_fields("area,volume"), Err("volume".to_string()));
+    }
+
+    #[test]
+    fn test_selected_columns_in_order() {
+        let fields = [Field::Area, Field::Perimeter, Field::Circumradius];
+        assert_eq!(
+            render(&fields, ',', &[Ok(right_summary())]),
+            "area,perimeter,circumradius\n6.0,12.0,2.5\n"
+        );
+        assert_eq!(
+            render(
+                &[Field::SideClass, Field::AngleADeg],
+                '\t',
+                &[Ok(right_summary())]
+            ),
+            "side_class\tangle_a_deg\nScalene\t90.0\n"
+        );
+    }
+
+    #[test]
+    fn test_failed_rows_and_escaping() {
+        let err = AppError::from(DegenerateTriangleError::Collinear {
+            a: Point::new(0.0, 0.0),
+            b: Point::new(1.0, 0.0),
+            c: Point::new(2.0, 0.0),
+        });
+        let fields = [Field::Line, Field::Area, Field::Error];
+        let csv = render(&fields, ',', &[Ok(right_summary()), Err(err)]);
+        assert_eq!(
+            csv,
+            "line,area,error\n\
+             1,6.0,\n\
+             2,,\"Triangle error: Points (0, 0), (1, 0), and (2, 0) are collinear\"\n"
+        );
+        assert_eq!(escape_cell("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_cell("a\tb", '\t'), "a b");
+    }
+
+    #[test]
+    fn test_batch_keeps_line_and_error() {
+        let err = AppError::from(DegenerateTriangleError::Collinear {
+            a: Point::new(0.0, 0.0),
+            b: Point::new(1.0, 0.0),
+            c: Point::new(2.0, 0.0),
+        });
+        let fields = batch_fields(vec![Field::Area, Field::Perimeter]);
+        let csv = render(&fields, ',', &[Ok(right_summary()), Err(err)]);
+        assert_eq!(
+            csv,
+            "line,area,perimeter,error\n\
+             1,6.0,12.0,\n\
+             2,,,\"Triangle error: Points (0, 0), (1, 0), and (2, 0) are collinear\"\n"
+        );
+        let chosen = vec![Field::Error, Field::Area, Field::Line];
+        assert_eq!(batch_fields(chosen.clone()), chosen);
+    }
+
+    #[test]
+    fn test_default_fields() {
+        assert_eq!(default_fields(true).len(), Field::ALL.len());
+        let single = default_fields(false);
+        assert!(!single.contains(&Field::Line));
+        assert!(!single.contains(&Field::Error));
+    }
+}
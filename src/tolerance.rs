@@ -0,0 +1,116 @@
+//! How close two floating-point quantities must be to count as equal.
+//!
+//! A [`Tolerance`] combines a relative bound, applied to the size of the
+//! quantities compared, with an optional absolute floor. The default is purely
+//! relative, so a triangle and a scaled copy of it are classified and
+//! validated the same way whatever their size.
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Tolerance {
+    /// Largest accepted difference as a fraction of the magnitudes compared.
+    pub relative: f64,
+    /// Differences up to this much are accepted regardless of magnitude.
+    pub absolute: f64,
+}
+
+impl Tolerance {
+    /// Accepted forms of `--tolerance`.
+    pub const SPECS: &'static str = "REL or REL,ABS (finite, non-negative numbers)";
+
+    #[must_use]
+    pub const fn new(relative: f64, absolute: f64) -> Self {
+        Self { relative, absolute }
+    }
+
+    /// A purely scale-relative tolerance.
+    #[must_use]
+    pub const fn relative(relative: f64) -> Self {
+        Self::new(relative, 0.0)
+    }
+
+    /// Whether `a` and `b` agree up to the tolerance.
+    #[must_use]
+    pub fn approx_eq(self, a: f64, b: f64) -> bool {
+        let diff = (a - b).abs();
+        diff <= self.absolute || diff <= self.relative * a.abs().max(b.abs())
+    }
+
+    /// Whether `value` is zero for a quantity whose natural size is `scale`,
+    /// e.g. a cross product against the squared length of the longest side.
+    #[must_use]
+    pub fn is_negligible(self, value: f64, scale: f64) -> bool {
+        let value = value.abs();
+        value <= self.absolute || value <= self.relative * scale.abs()
+    }
+
+    /// Parses `REL` or `REL,ABS`, see [`Self::SPECS`].
+    #[must_use]
+    pub fn from_spec(spec: &str) -> Option<Self> {
+        let values = spec
+            .split(',')
+            .map(|value| {
+                value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|value| value.is_finite() && *value >= 0.0)
+            })
+            .collect::<Option<Vec<f64>>>()?;
+        match *values.as_slice() {
+            [relative] => Some(Self::relative(relative)),
+            [relative, absolute] => Some(Self::new(relative, absolute)),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self::relative(1e-10)
+    }
+}
+
+impl Display for Tolerance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "relative {:e}", self.relative)?;
+        if self.absolute > 0.0 {
+            write!(f, ", absolute {:e}", self.absolute)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_comparison_is_scale_invariant() {
+        let tolerance = Tolerance::default();
+        for scale in [1e-12, 1e-6, 1.0, 1e6, 1e12] {
+            assert!(tolerance.approx_eq(scale, scale * (1.0 + 1e-12)), "{scale}");
+            assert!(!tolerance.approx_eq(scale, scale * (1.0 + 1e-6)), "{scale}");
+            assert!(tolerance.is_negligible(scale * scale * 1e-12, scale * scale));
+            assert!(!tolerance.is_negligible(scale * scale * 1e-6, scale * scale));
+        }
+        assert!(!tolerance.approx_eq(1e-12, 0.0));
+        assert!(Tolerance::new(1e-9, 1e-9).approx_eq(1e-12, 0.0));
+    }
+
+    #[test]
+    fn test_parse_specs() {
+        assert_eq!(
+            Tolerance::from_spec("1e-6"),
+            Some(Tolerance::relative(1e-6))
+        );
+        assert_eq!(
+            Tolerance::from_spec("1e-6, 1e-12"),
+            Some(Tolerance::new(1e-6, 1e-12))
+        );
+        for bad in ["", "x", "-1e-6", "1,2,3", "inf", "1e-6,"] {
+            assert_eq!(Tolerance::from_spec(bad), None, "{bad}");
+        }
+    }
+}
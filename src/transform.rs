@@ -0,0 +1,371 @@
+//! Affine maps of the plane: rotations, scalings, reflections, shears and
+//! translations, and their compositions.
+//!
+//! A [`Transform`] sends `(x, y)` to `(a x + b y + tx, c x + d y + ty)`. Areas
+//! scale by the absolute value of the determinant `a d - b c`; similarities
+//! additionally preserve angles and scale every length by the same ratio.
+
+use crate::point::Point;
+use crate::primitives::Line;
+use crate::tolerance::Tolerance;
+use crate::triangle::Triangle;
+use std::fmt::{Display, Formatter};
+
+/// An affine map `p -> M p + t` with the linear part `M = [a b; c d]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub tx: f64,
+    pub ty: f64,
+}
+
+impl Transform {
+    /// Accepted forms of one `--transform` step.
+    pub const SPECS: &'static str = "rotate:DEG, scale:S, scale:SX,SY, translate:DX,DY, \
+         shear:KX,KY, reflect:x, reflect:y or matrix:A,B,C,D,TX,TY";
+
+    #[must_use]
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, tx: f64, ty: f64) -> Self {
+        Self { a, b, c, d, tx, ty }
+    }
+
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    #[must_use]
+    pub const fn translation(offset: Point) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, offset.x, offset.y)
+    }
+
+    /// Counter-clockwise rotation about the origin.
+    #[must_use]
+    pub fn rotation(radians: f64) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new(cos, -sin, sin, cos, 0.0, 0.0)
+    }
+
+    /// Counter-clockwise rotation about `center`.
+    #[must_use]
+    pub fn rotation_about(center: Point, radians: f64) -> Self {
+        Self::translation(-center)
+            .then(Self::rotation(radians))
+            .then(Self::translation(center))
+    }
+
+    /// Scaling about the origin, by `sx` along x and `sy` along y.
+    #[must_use]
+    pub const fn scaling(sx: f64, sy: f64) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Shear moving x by `kx` times y and y by `ky` times x.
+    #[must_use]
+    pub const fn shear(kx: f64, ky: f64) -> Self {
+        Self::new(1.0, kx, ky, 1.0, 0.0, 0.0)
+    }
+
+    /// Reflection across `line`.
+    #[must_use]
+    pub fn reflection(line: &Line) -> Self {
+        let Point { x: dx, y: dy } = line.direction;
+        let length_sq = line.direction.length_sq();
+        let cos = dx.mul_add(dx, -dy * dy) / length_sq;
+        let sin = 2.0 * dx * dy / length_sq;
+        let mirror = Self::new(cos, sin, sin, -cos, 0.0, 0.0);
+        Self::translation(-line.point)
+            .then(mirror)
+            .then(Self::translation(line.point))
+    }
+
+    /// The map applying `self` first and `next` afterwards.
+    #[must_use]
+    pub fn then(self, next: Self) -> Self {
+        let offset = next.apply(Point::new(self.tx, self.ty));
+        Self::new(
+            next.a.mul_add(self.a, next.b * self.c),
+            next.a.mul_add(self.b, next.b * self.d),
+            next.c.mul_add(self.a, next.d * self.c),
+            next.c.mul_add(self.b, next.d * self.d),
+            offset.x,
+            offset.y,
+        )
+    }
+
+    /// Determinant of the linear part: the factor areas are scaled by,
+    /// negative when the map reverses orientation.
+    #[must_use]
+    pub fn determinant(&self) -> f64 {
+        self.a.mul_add(self.d, -self.b * self.c)
+    }
+
+    /// The map undoing `self`, `None` when it collapses the plane onto a line
+    /// or a point.
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let linear = Self::new(
+            self.d / det,
+            -self.b / det,
+            -self.c / det,
+            self.a / det,
+            0.0,
+            0.0,
+        );
+        let offset = linear.apply(Point::new(self.tx, self.ty));
+        Some(Self {
+            tx: -offset.x,
+            ty: -offset.y,
+            ..linear
+        })
+    }
+
+    /// Whether the map preserves angles, i.e. is a rotation or reflection
+    /// combined with a uniform scaling and a translation.
+    #[must_use]
+    pub fn is_similarity(&self) -> bool {
+        self.is_similarity_with(Tolerance::default())
+    }
+
+    /// [`Self::is_similarity`], with the entries of the linear part compared
+    /// within `tolerance`, relative to the largest of them.
+    #[must_use]
+    pub fn is_similarity_with(&self, tolerance: Tolerance) -> bool {
+        let scale = self
+            .a
+            .abs()
+            .max(self.b.abs())
+            .max(self.c.abs())
+            .max(self.d.abs());
+        let equal = |lhs: f64, rhs: f64| tolerance.is_negligible(lhs - rhs, scale);
+        let direct = equal(self.a, self.d) && equal(self.b, -self.c);
+        let mirrored = equal(self.a, -self.d) && equal(self.b, self.c);
+        (direct || mirrored) && self.determinant() != 0.0
+    }
+
+    /// The ratio lengths are scaled by, `None` unless the map is a
+    /// similarity.
+    #[must_use]
+    pub fn similarity_ratio(&self) -> Option<f64> {
+        self.similarity_ratio_with(Tolerance::default())
+    }
+
+    /// [`Self::similarity_ratio`] with the similarity test of
+    /// [`Self::is_similarity_with`].
+    #[must_use]
+    pub fn similarity_ratio_with(&self, tolerance: Tolerance) -> Option<f64> {
+        self.is_similarity_with(tolerance)
+            .then(|| self.determinant().abs().sqrt())
+    }
+
+    #[must_use]
+    pub const fn apply(&self, point: Point) -> Point {
+        Point::new(
+            self.a.mul_add(point.x, self.b.mul_add(point.y, self.tx)),
+            self.c.mul_add(point.x, self.d.mul_add(point.y, self.ty)),
+        )
+    }
+
+    /// Maps every vertex. The result is degenerate when the determinant is
+    /// zero.
+    #[must_use]
+    pub const fn apply_triangle(&self, tri: &Triangle) -> Triangle {
+        Triangle::new(self.apply(tri.a), self.apply(tri.b), self.apply(tri.c))
+    }
+
+    /// Parses one step such as `rotate:90` or `translate:1,2`, see
+    /// [`Self::SPECS`]. Angles are in degrees.
+    #[must_use]
+    pub fn from_spec(spec: &str) -> Option<Self> {
+        let (name, args) = spec.split_once(':')?;
+        let numbers = args
+            .split(',')
+            .map(|arg| {
+                arg.trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|value| value.is_finite())
+            })
+            .collect::<Option<Vec<f64>>>();
+        let transform = match (name.trim().to_ascii_lowercase().as_str(), args.trim()) {
+            ("reflect", "x") => Self::scaling(1.0, -1.0),
+            ("reflect", "y") => Self::scaling(-1.0, 1.0),
+            (name, _) => match (name, numbers?.as_slice()) {
+                ("rotate", &[degrees]) => Self::rotation(degrees.to_radians()),
+                ("scale", &[factor]) => Self::scaling(factor, factor),
+                ("scale", &[sx, sy]) => Self::scaling(sx, sy),
+                ("translate", &[dx, dy]) => Self::translation(Point::new(dx, dy)),
+                ("shear", &[kx, ky]) => Self::shear(kx, ky),
+                ("matrix", &[a, b, c, d, tx, ty]) => Self::new(a, b, c, d, tx, ty),
+                _ => return None,
+            },
+        };
+        Some(transform)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{} {} {}; {} {} {}]",
+            self.a, self.b, self.tx, self.c, self.d, self.ty
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::compute_summary;
+    use crate::test_support::{approx_eq, approx_eq_pt, scalene};
+
+    #[test]
+    fn test_basic_maps() {
+        let quarter = Transform::rotation(std::f64::consts::FRAC_PI_2);
+        assert!(approx_eq_pt(
+            quarter.apply(Point::new(1.0, 0.0)),
+            Point::new(0.0, 1.0)
+        ));
+
+        let about = Transform::rotation_about(Point::one(), std::f64::consts::PI);
+        assert!(approx_eq_pt(about.apply(Point::zero()), Point::splat(2.0)));
+
+        let diagonal = Line::new(Point::new(0.0, 1.0), Point::one());
+        let mirror = Transform::reflection(&diagonal);
+        assert!(approx_eq_pt(
+            mirror.apply(Point::new(2.0, 0.0)),
+            Point::new(-1.0, 3.0)
+        ));
+        assert!(approx_eq(mirror.determinant(), -1.0));
+
+        assert!(approx_eq_pt(
+            Transform::shear(2.0, 0.0).apply(Point::new(1.0, 1.0)),
+            Point::new(3.0, 1.0)
+        ));
+    }
+
+    #[test]
+    fn test_composition_and_inverse() {
+        let first = Transform::translation(Point::new(1.0, 0.0));
+        let second = Transform::scaling(2.0, 3.0);
+        let point = Point::new(1.0, 1.0);
+        // Translate, then scale: (2, 1) -> (4, 3).
+        assert!(approx_eq_pt(
+            first.then(second).apply(point),
+            Point::new(4.0, 3.0)
+        ));
+        assert!(approx_eq_pt(
+            second.then(first).apply(point),
+            Point::new(3.0, 3.0)
+        ));
+
+        let combined = Transform::new(1.5, -0.5, 2.0, 0.25, 3.0, -4.0).then(first);
+        let inverse = combined.inverse().unwrap();
+        assert!(approx_eq_pt(inverse.apply(combined.apply(point)), point));
+        assert!(approx_eq_pt(
+            combined.then(inverse).apply(Point::new(-7.0, 2.0)),
+            Point::new(-7.0, 2.0)
+        ));
+        assert_eq!(Transform::scaling(1.0, 0.0).inverse(), None);
+    }
+
+    #[test]
+    fn test_similarity_preserves_angles() {
+        let tri = scalene();
+        let original = compute_summary(&tri);
+        let similarity = Transform::rotation(0.7)
+            .then(Transform::scaling(2.5, 2.5))
+            .then(Transform::reflection(&Line::new(
+                Point::one(),
+                Point::new(1.0, 3.0),
+            )))
+            .then(Transform::translation(Point::new(-4.0, 9.0)));
+        assert!(similarity.is_similarity());
+        assert!(approx_eq(similarity.similarity_ratio().unwrap(), 2.5));
+
+        let mapped = compute_summary(&similarity.apply_triangle(&tri));
+        assert!(approx_eq(mapped.angle_a_rad, original.angle_a_rad));
+        assert!(approx_eq(mapped.angle_b_rad, original.angle_b_rad));
+        assert!(approx_eq(mapped.angle_c_rad, original.angle_c_rad));
+        assert!(approx_eq(mapped.side_a, 2.5 * original.side_a));
+        // Centres are mapped along with the vertices.
+        assert!(approx_eq_pt(
+            mapped.circumcenter,
+            similarity.apply(original.circumcenter)
+        ));
+    }
+
+    #[test]
+    fn test_affine_area_ratio() {
+        let tri = scalene();
+        let original = compute_summary(&tri);
+        let affine = Transform::shear(0.8, -0.3)
+            .then(Transform::scaling(3.0, 0.5))
+            .then(Transform::translation(Point::new(2.0, 2.0)));
+        assert!(!affine.is_similarity());
+        assert_eq!(affine.similarity_ratio(), None);
+        // Whether a map counts as a similarity does not depend on its scale.
+        for scale in [1e-12, 1e12] {
+            let tiny_shear = Transform::shear(1e-6, 0.0).then(Transform::scaling(scale, scale));
+            assert!(!tiny_shear.is_similarity(), "{scale}");
+            assert!(tiny_shear.is_similarity_with(Tolerance::relative(1e-3)));
+        }
+
+        let mapped = compute_summary(&affine.apply_triangle(&tri));
+        assert!(approx_eq(
+            mapped.area,
+            original.area * affine.determinant().abs()
+        ));
+        // Affine maps keep the centroid, not the circumcenter.
+        assert!(approx_eq_pt(
+            mapped.centroid,
+            affine.apply(original.centroid)
+        ));
+    }
+
+    #[test]
+    fn test_parse_specs() {
+        assert_eq!(
+            Transform::from_spec("scale:2"),
+            Some(Transform::scaling(2.0, 2.0))
+        );
+        assert_eq!(
+            Transform::from_spec("translate: 1, -2"),
+            Some(Transform::translation(Point::new(1.0, -2.0)))
+        );
+        assert_eq!(
+            Transform::from_spec("Reflect:x"),
+            Some(Transform::scaling(1.0, -1.0))
+        );
+        assert_eq!(
+            Transform::from_spec("matrix:1,2,3,4,5,6"),
+            Some(Transform::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0))
+        );
+        assert!(Transform::from_spec("rotate:90").is_some());
+        for bad in [
+            "rotate",
+            "rotate:x",
+            "scale:1,2,3",
+            "twist:1",
+            "reflect:z",
+            "rotate:inf",
+        ] {
+            assert_eq!(Transform::from_spec(bad), None, "{bad}");
+        }
+    }
+}
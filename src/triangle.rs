@@ -1,47 +1,101 @@
-use crate::errors::AppError;
+use crate::errors::Error;
 use crate::point::Point;
-use std::error::Error;
+use crate::predicates::orient2d;
+use crate::scalar::{Real, Scalar};
+use crate::tolerance::Tolerance;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
-pub struct Triangle {
-    pub a: Point,
-    pub b: Point,
-    pub c: Point,
+/// A triangle with vertices of any [`Scalar`] type. Construct `f64`
+/// triangles with [`build_triangle`] to have them validated.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd)]
+pub struct Triangle<T = f64> {
+    pub a: Point<T>,
+    pub b: Point<T>,
+    pub c: Point<T>,
 }
 
-impl Triangle {
-    pub const fn new(a: Point, b: Point, c: Point) -> Self {
+impl<T> Triangle<T> {
+    #[must_use]
+    pub const fn new(a: Point<T>, b: Point<T>, c: Point<T>) -> Self {
         Self { a, b, c }
     }
+}
+
+impl<T: Copy> Triangle<T> {
+    #[must_use]
+    pub const fn splat_recursive(size: T) -> Self {
+        Self::splat(Point::splat(size))
+    }
+    #[must_use]
+    pub const fn splat(point: Point<T>) -> Self {
+        Self::new(point, point, point)
+    }
+}
+
+impl Triangle {
+    #[must_use]
     pub const fn zero() -> Self {
-        Self::new(Point::zero(), Point::zero(), Point::zero())
+        Self::splat(Point::zero())
     }
+    #[must_use]
     pub const fn one() -> Self {
-        Self::new(Point::one(), Point::one(), Point::one())
+        Self::splat(Point::one())
     }
-    pub const fn splat_recursive(size: f64) -> Self {
-        Self::new(Point::splat(size), Point::splat(size), Point::splat(size))
+}
+
+impl<T: Scalar> Triangle<T> {
+    /// Twice the signed area: positive when A, B and C run counter-clockwise.
+    #[must_use]
+    pub fn twice_signed_area(&self) -> T {
+        let Self { a, b, c } = self.clone();
+        (b - a.clone()).cross(c - a)
     }
-    pub const fn splat(point: Point) -> Self {
-        Self::new(point, point, point)
+
+    /// Squared lengths of the sides opposite A, B and C.
+    #[must_use]
+    pub fn side_lengths_sq(&self) -> [T; 3] {
+        let Self { a, b, c } = self.clone();
+        [
+            (c.clone() - b.clone()).length_sq(),
+            (c - a.clone()).length_sq(),
+            (b - a).length_sq(),
+        ]
+    }
+
+    /// The nearest triangle with `f64` coordinates.
+    #[must_use]
+    pub fn to_f64(&self) -> Triangle {
+        Triangle::new(self.a.to_f64(), self.b.to_f64(), self.c.to_f64())
     }
 }
 
-impl Display for Triangle {
+impl<T: Real> Triangle<T> {
+    /// Lengths of the sides opposite A, B and C.
+    #[must_use]
+    pub fn side_lengths(&self) -> [T; 3] {
+        [
+            self.b.distance_to(self.c),
+            self.a.distance_to(self.c),
+            self.a.distance_to(self.b),
+        ]
+    }
+}
+
+impl<T: Display> Display for Triangle<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Triangle[{}, {}, {}]", self.a, self.b, self.c)
     }
 }
 
-impl From<(Point, Point, Point)> for Triangle {
-    fn from((first, second, third): (Point, Point, Point)) -> Self {
+impl<T> From<(Point<T>, Point<T>, Point<T>)> for Triangle<T> {
+    fn from((first, second, third): (Point<T>, Point<T>, Point<T>)) -> Self {
         Self::new(first, second, third)
     }
 }
 
-impl From<(f64, f64, f64, f64, f64, f64)> for Triangle {
-    fn from((first, second, third, fourth, fifth, sixth): (f64, f64, f64, f64, f64, f64)) -> Self {
+impl<T> From<(T, T, T, T, T, T)> for Triangle<T> {
+    fn from((first, second, third, fourth, fifth, sixth): (T, T, T, T, T, T)) -> Self {
         Self::new(
             Point::new(first, second),
             Point::new(third, fourth),
@@ -50,14 +104,14 @@ impl From<(f64, f64, f64, f64, f64, f64)> for Triangle {
     }
 }
 
-impl From<[Point; 3]> for Triangle {
-    fn from([first, second, third]: [Point; 3]) -> Self {
+impl<T> From<[Point<T>; 3]> for Triangle<T> {
+    fn from([first, second, third]: [Point<T>; 3]) -> Self {
         Self::new(first, second, third)
     }
 }
 
-impl From<[f64; 6]> for Triangle {
-    fn from([first, second, third, fourth, fifth, sixth]: [f64; 6]) -> Self {
+impl<T> From<[T; 6]> for Triangle<T> {
+    fn from([first, second, third, fourth, fifth, sixth]: [T; 6]) -> Self {
         Self::new(
             Point::new(first, second),
             Point::new(third, fourth),
@@ -67,10 +121,23 @@ impl From<[f64; 6]> for Triangle {
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash)]
+#[non_exhaustive]
 pub enum TriangleCreateError {
-    InvalidPointCount { got: usize },
-    InvalidFormat { got: String, example: String },
-    DuplicatePoint { point: Point },
+    InvalidPointCount {
+        got: usize,
+    },
+    InvalidFormat {
+        got: String,
+        example: String,
+    },
+    DuplicatePoint {
+        point: Point,
+    },
+    /// Two points are distinct but closer than the tolerance allows.
+    NearlyDuplicatePoint {
+        point: Point,
+        other: Point,
+    },
 }
 
 impl Display for TriangleCreateError {
@@ -88,25 +155,43 @@ impl Display for TriangleCreateError {
             Self::DuplicatePoint { point } => {
                 write!(f, "Duplicate point: {point} is used more than once")
             }
+            Self::NearlyDuplicatePoint { point, other } => {
+                write!(f, "Duplicate point: {point} and {other} nearly coincide")
+            }
         }
     }
 }
 
-impl Error for TriangleCreateError {}
+impl std::error::Error for TriangleCreateError {}
 
 /// Errors encountered when points do not form a valid triangle.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[non_exhaustive]
 pub enum DegenerateTriangleError {
     Collinear {
         a: Point,
         b: Point,
         c: Point,
     },
+    /// Not exactly collinear, but flatter than the tolerance allows.
+    NearlyCollinear {
+        a: Point,
+        b: Point,
+        c: Point,
+    },
     InequalityViolation {
         side_a: f64,
         side_b: f64,
         side_c: f64,
     },
+    /// A constructed vertex is the meeting point of two parallel lines.
+    VertexAtInfinity {
+        label: &'static str,
+    },
+    /// The construction needs a point off the lines BC, CA and AB.
+    PointOnSideLine {
+        point: Point,
+    },
 }
 
 impl Display for DegenerateTriangleError {
@@ -115,6 +200,9 @@ impl Display for DegenerateTriangleError {
             Self::Collinear { a, b, c } => {
                 write!(f, "Points {a}, {b}, and {c} are collinear")
             }
+            Self::NearlyCollinear { a, b, c } => {
+                write!(f, "Points {a}, {b}, and {c} are nearly collinear")
+            }
             Self::InequalityViolation {
                 side_a,
                 side_b,
@@ -125,40 +213,104 @@ impl Display for DegenerateTriangleError {
                     "Triangle inequality violated: sides {side_a:.4}, {side_b:.4}, {side_c:.4} cannot form a triangle (one side ≥ sum of others)"
                 )
             }
+            Self::VertexAtInfinity { label } => {
+                write!(f, "Vertex {label} lies at infinity")
+            }
+            Self::PointOnSideLine { point } => {
+                write!(f, "Point {point} lies on a side line of the triangle")
+            }
         }
     }
 }
 
-impl Error for DegenerateTriangleError {}
+impl std::error::Error for DegenerateTriangleError {}
+
+/// Rejects points with a coordinate that is not a finite number.
+///
+/// # Errors
+/// Returns [`TriangleCreateError::InvalidFormat`] with the first such point.
+pub fn check_finite<T: Scalar>(points: &[Point<T>; 3]) -> Result<(), TriangleCreateError> {
+    points
+        .iter()
+        .find(|point| !(point.x.is_finite() && point.y.is_finite()))
+        .map_or(Ok(()), |point| {
+            Err(TriangleCreateError::InvalidFormat {
+                got: point.to_string(),
+                example: "a point with finite coordinates e.g. (1, 2)".to_string(),
+            })
+        })
+}
 
-pub fn check_duplicate_points(points: &[Point; 3]) -> Result<(), TriangleCreateError> {
-    if points[0] == points[1] {
-        return Err(TriangleCreateError::DuplicatePoint { point: points[0] });
+/// Rejects input where two of the three points coincide, or lie closer
+/// together than `tolerance` relative to the longest side.
+///
+/// Coincidence is decided exactly. Closeness is a matter of tolerance and is
+/// judged on the nearest `f64` points, which for `f64` input are the points
+/// themselves.
+///
+/// # Errors
+/// Returns [`TriangleCreateError::DuplicatePoint`] with the repeated point, or
+/// [`TriangleCreateError::NearlyDuplicatePoint`] with the two close points.
+pub fn check_duplicate_points<T: Scalar>(
+    points: &[Point<T>; 3],
+    tolerance: Tolerance,
+) -> Result<(), TriangleCreateError> {
+    let pairs = [(0, 1), (1, 2), (0, 2)].map(|(i, j)| (&points[i], &points[j]));
+    if let Some((point, _)) = pairs.iter().find(|(point, other)| point == other) {
+        return Err(TriangleCreateError::DuplicatePoint {
+            point: point.to_f64(),
+        });
     }
-    if points[1] == points[2] {
-        return Err(TriangleCreateError::DuplicatePoint { point: points[1] });
-    }
-    if points[0] == points[2] {
-        return Err(TriangleCreateError::DuplicatePoint { point: points[0] });
+    let pairs = pairs.map(|(point, other)| (point.to_f64(), other.to_f64()));
+    let longest = pairs
+        .iter()
+        .map(|(point, other)| point.distance_to(*other))
+        .fold(0.0, f64::max);
+    if let Some(&(point, other)) = pairs
+        .iter()
+        .find(|(point, other)| tolerance.is_negligible(point.distance_to(*other), longest))
+    {
+        return Err(TriangleCreateError::NearlyDuplicatePoint { point, other });
     }
     Ok(())
 }
 
-pub fn check_collinear(points: &[Point; 3]) -> Result<(), DegenerateTriangleError> {
-    let a = points[0];
-    let b = points[1];
-    let c = points[2];
+/// Rejects points lying on a common line.
+///
+/// Exact collinearity is decided with [`Scalar::area_sign`], so it is correct
+/// for every representable input. Points that are not exactly collinear are
+/// still rejected when the area they span, measured on the nearest `f64`
+/// points, is negligible against the squared longest side, which keeps the
+/// check independent of the size of the triangle.
+///
+/// # Errors
+/// Returns [`DegenerateTriangleError::Collinear`] when the points span no area
+/// or the sign of the area cannot be decided, as for coordinates that are not
+/// finite, and [`DegenerateTriangleError::NearlyCollinear`] when the area is
+/// within the tolerance.
+pub fn check_collinear<T: Scalar>(
+    points: &[Point<T>; 3],
+    tolerance: Tolerance,
+) -> Result<(), DegenerateTriangleError> {
+    let [a, b, c] = points.each_ref().map(Point::to_f64);
+    if T::area_sign(&points[0], &points[1], &points[2]).is_none_or(|sign| sign == Ordering::Equal) {
+        return Err(DegenerateTriangleError::Collinear { a, b, c });
+    }
 
     let ab = b - a;
     let ac = c - a;
-    let cross_product = ab.cross(ac).abs();
-
-    if cross_product < 1e-10 {
-        return Err(DegenerateTriangleError::Collinear { a, b, c });
+    let longest_sq = ab.length_sq().max(ac.length_sq()).max((c - b).length_sq());
+    if tolerance.is_negligible(orient2d(a, b, c), longest_sq) {
+        return Err(DegenerateTriangleError::NearlyCollinear { a, b, c });
     }
     Ok(())
 }
 
+/// Checks the strict triangle inequality for three side lengths.
+///
+/// # Errors
+/// Returns [`DegenerateTriangleError::InequalityViolation`] when one side is at
+/// least the sum of the other two.
 pub fn is_valid_triangle(
     side_a: f64,
     side_b: f64,
@@ -175,16 +327,83 @@ pub fn is_valid_triangle(
     })
 }
 
-pub fn build_triangle(points: [Point; 3]) -> Result<Triangle, AppError> {
-    check_duplicate_points(&points)?;
-    check_collinear(&points)?;
+/// Parses a single line holding three points, either as six numbers or as
+/// three `x,y` groups.
+///
+/// Whitespace, commas, semicolons and parentheses all
+/// separate values, so `0 0 4 0 0 3` and `(0,0) (4,0) (0,3)` are equivalent.
+///
+/// # Errors
+/// Returns [`TriangleCreateError::InvalidFormat`] when a value is not a finite
+/// number or the values do not pair up into points, and
+/// [`TriangleCreateError::InvalidPointCount`] when there are not exactly three points.
+pub fn to_triangle(input: &str) -> Result<[Point; 3], TriangleCreateError> {
+    let trimmed = input.trim();
+    let tokens: Vec<&str> = trimmed
+        .split(|ch: char| ch.is_whitespace() || matches!(ch, ',' | ';' | '(' | ')'))
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    if !tokens.len().is_multiple_of(2) {
+        return Err(TriangleCreateError::InvalidFormat {
+            got: trimmed.to_string(),
+            example: "x1,y1 x2,y2 x3,y3".to_string(),
+        });
+    }
+    if tokens.len() != 6 {
+        return Err(TriangleCreateError::InvalidPointCount {
+            got: tokens.len() / 2,
+        });
+    }
+
+    let mut values = [0.0; 6];
+    for (value, token) in values.iter_mut().zip(&tokens) {
+        *value = token
+            .parse::<f64>()
+            .ok()
+            .filter(|parsed| f64::is_finite(*parsed))
+            .ok_or_else(|| TriangleCreateError::InvalidFormat {
+                got: (*token).to_string(),
+                example: "a finite decimal value e.g. 1.0".to_string(),
+            })?;
+    }
 
-    let side_a = points[1].distance_to(points[2]);
-    let side_b = points[0].distance_to(points[2]);
-    let side_c = points[0].distance_to(points[1]);
-    is_valid_triangle(side_a, side_b, side_c)?;
+    let [ax, ay, bx, by, cx, cy] = values;
+    Ok([Point::new(ax, ay), Point::new(bx, by), Point::new(cx, cy)])
+}
+
+/// Validates three points and builds the triangle they describe. This is the
+/// checked way to obtain a [`Triangle`]; [`Triangle::new`] accepts anything.
+///
+/// Takes `f64` points so that literals need no annotation; other scalar
+/// types go through [`build_triangle_with`].
+///
+/// # Errors
+/// Returns [`Error::TriangleCreate`] when a coordinate is not finite or two
+/// points coincide and [`Error::DegenerateTriangle`] when the points are
+/// collinear.
+pub fn build_triangle(points: [Point; 3]) -> Result<Triangle, Error> {
+    build_triangle_with(points, Tolerance::default())
+}
 
-    Ok(Triangle::new(points[0], points[1], points[2]))
+/// [`build_triangle`] for points of any scalar type, deciding collinearity
+/// with `tolerance`.
+///
+/// # Errors
+/// As [`build_triangle`].
+pub fn build_triangle_with<T: Scalar>(
+    points: [Point<T>; 3],
+    tolerance: Tolerance,
+) -> Result<Triangle<T>, Error> {
+    check_finite(&points)?;
+    check_duplicate_points(&points, tolerance)?;
+    check_collinear(&points, tolerance)?;
+
+    let [a, b, c] = points.clone().map(|point| point.to_f64());
+    is_valid_triangle(b.distance_to(c), a.distance_to(c), a.distance_to(b))?;
+
+    let [a, b, c] = points;
+    Ok(Triangle::new(a, b, c))
 }
 
 #[cfg(test)]
@@ -197,10 +416,12 @@ mod tests {
         let p2 = Point::new(0.0, 0.0);
         let p3 = Point::new(1.0, 1.0);
 
-        assert!(check_duplicate_points(&[p1, p2, p3]).is_err());
-        assert!(check_duplicate_points(&[p1, p3, p2]).is_err());
-        assert!(check_duplicate_points(&[p3, p1, p2]).is_err());
-        assert!(check_duplicate_points(&[p1, p3, Point::new(2.0, 2.0)]).is_ok());
+        assert!(check_duplicate_points(&[p1, p2, p3], Tolerance::default()).is_err());
+        assert!(check_duplicate_points(&[p1, p3, p2], Tolerance::default()).is_err());
+        assert!(check_duplicate_points(&[p3, p1, p2], Tolerance::default()).is_err());
+        assert!(
+            check_duplicate_points(&[p1, p3, Point::new(2.0, 2.0)], Tolerance::default()).is_ok()
+        );
     }
 
     #[test]
@@ -209,10 +430,15 @@ mod tests {
         let p2 = Point::new(1e-18, 1e-18);
         let p3 = Point::new(1.0, 1.0);
 
-        // These are currently NOT considered duplicates because we use exact equality
-        assert!(check_duplicate_points(&[p1, p2, p3]).is_ok());
-
-        // But build_triangle should fail due to collinearity or triangle inequality
+        // Distinct, so only the tolerance tier catches them.
+        assert!(check_duplicate_points(&[p1, p2, p3], Tolerance::relative(0.0)).is_ok());
+        assert_eq!(
+            check_duplicate_points(&[p1, p2, p3], Tolerance::default()),
+            Err(TriangleCreateError::NearlyDuplicatePoint {
+                point: p1,
+                other: p2
+            })
+        );
         assert!(build_triangle([p1, p2, p3]).is_err());
     }
 
@@ -221,21 +447,32 @@ mod tests {
         let p1 = Point::new(0.0, 0.0);
         let p2 = Point::new(1.0, 0.0);
         let p3 = Point::new(2.0, 0.0);
-        assert!(check_collinear(&[p1, p2, p3]).is_err());
+        assert!(check_collinear(&[p1, p2, p3], Tolerance::default()).is_err());
 
         let p4 = Point::new(0.0, 1.0);
-        assert!(check_collinear(&[p1, p2, p4]).is_ok());
+        assert!(check_collinear(&[p1, p2, p4], Tolerance::default()).is_ok());
 
         // Near collinear
         let p5 = Point::new(2.0, 1e-11);
         assert!(
-            check_collinear(&[p1, p2, p5]).is_err(),
+            check_collinear(&[p1, p2, p5], Tolerance::default()).is_err(),
             "Should be considered collinear with 1e-11 offset"
         );
 
+        assert!(matches!(
+            check_collinear(&[p1, p2, p3], Tolerance::relative(0.0)),
+            Err(DegenerateTriangleError::Collinear { .. })
+        ));
+        assert!(matches!(
+            check_collinear(&[p1, p2, p5], Tolerance::default()),
+            Err(DegenerateTriangleError::NearlyCollinear { .. })
+        ));
+        // Without a tolerance only exact collinearity is rejected.
+        assert!(check_collinear(&[p1, p2, p5], Tolerance::relative(0.0)).is_ok());
+
         let p6 = Point::new(2.0, 1e-9);
         assert!(
-            check_collinear(&[p1, p2, p6]).is_ok(),
+            check_collinear(&[p1, p2, p6], Tolerance::default()).is_ok(),
             "Should NOT be considered collinear with 1e-9 offset"
         );
     }
@@ -263,20 +500,68 @@ mod tests {
         assert!(build_triangle([p1, p2, Point::new(2.0, 0.0)]).is_err());
     }
 
+    #[test]
+    fn test_to_triangle_parsing() {
+        let expected = [
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        ];
+        assert_eq!(to_triangle("0 0 4 0 0 3"), Ok(expected));
+        assert_eq!(to_triangle("0,0 4,0 0,3"), Ok(expected));
+        assert_eq!(to_triangle(" (0, 0); (4, 0); (0, 3) "), Ok(expected));
+        assert_eq!(to_triangle("0,0,4,0,0,3"), Ok(expected));
+
+        assert_eq!(
+            to_triangle("0,0 4,0"),
+            Err(TriangleCreateError::InvalidPointCount { got: 2 })
+        );
+        assert_eq!(
+            to_triangle("0,0 4,0 0,3 1,1"),
+            Err(TriangleCreateError::InvalidPointCount { got: 4 })
+        );
+        assert!(matches!(
+            to_triangle("0,0 4,0 0"),
+            Err(TriangleCreateError::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            to_triangle("0,0 4,0 0,NaN"),
+            Err(TriangleCreateError::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            to_triangle("0,0 4,x 0,3"),
+            Err(TriangleCreateError::InvalidFormat { .. })
+        ));
+    }
+
     #[test]
     fn test_small_valid_triangle() {
-        let s = 1e-4; // s^2 = 1e-8 > 1e-10
         let p1 = Point::new(0.0, 0.0);
-        let p2 = Point::new(s, 0.0);
-        let p3 = Point::new(0.0, s);
-        assert!(build_triangle([p1, p2, p3]).is_ok());
+        for s in [1e-4, 1e-6, 1e-12] {
+            let p2 = Point::new(s, 0.0);
+            let p3 = Point::new(0.0, s);
+            assert!(build_triangle([p1, p2, p3]).is_ok(), "{s}");
+            // Still flat when the offset is tiny relative to the size.
+            let p4 = Point::new(2.0 * s, s * 1e-12);
+            assert!(build_triangle([p1, p2, p4]).is_err(), "{s}");
+        }
+    }
 
-        let s2 = 1e-6; // s2^2 = 1e-12 < 1e-10
-        let p4 = Point::new(s2, 0.0);
-        let p5 = Point::new(0.0, s2);
-        assert!(
-            build_triangle([p1, p4, p5]).is_err(),
-            "Should be rejected as too small/collinear"
-        );
+    #[test]
+    fn test_custom_tolerance() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 1e-6),
+        ];
+        assert!(build_triangle(points).is_ok());
+        assert!(build_triangle_with(points, Tolerance::relative(1e-5)).is_err());
+        // An absolute floor rejects triangles below a fixed size.
+        let tiny = [
+            Point::new(0.0, 0.0),
+            Point::new(1e-6, 0.0),
+            Point::new(0.0, 1e-6),
+        ];
+        assert!(build_triangle_with(tiny, Tolerance::new(1e-10, 1e-10)).is_err());
     }
 }